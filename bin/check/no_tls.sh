@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# Checks that Hurl builds and passes its unit tests without TLS support.
+cargo clippy --package hurl --all-targets --no-default-features --features no-tls -- -D warnings
+cargo test --package hurl --lib --bins --no-default-features --features no-tls
@@ -34,6 +34,11 @@ if ($LASTEXITCODE) { Throw }
 sleep 5
 if (netstat -ano | Select-String LISTENING | Select-string 127.0.0.1:8003) {write-host -foregroundcolor Green "server-ssl-client-authent up"} else {write-host -foregroundcolor Red "server-ssl-client-authent is down" ; cat build\server-ssl-client-authent.log ; exit 1}
 
+python tunnel_proxy/server.py 8004 2>&1 > build\server-tunnel-proxy.log &
+if ($LASTEXITCODE) { Throw }
+sleep 5
+if (netstat -ano | Select-String LISTENING | Select-string 127.0.0.1:8004) {write-host -foregroundcolor Green "server-tunnel-proxy up"} else {write-host -foregroundcolor Red "server-tunnel-proxy is down" ; cat build\server-tunnel-proxy.log ; exit 1}
+
 Get-ChildItem -Force C:\Squid\bin
 write-output "cache deny all" "cache_log /dev/null" "access_log /dev/null" "http_access allow all" "http_port 0.0.0.0:3128" "request_header_add From-Proxy Hello" "reply_header_add From-Proxy Hello" > squid.conf
 C:\Squid\bin\squid -d 2 -N -f squid.conf 2>&1 | tee -Append -filepath build\proxy.log &
@@ -61,6 +61,10 @@ echo -e "\n------------------ Starting ssl/server.py (Self-signed certificate +
 nohup python3 ssl/server.py 8003 ssl/server/cert.selfsigned.pem true > build/server-ssl-client-authent.log 2>&1 &
 check_listen_port "ssl/server.py" 8003 || cat_and_exit_err build/server-ssl-client-authent.log
 
+echo -e "\n------------------ Starting tunnel_proxy/server.py"
+python3 tunnel_proxy/server.py 8004 > build/server-tunnel-proxy.log 2>&1 &
+check_listen_port "tunnel_proxy/server.py" 8004 || cat_and_exit_err build/server-tunnel-proxy.log
+
 echo -e "\n------------------ Starting unix_socket/server.py"
 python3 unix_socket/server.py > build/server-unix-socket.log 2>&1 &
 
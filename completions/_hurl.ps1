@@ -20,11 +20,19 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
 
     $completions = @(switch ($command) {
         'hurl'
-         {[CompletionResult]::new('--aws-sigv4', 'aws-sigv4', [CompletionResultType]::ParameterName, 'Use AWS V4 signature authentication in the transfer')
+         {[CompletionResult]::new('--abort-on-first-assert-failure-within-entry', 'abort-on-first-assert-failure-within-entry', [CompletionResultType]::ParameterName, 'Stop evaluating asserts of an entry at the first failure')
+            [CompletionResult]::new('--accept-encoding', 'accept-encoding', [CompletionResultType]::ParameterName, 'Request a response compressed with ENCODINGS, or none')
+            [CompletionResult]::new('--assert-file', 'assert-file', [CompletionResultType]::ParameterName, 'Apply the asserts of FILE to the last response of each file')
+            [CompletionResult]::new('--aws-sigv4', 'aws-sigv4', [CompletionResultType]::ParameterName, 'Use AWS V4 signature authentication in the transfer')
+            [CompletionResult]::new('--body-format', 'body-format', [CompletionResultType]::ParameterName, 'Format of the written response body: auto, text, binary or json')
+            [CompletionResult]::new('--body-only-on-failure', 'body-only-on-failure', [CompletionResultType]::ParameterName, 'Output the body of the last response only if the run fails')
+            [CompletionResult]::new('--buffer-size', 'buffer-size', [CompletionResultType]::ParameterName, 'Size in bytes of the buffer used to read response bodies')
             [CompletionResult]::new('--cacert', 'cacert', [CompletionResultType]::ParameterName, 'CA certificate to verify peer against (PEM format)')
+            [CompletionResult]::new('--capture-proxy', 'capture-proxy', [CompletionResultType]::ParameterName, '(Experimental) Run a local proxy recording HTTP traffic as a Hurl file')
             [CompletionResult]::new('--cert', 'cert', [CompletionResultType]::ParameterName, 'Client certificate file and password')
             [CompletionResult]::new('--key', 'key', [CompletionResultType]::ParameterName, 'Private key file name')
             [CompletionResult]::new('--color', 'color', [CompletionResultType]::ParameterName, 'Colorize output')
+            [CompletionResult]::new('--compress-reports', 'compress-reports', [CompletionResultType]::ParameterName, 'Write JUnit and TAP reports gzip compressed, with a .gz suffix')
             [CompletionResult]::new('--compressed', 'compressed', [CompletionResultType]::ParameterName, 'Request compressed response (using deflate or gzip)')
             [CompletionResult]::new('--connect-timeout', 'connect-timeout', [CompletionResultType]::ParameterName, 'Maximum time allowed for connection')
             [CompletionResult]::new('--connect-to', 'connect-to', [CompletionResultType]::ParameterName, 'For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead')
@@ -32,24 +40,38 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
             [CompletionResult]::new('--cookie', 'cookie', [CompletionResultType]::ParameterName, 'Read cookies from FILE')
             [CompletionResult]::new('--cookie-jar', 'cookie-jar', [CompletionResultType]::ParameterName, 'Write cookies to FILE after running the session (only for one session)')
             [CompletionResult]::new('--delay', 'delay', [CompletionResultType]::ParameterName, 'Sets delay before each request.')
+            [CompletionResult]::new('--diagnostics-json', 'diagnostics-json', [CompletionResultType]::ParameterName, 'Output parsing errors and lint warnings as JSON diagnostics')
             [CompletionResult]::new('--error-format', 'error-format', [CompletionResultType]::ParameterName, 'Control the format of error messages')
+            [CompletionResult]::new('--explain', 'explain', [CompletionResultType]::ParameterName, 'Print the result of each assert, even on success')
             [CompletionResult]::new('--fail-at-end', 'fail-at-end', [CompletionResultType]::ParameterName, 'Fail at end')
+            [CompletionResult]::new('--fail-fast', 'fail-fast', [CompletionResultType]::ParameterName, 'Stop running new files after the first failed file')
+            [CompletionResult]::new('--fail-on', 'fail-on', [CompletionResultType]::ParameterName, 'Fail on responses with a status in one of the comma separated CLASSES (ex: 4xx,5xx)')
             [CompletionResult]::new('--file-root', 'file-root', [CompletionResultType]::ParameterName, 'Set root directory to import files [default: current directory]')
             [CompletionResult]::new('--location', 'location', [CompletionResultType]::ParameterName, 'Follow redirects')
             [CompletionResult]::new('--location-trusted', 'location-trusted', [CompletionResultType]::ParameterName, 'Follow redirects but allows sending the name + password to all hosts that the site may redirect to.')
             [CompletionResult]::new('--from-entry', 'from-entry', [CompletionResultType]::ParameterName, 'Execute Hurl file from ENTRY_NUMBER (starting at 1)')
             [CompletionResult]::new('--glob', 'glob', [CompletionResultType]::ParameterName, 'Specify input files that match the given GLOB. Multiple glob flags may be used')
+            [CompletionResult]::new('--header-match', 'header-match', [CompletionResultType]::ParameterName, 'Match header names of queries exactly or case-insensitively')
             [CompletionResult]::new('--http1.0', 'http1.0', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 1.0')
             [CompletionResult]::new('--http1.1', 'http1.1', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 1.1')
             [CompletionResult]::new('--http2', 'http2', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 2')
             [CompletionResult]::new('--http3', 'http3', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 3')
+            [CompletionResult]::new('--idempotency-key-header', 'idempotency-key-header', [CompletionResultType]::ParameterName, 'Add a stable generated key header to mutating requests, reused across retries')
             [CompletionResult]::new('--ignore-asserts', 'ignore-asserts', [CompletionResultType]::ParameterName, 'Ignore asserts defined in the Hurl file')
             [CompletionResult]::new('--include', 'include', [CompletionResultType]::ParameterName, 'Include the HTTP headers in the output')
+            [CompletionResult]::new('--include-request', 'include-request', [CompletionResultType]::ParameterName, 'Include the HTTP request line and headers, and the HTTP response headers in the output')
+            [CompletionResult]::new('--input', 'input', [CompletionResultType]::ParameterName, '(Experimental) Read the response evaluated by --query from FILE')
             [CompletionResult]::new('--insecure', 'insecure', [CompletionResultType]::ParameterName, 'Allow insecure SSL connections')
+            [CompletionResult]::new('--insecure-host', 'insecure-host', [CompletionResultType]::ParameterName, 'Allow insecure SSL connections to HOST only')
             [CompletionResult]::new('--interactive', 'interactive', [CompletionResultType]::ParameterName, 'Turn on interactive mode')
             [CompletionResult]::new('--ipv4', 'ipv4', [CompletionResultType]::ParameterName, 'Tell Hurl to use IPv4 addresses only when resolving host names, and not for example try IPv6')
             [CompletionResult]::new('--ipv6', 'ipv6', [CompletionResultType]::ParameterName, 'Tell Hurl to use IPv6 addresses only when resolving host names, and not for example try IPv4')
             [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Output each Hurl file result to JSON')
+            [CompletionResult]::new('--lint', 'lint', [CompletionResultType]::ParameterName, 'Check files for likely mistakes without running them')
+            [CompletionResult]::new('--list-entries', 'list-entries', [CompletionResultType]::ParameterName, 'List the entries of files without running them')
+            [CompletionResult]::new('--max-concurrent-per-host', 'max-concurrent-per-host', [CompletionResultType]::ParameterName, '(Experimental) Maximum number of concurrent requests to the same host in parallel mode')
+            [CompletionResult]::new('--max-header-size', 'max-header-size', [CompletionResultType]::ParameterName, 'Maximum size in bytes of the response headers')
+            [CompletionResult]::new('--max-pages', 'max-pages', [CompletionResultType]::ParameterName, 'Maximum number of pages to fetch when following pagination links')
             [CompletionResult]::new('--max-redirs', 'max-redirs', [CompletionResultType]::ParameterName, 'Maximum number of redirects allowed, -1 for unlimited redirects')
             [CompletionResult]::new('--max-time', 'max-time', [CompletionResultType]::ParameterName, 'Maximum time allowed for the transfer')
             [CompletionResult]::new('--max-workers', 'max-workers', [CompletionResultType]::ParameterName, '(Experimental) Maximum number of parallel execution')
@@ -59,25 +81,58 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
             [CompletionResult]::new('--no-color', 'no-color', [CompletionResultType]::ParameterName, 'Do not colorize output')
             [CompletionResult]::new('--no-output', 'no-output', [CompletionResultType]::ParameterName, 'Suppress output. By default, Hurl outputs the body of the last response')
             [CompletionResult]::new('--noproxy', 'noproxy', [CompletionResultType]::ParameterName, 'List of hosts which do not use proxy')
+            [CompletionResult]::new('--normalize-json-numbers', 'normalize-json-numbers', [CompletionResultType]::ParameterName, 'Normalize numbers when comparing JSON bodies')
+            [CompletionResult]::new('--normalize-newlines', 'normalize-newlines', [CompletionResultType]::ParameterName, 'Normalize line endings when comparing texts')
             [CompletionResult]::new('--output', 'output', [CompletionResultType]::ParameterName, 'Write to FILE instead of stdout')
+            [CompletionResult]::new('--output-append-newline', 'output-append-newline', [CompletionResultType]::ParameterName, 'Append a newline after the response body written to standard output')
+            [CompletionResult]::new('--output-split', 'output-split', [CompletionResultType]::ParameterName, 'Write the last response body of each entry to a numbered file in DIR')
+            [CompletionResult]::new('--paginate', 'paginate', [CompletionResultType]::ParameterName, 'Follow the pagination links with relation type REL and aggregate the pages bodies')
             [CompletionResult]::new('--parallel', 'parallel', [CompletionResultType]::ParameterName, '(Experimental) Run files in parallel')
+            [CompletionResult]::new('--parse-only', 'parse-only', [CompletionResultType]::ParameterName, 'Parse files without running them')
+            [CompletionResult]::new('--partial-body', 'partial-body', [CompletionResultType]::ParameterName, 'Stop reading response bodies as soon as every body contains assert matches')
             [CompletionResult]::new('--path-as-is', 'path-as-is', [CompletionResultType]::ParameterName, 'Tell Hurl to not handle sequences of /../ or /./ in the given URL path')
+            [CompletionResult]::new('--print-curl-on-failure', 'print-curl-on-failure', [CompletionResultType]::ParameterName, 'Print the curl command line of each failing entry')
+            [CompletionResult]::new('--print-request-duration-warnings', 'print-request-duration-warnings', [CompletionResultType]::ParameterName, 'Print a warning for each entry slower than --slow-warn-ms after the run')
+            [CompletionResult]::new('--print-timings', 'print-timings', [CompletionResultType]::ParameterName, 'Print a table of the entries timings after the run')
             [CompletionResult]::new('--proxy', 'proxy', [CompletionResultType]::ParameterName, 'Use proxy on given PROTOCOL/HOST/PORT')
+            [CompletionResult]::new('--proxy-header', 'proxy-header', [CompletionResultType]::ParameterName, 'Pass custom header to proxy')
+            [CompletionResult]::new('--proxy-tunnel', 'proxy-tunnel', [CompletionResultType]::ParameterName, 'Tunnel HTTP requests through the proxy with CONNECT')
+            [CompletionResult]::new('--query', 'query', [CompletionResultType]::ParameterName, '(Experimental) Evaluate a query on a stored response and print the result')
+            [CompletionResult]::new('--randomize-order', 'randomize-order', [CompletionResultType]::ParameterName, 'Run entries of a file in a random order, keeping dependent entries ordered')
+            [CompletionResult]::new('--record', 'record', [CompletionResultType]::ParameterName, 'Record all HTTP exchanges to the cassette FILE')
+            [CompletionResult]::new('--replay', 'replay', [CompletionResultType]::ParameterName, 'Serve HTTP exchanges recorded in the cassette FILE, without network access')
             [CompletionResult]::new('--report-html', 'report-html', [CompletionResultType]::ParameterName, 'Generate HTML report to DIR')
             [CompletionResult]::new('--report-junit', 'report-junit', [CompletionResultType]::ParameterName, 'Write a JUnit XML report to FILE')
+            [CompletionResult]::new('--report-sort', 'report-sort', [CompletionResultType]::ParameterName, 'Sort the files of JUnit, TAP and HTML reports')
             [CompletionResult]::new('--report-tap', 'report-tap', [CompletionResultType]::ParameterName, 'Write a TAP report to FILE')
             [CompletionResult]::new('--resolve', 'resolve', [CompletionResultType]::ParameterName, 'Provide a custom address for a specific HOST and PORT pair')
+            [CompletionResult]::new('--resolve-from-file', 'resolve-from-file', [CompletionResultType]::ParameterName, 'Read custom addresses for HOST and PORT pairs from a file')
             [CompletionResult]::new('--retry', 'retry', [CompletionResultType]::ParameterName, 'Maximum number of retries, 0 for no retries, -1 for unlimited retries')
+            [CompletionResult]::new('--retry-connect', 'retry-connect', [CompletionResultType]::ParameterName, 'Maximum number of retries on connection errors, 0 for no retries, -1 for unlimited retries')
             [CompletionResult]::new('--retry-interval', 'retry-interval', [CompletionResultType]::ParameterName, 'Interval in milliseconds before a retry')
+            [CompletionResult]::new('--sample', 'sample', [CompletionResultType]::ParameterName, 'Run a random sample of PERCENT% of the input files')
+            [CompletionResult]::new('--seed', 'seed', [CompletionResultType]::ParameterName, 'Set the seed used to randomize the order of entries or the sampled files')
+            [CompletionResult]::new('--set-at', 'set-at', [CompletionResultType]::ParameterName, 'Set a variable from entry ENTRY_NUMBER (starting at 1)')
+            [CompletionResult]::new('--setup', 'setup', [CompletionResultType]::ParameterName, 'Run FILE before the Hurl files, using its captures as variables')
+            [CompletionResult]::new('--slow-warn-ms', 'slow-warn-ms', [CompletionResultType]::ParameterName, 'Duration above which an entry is reported as slow')
+            [CompletionResult]::new('--sni', 'sni', [CompletionResultType]::ParameterName, 'Use NAME as TLS server name (SNI) and Host header, instead of the URL host')
+            [CompletionResult]::new('--sort-headers', 'sort-headers', [CompletionResultType]::ParameterName, 'Sort the HTTP response headers by name in the output')
             [CompletionResult]::new('--ssl-no-revoke', 'ssl-no-revoke', [CompletionResultType]::ParameterName, '(Windows) Tell Hurl to disable certificate revocation checks. WARNING: this option loosens the SSL security, and by using this flag you ask for exactly that.')
+            [CompletionResult]::new('--teardown', 'teardown', [CompletionResultType]::ParameterName, 'Run FILE after the Hurl files, even if they have failed')
+            [CompletionResult]::new('--teardown-strict', 'teardown-strict', [CompletionResultType]::ParameterName, 'Fail the run if the teardown file fails')
             [CompletionResult]::new('--test', 'test', [CompletionResultType]::ParameterName, 'Activate test mode')
+            [CompletionResult]::new('--timezone', 'timezone', [CompletionResultType]::ParameterName, 'Set the timezone used to parse and format dates in filters')
             [CompletionResult]::new('--to-entry', 'to-entry', [CompletionResultType]::ParameterName, 'Execute Hurl file to ENTRY_NUMBER (starting at 1)')
+            [CompletionResult]::new('--trace-context', 'trace-context', [CompletionResultType]::ParameterName, 'Add a W3C traceparent header to each request')
+            [CompletionResult]::new('--trace-state', 'trace-state', [CompletionResultType]::ParameterName, 'Add a W3C tracestate header with STATE to each request, with --trace-context')
             [CompletionResult]::new('--unix-socket', 'unix-socket', [CompletionResultType]::ParameterName, '(HTTP) Connect through this Unix domain socket, instead of using the network')
             [CompletionResult]::new('--user', 'user', [CompletionResultType]::ParameterName, 'Add basic Authentication header to each request')
             [CompletionResult]::new('--user-agent', 'user-agent', [CompletionResultType]::ParameterName, 'Specify the User-Agent string to send to the HTTP server')
             [CompletionResult]::new('--variable', 'variable', [CompletionResultType]::ParameterName, 'Define a variable')
             [CompletionResult]::new('--variables-file', 'variables-file', [CompletionResultType]::ParameterName, 'Define a properties file in which you define your variables')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Turn on verbose')
+            [CompletionResult]::new('--verify-content-length', 'verify-content-length', [CompletionResultType]::ParameterName, 'Check that each response body length matches its Content-Length header')
+            [CompletionResult]::new('--version-json', 'version-json', [CompletionResultType]::ParameterName, 'Print version information as JSON')
             [CompletionResult]::new('--very-verbose', 'very-verbose', [CompletionResultType]::ParameterName, 'Turn on verbose output, including HTTP response and libcurl logs')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--version', 'version', [CompletionResultType]::ParameterName, 'Print version')
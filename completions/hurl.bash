@@ -5,7 +5,7 @@ _hurl()
     _init_completion || return
 
     if [[ $cur == -* ]]; then
-        COMPREPLY=($(compgen -W '--aws-sigv4 --cacert --cert --key --color --compressed --connect-timeout --connect-to --continue-on-error --cookie --cookie-jar --delay --error-format --fail-at-end --file-root --location --location-trusted --from-entry --glob --http1.0 --http1.1 --http2 --http3 --ignore-asserts --include --insecure --interactive --ipv4 --ipv6 --json --max-redirs --max-time --max-workers --netrc --netrc-file --netrc-optional --no-color --no-output --noproxy --output --parallel --path-as-is --proxy --report-html --report-junit --report-tap --resolve --retry --retry-interval --ssl-no-revoke --test --to-entry --unix-socket --user --user-agent --variable --variables-file --verbose --very-verbose --help --version' -- "$cur"))
+        COMPREPLY=($(compgen -W '--abort-on-first-assert-failure-within-entry --accept-encoding --assert-file --aws-sigv4 --body-format --body-only-on-failure --buffer-size --cacert --capture-proxy --cert --key --color --compress-reports --compressed --connect-timeout --connect-to --continue-on-error --cookie --cookie-jar --delay --diagnostics-json --error-format --explain --fail-at-end --fail-fast --fail-on --file-root --location --location-trusted --from-entry --glob --header-match --http1.0 --http1.1 --http2 --http3 --idempotency-key-header --ignore-asserts --include --include-request --input --insecure --insecure-host --interactive --ipv4 --ipv6 --json --lint --list-entries --max-concurrent-per-host --max-header-size --max-pages --max-redirs --max-time --max-workers --netrc --netrc-file --netrc-optional --no-color --no-output --noproxy --normalize-json-numbers --normalize-newlines --output --output-append-newline --output-split --paginate --parallel --parse-only --partial-body --path-as-is --print-curl-on-failure --print-request-duration-warnings --print-timings --proxy --proxy-header --proxy-tunnel --query --randomize-order --record --replay --report-html --report-junit --report-sort --report-tap --resolve --resolve-from-file --retry --retry-connect --retry-interval --sample --seed --set-at --setup --slow-warn-ms --sni --sort-headers --ssl-no-revoke --teardown --teardown-strict --test --timezone --to-entry --trace-context --trace-state --unix-socket --user --user-agent --variable --variables-file --verbose --verify-content-length --version-json --very-verbose --help --version' -- "$cur"))
         return
     fi
  
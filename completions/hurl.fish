@@ -1,8 +1,16 @@
+complete -c hurl -l abort-on-first-assert-failure-within-entry -d 'Stop evaluating asserts of an entry at the first failure'
+complete -c hurl -l accept-encoding -d 'Request a response compressed with ENCODINGS, or none'
+complete -c hurl -l assert-file -d 'Apply the asserts of FILE to the last response of each file'
 complete -c hurl -l aws-sigv4 -d 'Use AWS V4 signature authentication in the transfer'
+complete -c hurl -l body-format -d 'Format of the written response body: auto, text, binary or json'
+complete -c hurl -l body-only-on-failure -d 'Output the body of the last response only if the run fails'
+complete -c hurl -l buffer-size -d 'Size in bytes of the buffer used to read response bodies'
 complete -c hurl -l cacert -d 'CA certificate to verify peer against (PEM format)'
+complete -c hurl -l capture-proxy -d '(Experimental) Run a local proxy recording HTTP traffic as a Hurl file'
 complete -c hurl -l cert -d 'Client certificate file and password'
 complete -c hurl -l key -d 'Private key file name'
 complete -c hurl -l color -d 'Colorize output'
+complete -c hurl -l compress-reports -d 'Write JUnit and TAP reports gzip compressed, with a .gz suffix'
 complete -c hurl -l compressed -d 'Request compressed response (using deflate or gzip)'
 complete -c hurl -l connect-timeout -d 'Maximum time allowed for connection'
 complete -c hurl -l connect-to -d 'For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead'
@@ -10,24 +18,38 @@ complete -c hurl -l continue-on-error -d 'Continue executing requests even if an
 complete -c hurl -l cookie -d 'Read cookies from FILE'
 complete -c hurl -l cookie-jar -d 'Write cookies to FILE after running the session (only for one session)'
 complete -c hurl -l delay -d 'Sets delay before each request.'
+complete -c hurl -l diagnostics-json -d 'Output parsing errors and lint warnings as JSON diagnostics'
 complete -c hurl -l error-format -d 'Control the format of error messages'
+complete -c hurl -l explain -d 'Print the result of each assert, even on success'
 complete -c hurl -l fail-at-end -d 'Fail at end'
+complete -c hurl -l fail-fast -d 'Stop running new files after the first failed file'
+complete -c hurl -l fail-on -d 'Fail on responses with a status in one of the comma separated CLASSES (ex: 4xx,5xx)'
 complete -c hurl -l file-root -d 'Set root directory to import files [default: current directory]'
 complete -c hurl -l location -d 'Follow redirects'
 complete -c hurl -l location-trusted -d 'Follow redirects but allows sending the name + password to all hosts that the site may redirect to.'
 complete -c hurl -l from-entry -d 'Execute Hurl file from ENTRY_NUMBER (starting at 1)'
 complete -c hurl -l glob -d 'Specify input files that match the given GLOB. Multiple glob flags may be used'
+complete -c hurl -l header-match -d 'Match header names of queries exactly or case-insensitively'
 complete -c hurl -l http1.0 -d 'Tell Hurl to use HTTP version 1.0'
 complete -c hurl -l http1.1 -d 'Tell Hurl to use HTTP version 1.1'
 complete -c hurl -l http2 -d 'Tell Hurl to use HTTP version 2'
 complete -c hurl -l http3 -d 'Tell Hurl to use HTTP version 3'
+complete -c hurl -l idempotency-key-header -d 'Add a stable generated key header to mutating requests, reused across retries'
 complete -c hurl -l ignore-asserts -d 'Ignore asserts defined in the Hurl file'
 complete -c hurl -l include -d 'Include the HTTP headers in the output'
+complete -c hurl -l include-request -d 'Include the HTTP request line and headers, and the HTTP response headers in the output'
+complete -c hurl -l input -d '(Experimental) Read the response evaluated by --query from FILE'
 complete -c hurl -l insecure -d 'Allow insecure SSL connections'
+complete -c hurl -l insecure-host -d 'Allow insecure SSL connections to HOST only'
 complete -c hurl -l interactive -d 'Turn on interactive mode'
 complete -c hurl -l ipv4 -d 'Tell Hurl to use IPv4 addresses only when resolving host names, and not for example try IPv6'
 complete -c hurl -l ipv6 -d 'Tell Hurl to use IPv6 addresses only when resolving host names, and not for example try IPv4'
 complete -c hurl -l json -d 'Output each Hurl file result to JSON'
+complete -c hurl -l lint -d 'Check files for likely mistakes without running them'
+complete -c hurl -l list-entries -d 'List the entries of files without running them'
+complete -c hurl -l max-concurrent-per-host -d '(Experimental) Maximum number of concurrent requests to the same host in parallel mode'
+complete -c hurl -l max-header-size -d 'Maximum size in bytes of the response headers'
+complete -c hurl -l max-pages -d 'Maximum number of pages to fetch when following pagination links'
 complete -c hurl -l max-redirs -d 'Maximum number of redirects allowed, -1 for unlimited redirects'
 complete -c hurl -l max-time -d 'Maximum time allowed for the transfer'
 complete -c hurl -l max-workers -d '(Experimental) Maximum number of parallel execution'
@@ -37,25 +59,58 @@ complete -c hurl -l netrc-optional -d 'Use either .netrc or the URL'
 complete -c hurl -l no-color -d 'Do not colorize output'
 complete -c hurl -l no-output -d 'Suppress output. By default, Hurl outputs the body of the last response'
 complete -c hurl -l noproxy -d 'List of hosts which do not use proxy'
+complete -c hurl -l normalize-json-numbers -d 'Normalize numbers when comparing JSON bodies'
+complete -c hurl -l normalize-newlines -d 'Normalize line endings when comparing texts'
 complete -c hurl -l output -d 'Write to FILE instead of stdout'
+complete -c hurl -l output-append-newline -d 'Append a newline after the response body written to standard output'
+complete -c hurl -l output-split -d 'Write the last response body of each entry to a numbered file in DIR'
+complete -c hurl -l paginate -d 'Follow the pagination links with relation type REL and aggregate the pages bodies'
 complete -c hurl -l parallel -d '(Experimental) Run files in parallel'
+complete -c hurl -l parse-only -d 'Parse files without running them'
+complete -c hurl -l partial-body -d 'Stop reading response bodies as soon as every body contains assert matches'
 complete -c hurl -l path-as-is -d 'Tell Hurl to not handle sequences of /../ or /./ in the given URL path'
+complete -c hurl -l print-curl-on-failure -d 'Print the curl command line of each failing entry'
+complete -c hurl -l print-request-duration-warnings -d 'Print a warning for each entry slower than --slow-warn-ms after the run'
+complete -c hurl -l print-timings -d 'Print a table of the entries timings after the run'
 complete -c hurl -l proxy -d 'Use proxy on given PROTOCOL/HOST/PORT'
+complete -c hurl -l proxy-header -d 'Pass custom header to proxy'
+complete -c hurl -l proxy-tunnel -d 'Tunnel HTTP requests through the proxy with CONNECT'
+complete -c hurl -l query -d '(Experimental) Evaluate a query on a stored response and print the result'
+complete -c hurl -l randomize-order -d 'Run entries of a file in a random order, keeping dependent entries ordered'
+complete -c hurl -l record -d 'Record all HTTP exchanges to the cassette FILE'
+complete -c hurl -l replay -d 'Serve HTTP exchanges recorded in the cassette FILE, without network access'
 complete -c hurl -l report-html -d 'Generate HTML report to DIR'
 complete -c hurl -l report-junit -d 'Write a JUnit XML report to FILE'
+complete -c hurl -l report-sort -d 'Sort the files of JUnit, TAP and HTML reports'
 complete -c hurl -l report-tap -d 'Write a TAP report to FILE'
 complete -c hurl -l resolve -d 'Provide a custom address for a specific HOST and PORT pair'
+complete -c hurl -l resolve-from-file -d 'Read custom addresses for HOST and PORT pairs from a file'
 complete -c hurl -l retry -d 'Maximum number of retries, 0 for no retries, -1 for unlimited retries'
+complete -c hurl -l retry-connect -d 'Maximum number of retries on connection errors, 0 for no retries, -1 for unlimited retries'
 complete -c hurl -l retry-interval -d 'Interval in milliseconds before a retry'
+complete -c hurl -l sample -d 'Run a random sample of PERCENT% of the input files'
+complete -c hurl -l seed -d 'Set the seed used to randomize the order of entries or the sampled files'
+complete -c hurl -l set-at -d 'Set a variable from entry ENTRY_NUMBER (starting at 1)'
+complete -c hurl -l setup -d 'Run FILE before the Hurl files, using its captures as variables'
+complete -c hurl -l slow-warn-ms -d 'Duration above which an entry is reported as slow'
+complete -c hurl -l sni -d 'Use NAME as TLS server name (SNI) and Host header, instead of the URL host'
+complete -c hurl -l sort-headers -d 'Sort the HTTP response headers by name in the output'
 complete -c hurl -l ssl-no-revoke -d '(Windows) Tell Hurl to disable certificate revocation checks. WARNING: this option loosens the SSL security, and by using this flag you ask for exactly that.'
+complete -c hurl -l teardown -d 'Run FILE after the Hurl files, even if they have failed'
+complete -c hurl -l teardown-strict -d 'Fail the run if the teardown file fails'
 complete -c hurl -l test -d 'Activate test mode'
+complete -c hurl -l timezone -d 'Set the timezone used to parse and format dates in filters'
 complete -c hurl -l to-entry -d 'Execute Hurl file to ENTRY_NUMBER (starting at 1)'
+complete -c hurl -l trace-context -d 'Add a W3C traceparent header to each request'
+complete -c hurl -l trace-state -d 'Add a W3C tracestate header with STATE to each request, with --trace-context'
 complete -c hurl -l unix-socket -d '(HTTP) Connect through this Unix domain socket, instead of using the network'
 complete -c hurl -l user -d 'Add basic Authentication header to each request'
 complete -c hurl -l user-agent -d 'Specify the User-Agent string to send to the HTTP server'
 complete -c hurl -l variable -d 'Define a variable'
 complete -c hurl -l variables-file -d 'Define a properties file in which you define your variables'
 complete -c hurl -l verbose -d 'Turn on verbose'
+complete -c hurl -l verify-content-length -d 'Check that each response body length matches its Content-Length header'
+complete -c hurl -l version-json -d 'Print version information as JSON'
 complete -c hurl -l very-verbose -d 'Turn on verbose output, including HTTP response and libcurl logs'
 complete -c hurl -l help -d 'Print help'
 complete -c hurl -l version -d 'Print version'
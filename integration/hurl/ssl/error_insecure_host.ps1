@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl ssl/error_insecure_host.hurl --insecure-host example.org
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl ssl/error_insecure_host.hurl --insecure-host example.org
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl ssl/insecure_host.hurl --insecure-host localhost --verbose
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl ssl/insecure_host.hurl --insecure-host localhost --verbose
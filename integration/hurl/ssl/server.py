@@ -13,6 +13,14 @@ def hello():
     return "Hello World!"
 
 
+@app1.route("/proxy-header")
+def proxy_header():
+    # Headers sent to the proxy must not be forwarded to the host.
+    if "X-Proxy-Auth" in flask.request.headers:
+        return "Unexpected proxy header", 400
+    return "No proxy header"
+
+
 def start_server(port, cert_file, use_client_certificate_authentication):
     ssl_context = get_ssl_context(cert_file, use_client_certificate_authentication)
     app1.run(port=port, ssl_context=ssl_context)
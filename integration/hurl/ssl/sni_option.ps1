@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl ssl/sni_option.hurl --sni localhost --cacert ssl/ca/cert.pem --ssl-no-revoke --verbose
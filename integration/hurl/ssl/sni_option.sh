@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl ssl/sni_option.hurl --sni localhost --cacert ssl/ca/cert.pem --verbose
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_error_parser/diagnostics_json_option.hurl --parse-only --diagnostics-json
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_error_parser/diagnostics_json_option.hurl --parse-only --diagnostics-json
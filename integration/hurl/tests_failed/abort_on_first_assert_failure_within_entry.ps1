@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_failed/abort_on_first_assert_failure_within_entry.hurl --abort-on-first-assert-failure-within-entry --continue-on-error
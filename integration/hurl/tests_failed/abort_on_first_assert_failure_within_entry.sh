@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_failed/abort_on_first_assert_failure_within_entry.hurl --abort-on-first-assert-failure-within-entry --continue-on-error
@@ -1,3 +1,3 @@
 Set-StrictMode -Version latest
 $ErrorActionPreference = 'Stop'
-hurl tests_failed/assert_file.hurl
+hurl --assert-file tests_failed/assert_file.txt tests_failed/assert_file.hurl
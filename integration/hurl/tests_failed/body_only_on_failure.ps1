@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --body-only-on-failure tests_failed/body_only_on_failure.hurl
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/body-only-on-failure")
+def body_only_on_failure():
+    return "Hello World!"
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --test --fail-fast tests_failed/fail_fast.1.hurl tests_failed/fail_fast.2.hurl tests_failed/fail_fast.3.hurl
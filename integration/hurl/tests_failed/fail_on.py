@@ -0,0 +1,11 @@
+from app import app
+
+
+@app.route("/fail-on/ok")
+def fail_on_ok():
+    return "Hello"
+
+
+@app.route("/fail-on/not-found")
+def fail_on_not_found():
+    return "Not Found", 404
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_failed/fail_on.hurl --fail-on 4xx,5xx
@@ -0,0 +1,15 @@
+from app import app
+from flask import Response
+
+
+@app.route("/max-header-size/small")
+def max_header_size_small():
+    return Response("Hello World!")
+
+
+@app.route("/max-header-size/big")
+def max_header_size_big():
+    resp = Response("Hello World!")
+    for i in range(100):
+        resp.headers[f"X-Header-{i}"] = "x" * 100
+    return resp
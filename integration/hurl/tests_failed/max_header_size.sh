@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_failed/max_header_size.hurl --max-header-size 1000
@@ -0,0 +1,12 @@
+from app import app
+from flask import Response
+
+
+@app.route("/predicate-no-duplicate-keys/duplicate")
+def predicate_no_duplicate_keys_duplicate():
+    return Response(
+        """{
+  "user": {"id": 1, "name": "Bob", "name": "Bill"}
+}""",
+        mimetype="application/json",
+    )
@@ -0,0 +1,23 @@
+from app import app
+from flask import Response
+
+
+@app.route("/predicates-document/malformed-json")
+def predicates_document_malformed_json():
+    return Response(
+        """{
+  "name": "Bob",
+}""",
+        mimetype="application/json",
+    )
+
+
+@app.route("/predicates-document/malformed-xml")
+def predicates_document_malformed_xml():
+    return Response(
+        """<food>
+  <banana>
+</food>
+""",
+        mimetype="application/xml",
+    )
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --print-curl-on-failure tests_failed/print_curl_on_failure.hurl
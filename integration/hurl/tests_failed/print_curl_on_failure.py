@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/print-curl-on-failure")
+def print_curl_on_failure():
+    return "Hello"
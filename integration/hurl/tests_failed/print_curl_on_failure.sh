@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --print-curl-on-failure tests_failed/print_curl_on_failure.hurl
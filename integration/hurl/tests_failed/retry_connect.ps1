@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_failed/retry_connect.hurl --verbose --continue-on-error --retry-connect 2 --retry-interval 10
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_failed/retry_connect.hurl --verbose --continue-on-error --retry-connect 2 --retry-interval 10
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --teardown tests_failed/teardown.1.hurl tests_failed/teardown.hurl
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --teardown tests_failed/teardown.1.hurl tests_failed/teardown.hurl
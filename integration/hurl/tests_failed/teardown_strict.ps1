@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --teardown tests_failed/teardown_strict.1.hurl --teardown-strict tests_failed/teardown_strict.hurl
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --teardown tests_failed/teardown_strict.1.hurl --teardown-strict tests_failed/teardown_strict.hurl
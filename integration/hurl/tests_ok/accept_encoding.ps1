@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --accept-encoding gzip tests_ok/accept_encoding.hurl
+hurl --compressed --accept-encoding none tests_ok/accept_encoding_none.hurl
@@ -0,0 +1,22 @@
+from app import app
+from flask import make_response, request
+from io import BytesIO
+
+
+@app.route("/accept-encoding")
+def accept_encoding():
+    accept_encoding = request.headers.get("Accept-Encoding")
+    if accept_encoding is None:
+        resp = make_response("Hello World!")
+        resp.headers["X-Accept-Encoding"] = "none"
+        return resp
+    assert accept_encoding == "gzip"
+    result = BytesIO()
+    result.write(
+        b"\x1f\x8b\x08\x00\xed\x0c\x84\x5f\x00\x03\xf3\x48\xcd\xc9\xc9\x57\x08\xcf\x2f\xca\x49\x51\x04\x00\xa3\x1c\x29\x1c\x0c\x00\x00\x00"
+    )
+    data = result.getvalue()
+    resp = make_response(data)
+    resp.headers["Content-Encoding"] = "gzip"
+    resp.headers["X-Accept-Encoding"] = accept_encoding
+    return resp
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --accept-encoding gzip tests_ok/accept_encoding.hurl
+hurl --compressed --accept-encoding none tests_ok/accept_encoding_none.hurl
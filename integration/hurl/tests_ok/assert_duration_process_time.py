@@ -0,0 +1,13 @@
+from app import app
+from flask import make_response
+import time
+
+
+@app.route("/process-time")
+def process_time():
+    start = time.monotonic()
+    time.sleep(0.1)
+    elapsed = int((time.monotonic() - start) * 1000)
+    resp = make_response("Hello World!")
+    resp.headers["X-Process-Time"] = str(elapsed)
+    return resp
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --assert-file tests_ok/assert_file.txt tests_ok/assert_file.hurl
@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-file")
+def assert_file():
+    return Response(
+        '{"name": "Bob", "age": 42, "tags": ["a", "b"], "address": {"city": "Paris"}}',
+        mimetype="application/json",
+    )
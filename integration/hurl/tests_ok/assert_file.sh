@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --assert-file tests_ok/assert_file.txt tests_ok/assert_file.hurl
@@ -0,0 +1,7 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-match-flags")
+def assert_match_flags():
+    return Response("Hello World!\nBye World!\n", mimetype="text/plain")
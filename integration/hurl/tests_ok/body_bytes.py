@@ -0,0 +1,13 @@
+from app import app
+from flask import Response, request
+
+
+@app.route("/body-bytes")
+def body_bytes():
+    return Response(b"\xff\x00\x2aHello", mimetype="application/octet-stream")
+
+
+@app.route("/body-bytes/echo", methods=["POST"])
+def body_bytes_echo():
+    assert request.data == b"\xff\x00\x2aHello"
+    return Response(request.data, mimetype="application/octet-stream")
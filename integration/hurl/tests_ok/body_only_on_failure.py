@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/body-only-on-failure-ok")
+def body_only_on_failure_ok():
+    return "Hello World!"
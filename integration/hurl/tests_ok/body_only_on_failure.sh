@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --body-only-on-failure tests_ok/body_only_on_failure.hurl
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --buffer-size 1024 tests_ok/buffer_size_option.hurl --verbose
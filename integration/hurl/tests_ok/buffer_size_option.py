@@ -0,0 +1,9 @@
+from app import app
+from flask import make_response
+
+
+@app.route("/buffer-size")
+def buffer_size():
+    resp = make_response(b"0123456789abcdef" * 65536)
+    resp.content_type = "application/octet-stream"
+    return resp
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --buffer-size 1024 tests_ok/buffer_size_option.hurl --verbose
@@ -0,0 +1,7 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/cassette.json) {
+    Remove-Item build/cassette.json
+}
+hurl --no-output --record build/cassette.json tests_ok/cassette_option.hurl
+hurl --replay build/cassette.json tests_ok/cassette_option.hurl
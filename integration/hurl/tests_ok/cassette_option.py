@@ -0,0 +1,9 @@
+from flask import request
+from app import app
+
+
+@app.route("/cassette", methods=["POST"])
+def cassette():
+    assert request.data == b"Hello"
+    name = request.args.get("name")
+    return f"Hello {name}"
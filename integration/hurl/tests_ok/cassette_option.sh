@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/cassette.json
+hurl --no-output --record build/cassette.json tests_ok/cassette_option.hurl
+# Replay recorded exchanges, without any network access.
+hurl --replay build/cassette.json tests_ok/cassette_option.hurl
@@ -0,0 +1,17 @@
+import gzip
+
+from app import app
+from flask import make_response
+
+
+@app.route("/compression-ratio/gzip")
+def compression_ratio_gzip():
+    data = gzip.compress(b"Hello World! " * 100)
+    resp = make_response(data)
+    resp.headers["Content-Encoding"] = "gzip"
+    return resp
+
+
+@app.route("/compression-ratio/none")
+def compression_ratio_none():
+    return "Hello World!"
@@ -0,0 +1,35 @@
+import gzip
+
+from app import app
+from flask import make_response
+
+PNG = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR"
+
+
+@app.route("/content-type-sniffing/png")
+def content_type_sniffing_png():
+    resp = make_response(PNG)
+    resp.content_type = "application/octet-stream"
+    return resp
+
+
+@app.route("/content-type-sniffing/pdf")
+def content_type_sniffing_pdf():
+    resp = make_response(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+    resp.content_type = "text/plain; charset=utf-8"
+    return resp
+
+
+@app.route("/content-type-sniffing/gzip")
+def content_type_sniffing_gzip():
+    resp = make_response(gzip.compress(b"Hello World!"))
+    resp.content_type = "application/octet-stream"
+    return resp
+
+
+@app.route("/content-type-sniffing/gzip-encoded")
+def content_type_sniffing_gzip_encoded():
+    resp = make_response(gzip.compress(PNG))
+    resp.content_type = "application/octet-stream"
+    resp.headers["Content-Encoding"] = "gzip"
+    return resp
@@ -0,0 +1,14 @@
+from flask import redirect, request
+from app import app
+
+
+@app.route("/cookies-scoped/redirect")
+def cookies_scoped_redirect():
+    assert request.headers["Cookie"] == "theme=light"
+    return redirect("/cookies-scoped/api/check")
+
+
+@app.route("/cookies-scoped/api/check")
+def cookies_scoped_api_check():
+    assert request.headers["Cookie"] == "token=abc123"
+    return ""
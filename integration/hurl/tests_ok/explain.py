@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/explain")
+def explain():
+    return Response(
+        '{"name": "Bob", "age": 42, "friends": ["Alice", "Eve"]}',
+        mimetype="application/json",
+    )
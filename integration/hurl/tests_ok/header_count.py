@@ -0,0 +1,12 @@
+from app import app
+from flask import Response
+
+
+@app.route("/header-count")
+def header_count():
+    resp = Response("Hello World!")
+    resp.headers["x-foo"] = "xxx"
+    resp.headers.add("x-bar", "yyy0")
+    resp.headers.add("x-bar", "yyy1")
+    resp.headers.add("x-bar", "yyy2")
+    return resp
@@ -0,0 +1,9 @@
+from app import app
+from flask import Response
+
+
+@app.route("/header-match")
+def header_match():
+    resp = Response("Hello World!")
+    resp.headers["X-Request-ID"] = "abc"
+    return resp
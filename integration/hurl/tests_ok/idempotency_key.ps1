@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/idempotency_key.hurl --idempotency-key-header Idempotency-Key --retry 2 --retry-interval 10
@@ -0,0 +1,21 @@
+from app import app
+from flask import request
+
+idempotency_key_attempts = {}
+
+
+@app.route("/idempotency-key", methods=["POST"])
+def idempotency_key():
+    # The first request with a given key fails, so the entry is retried with the same key.
+    key = request.headers["Idempotency-Key"]
+    attempts = idempotency_key_attempts.get(key, 0) + 1
+    idempotency_key_attempts[key] = attempts
+    if attempts == 1:
+        return "", 500
+    return {"key": key, "attempts": attempts}
+
+
+@app.route("/idempotency-key/get")
+def idempotency_key_get():
+    assert "Idempotency-Key" not in request.headers
+    return ""
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/idempotency_key.hurl --idempotency-key-header Idempotency-Key --retry 2 --retry-interval 10
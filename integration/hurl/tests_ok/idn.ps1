@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/idn.hurl --resolve xn--r8jz45g.jp:8000:127.0.0.1 --verbose
@@ -0,0 +1,8 @@
+from flask import request
+from app import app
+
+
+@app.route("/idn")
+def idn():
+    assert request.host == "xn--r8jz45g.jp:8000"
+    return "Hello World!"
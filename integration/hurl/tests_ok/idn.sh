@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/idn.hurl --resolve xn--r8jz45g.jp:8000:127.0.0.1 --verbose
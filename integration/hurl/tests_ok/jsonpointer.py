@@ -0,0 +1,18 @@
+from app import app
+from flask import Response
+
+
+@app.route("/jsonpointer")
+def jsonpointer():
+    return Response(
+        """{
+  "data": [
+    {"id": 1, "name": "Bob"},
+    {"id": 2, "name": "Bill", "tags": ["a", "b"]}
+  ],
+  "a/b": "slash",
+  "m~n": "tilde",
+  "": "empty"
+}""",
+        mimetype="application/json",
+    )
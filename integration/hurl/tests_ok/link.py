@@ -0,0 +1,12 @@
+from app import app
+from flask import Response
+
+
+@app.route("/link/items")
+def link_items():
+    response = Response("page 1")
+    response.headers["Link"] = (
+        '<http://localhost:8000/link/items?page=2>; rel="next", '
+        '</link/items?page=5>; rel="last"'
+    )
+    return response
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/methods/template")
+def method_template():
+    return "PUT"
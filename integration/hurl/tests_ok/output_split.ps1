@@ -0,0 +1,11 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/output_split) {
+    Remove-Item -Recurse build/output_split
+}
+hurl --output-split build/output_split --output build/output_split.bin tests_ok/output_split.hurl
+foreach ($file in Get-ChildItem build/output_split | Sort-Object Name) {
+    Write-Host "build/output_split/$($file.Name)"
+    Write-Host (Get-Content $file.FullName -Raw) -NoNewLine
+}
+Write-Host (Get-Content build/output_split.bin -Raw) -NoNewLine
@@ -0,0 +1,9 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -rf build/output_split
+hurl --output-split build/output_split --output build/output_split.bin tests_ok/output_split.hurl
+for file in build/output_split/*; do
+    echo "$file"
+    cat "$file"
+done
+cat build/output_split.bin
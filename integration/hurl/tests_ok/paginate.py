@@ -0,0 +1,16 @@
+from app import app
+from flask import Response, request
+
+items = [{"id": 1}, {"id": 2}, {"id": 3}, {"id": 4}, {"id": 5}]
+
+
+@app.route("/paginate/items")
+def paginate_items():
+    page = int(request.args.get("page", "1"))
+    per_page = 3
+    start = (page - 1) * per_page
+    body = items[start : start + per_page]
+    response = Response(str(body).replace("'", '"'), mimetype="application/json")
+    if start + per_page < len(items):
+        response.headers["Link"] = f'</paginate/items?page={page + 1}>; rel="next"'
+    return response
@@ -0,0 +1,14 @@
+from flask import Response
+from app import app
+
+
+@app.route("/partial-body")
+def partial_body():
+    def generate():
+        yield "Hello Bob!\n"
+        # A large body that is not read by Hurl with --partial-body.
+        chunk = "a" * 1024 * 1024
+        for _ in range(100):
+            yield chunk
+
+    return Response(generate(), mimetype="text/plain")
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --partial-body --no-output tests_ok/partial_body.hurl
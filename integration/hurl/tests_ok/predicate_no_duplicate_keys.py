@@ -0,0 +1,13 @@
+from app import app
+from flask import Response
+
+
+@app.route("/predicate-no-duplicate-keys")
+def predicate_no_duplicate_keys():
+    return Response(
+        """{
+  "user": {"id": 1, "name": "Bob"},
+  "tags": [{"id": 1}, {"id": 2}]
+}""",
+        mimetype="application/json",
+    )
@@ -0,0 +1,39 @@
+from app import app
+from flask import Response
+
+
+@app.route("/predicates-document/json")
+def predicates_document_json():
+    return Response(
+        """{
+  "name": "Bob",
+  "tags": ["a", "b"]
+}""",
+        mimetype="application/json",
+    )
+
+
+@app.route("/predicates-document/xml")
+def predicates_document_xml():
+    return Response(
+        """<?xml version="1.0" encoding="utf-8"?>
+<food>
+  <banana type="fruit" price="1.1"/>
+</food>
+""",
+        mimetype="application/xml",
+    )
+
+
+@app.route("/predicates-document/html")
+def predicates_document_html():
+    return Response(
+        """<!DOCTYPE html>
+<html>
+  <body>
+    <section><p>Hello</p></section>
+  </body>
+</html>
+""",
+        mimetype="text/html",
+    )
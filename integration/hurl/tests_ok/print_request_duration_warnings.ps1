@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --no-output --print-request-duration-warnings --slow-warn-ms 400 tests_ok/print_request_duration_warnings.hurl
@@ -0,0 +1,8 @@
+from app import app
+import time
+
+
+@app.route("/print-request-duration-warnings")
+def print_request_duration_warnings():
+    time.sleep(0.5)
+    return "Hello World!"
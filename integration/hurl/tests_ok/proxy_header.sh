@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/proxy_header.hurl --insecure --proxy localhost:8004 --proxy-header "X-Proxy-Auth: secret"
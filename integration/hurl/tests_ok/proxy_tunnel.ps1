@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/proxy_tunnel.hurl --proxy localhost:8004 --proxy-header "X-Proxy-Auth: secret" --proxy-tunnel
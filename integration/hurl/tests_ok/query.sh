@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --query 'jsonpath "$.user.id"' --input tests_ok/query.json
+hurl --query 'jsonpath "$.user.name"' --input tests_ok/query.json
+hurl --query 'jsonpath "$.tags" count' --input tests_ok/query.json
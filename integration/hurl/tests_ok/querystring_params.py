@@ -18,3 +18,10 @@ def querystring_params_encoded():
     assert request.args.get("value2") == "/"
     assert request.args.get("value3") == "/"
     return ""
+
+
+@app.route("/querystring-params-reserved")
+def querystring_params_reserved():
+    assert request.args.get("filter[name]") == "x=1&y=2 #z"
+    assert request.args.get("query?") == "100% sure/+"
+    return ""
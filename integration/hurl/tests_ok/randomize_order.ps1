@@ -0,0 +1,7 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+# Whatever the order, the capture is always done before being used.
+foreach ($seed in 1..5) {
+    hurl --randomize-order --seed "$seed" --no-output tests_ok/randomize_order.hurl
+    if ($LASTEXITCODE -ne 0) { Exit $LASTEXITCODE }
+}
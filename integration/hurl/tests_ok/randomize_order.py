@@ -0,0 +1,13 @@
+from flask import request, Response
+from app import app
+
+
+@app.route("/randomize-order/login")
+def randomize_order_login():
+    return Response(headers={"X-Token": "abcd"})
+
+
+@app.route("/randomize-order/protected")
+def randomize_order_protected():
+    assert request.headers["Authorization"] == "Bearer abcd"
+    return ""
@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+# Whatever the order, the capture is always done before being used.
+for seed in 1 2 3 4 5; do
+    hurl --randomize-order --seed "$seed" --no-output tests_ok/randomize_order.hurl
+done
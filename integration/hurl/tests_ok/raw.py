@@ -0,0 +1,7 @@
+from app import app
+from flask import Response
+
+
+@app.route("/raw")
+def raw():
+    return Response("Hello World!", mimetype="text/plain")
@@ -0,0 +1,9 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/report_sort.tap) {
+    Remove-Item build/report_sort.tap
+}
+
+hurl --test --report-tap build/report_sort.tap --report-sort name tests_ok/test.3.hurl tests_ok/test.1.hurl
+
+Write-Host (Get-Content build/report_sort.tap -Raw) -NoNewLine
@@ -0,0 +1,7 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/report_sort.tap
+
+hurl --test --report-tap build/report_sort.tap --report-sort name tests_ok/test.3.hurl tests_ok/test.1.hurl
+
+cat build/report_sort.tap
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/resolve_from_file.hurl --resolve foo.com:8000:127.0.0.1 --resolve-from-file tests_ok/resolve_from_file.txt
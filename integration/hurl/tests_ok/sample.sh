@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+# A given seed always selects the same 10% of the files.
+hurl --test --sample 10% --seed 42 --glob "tests_ok/sample.*.hurl"
+hurl --test --sample 10% --seed 42 --glob "tests_ok/sample.*.hurl"
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --variable mode=slow --set-at 3:mode=fast --no-output tests_ok/set_at.hurl
@@ -0,0 +1,7 @@
+from app import app
+from flask import request
+
+
+@app.route("/set-at")
+def set_at():
+    return request.args.get("mode")
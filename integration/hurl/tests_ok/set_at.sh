@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --variable mode=slow --set-at 3:mode=fast --no-output tests_ok/set_at.hurl
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --setup tests_ok/setup.1.hurl tests_ok/setup.hurl
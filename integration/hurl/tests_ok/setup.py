@@ -0,0 +1,15 @@
+from app import app
+from flask import request
+
+
+@app.route("/setup/login", methods=["POST"])
+def setup_login():
+    assert request.form["user"] == "bob"
+    return {"token": "d1f8e3a2"}
+
+
+@app.route("/setup/private")
+def setup_private():
+    if request.headers.get("Authorization") != "Bearer d1f8e3a2":
+        return "Unauthorized", 401
+    return "Welcome Bob!"
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --setup tests_ok/setup.1.hurl tests_ok/setup.hurl
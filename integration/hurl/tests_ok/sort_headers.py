@@ -0,0 +1,12 @@
+from app import app
+from flask import Response
+
+
+@app.route("/sort-headers")
+def sort_headers():
+    resp = Response("Hello")
+    resp.headers["x-foo"] = "xxx"
+    resp.headers.add("x-bar", "yyy0")
+    resp.headers.add("x-bar", "yyy1")
+    resp.headers["x-baz"] = "zzz"
+    return resp
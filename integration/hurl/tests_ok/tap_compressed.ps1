@@ -0,0 +1,17 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/result_compressed.tap.gz) {
+    Remove-Item build/result_compressed.tap.gz
+}
+
+# test.2.hurl is KO but we want the script to continue until the end
+$ErrorActionPreference = 'Continue'
+hurl --test --compress-reports --report-tap build/result_compressed.tap tests_ok/test.1.hurl tests_ok/test.2.hurl
+hurl --test --compress-reports --report-tap build/result_compressed.tap tests_ok/test.3.hurl
+$ErrorActionPreference = 'Stop'
+
+$file = [System.IO.File]::OpenRead("build/result_compressed.tap.gz")
+$gzip = New-Object System.IO.Compression.GZipStream($file, [System.IO.Compression.CompressionMode]::Decompress)
+$reader = New-Object System.IO.StreamReader($gzip)
+Write-Host $reader.ReadToEnd() -NoNewLine
+$reader.Close()
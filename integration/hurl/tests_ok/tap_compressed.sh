@@ -0,0 +1,11 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/result_compressed.tap.gz
+
+# test.2.hurl is KO but we want the script to continue until the end
+set +eo pipefail
+hurl --test --compress-reports --report-tap build/result_compressed.tap tests_ok/test.1.hurl tests_ok/test.2.hurl
+hurl --test --compress-reports --report-tap build/result_compressed.tap tests_ok/test.3.hurl
+set -Eeuo pipefail
+
+gunzip -c build/result_compressed.tap.gz
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --teardown tests_ok/teardown.1.hurl tests_ok/teardown.hurl
@@ -0,0 +1,21 @@
+from app import app
+from flask import request
+
+items = {}
+
+
+@app.route("/teardown/items", methods=["POST"])
+def teardown_create_item():
+    item = request.get_json()
+    items[item["id"]] = item
+    return item, 201
+
+
+@app.route("/teardown/items/<id>", methods=["GET", "DELETE"])
+def teardown_item(id):
+    if id not in items:
+        return "", 404
+    if request.method == "DELETE":
+        del items[id]
+        return "", 204
+    return items[id]
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --teardown tests_ok/teardown.1.hurl tests_ok/teardown.hurl
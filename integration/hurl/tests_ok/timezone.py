@@ -0,0 +1,7 @@
+from app import app
+from flask import Response
+
+
+@app.route("/timezone")
+def timezone():
+    return Response('{"updated": "2024-01-02 10:00:00"}', mimetype="application/json")
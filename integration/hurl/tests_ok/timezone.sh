@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --timezone +02:00 tests_ok/timezone.hurl
@@ -0,0 +1,11 @@
+from app import app
+from flask import request
+
+
+@app.route("/trace-context")
+def trace_context():
+    # Returns the received W3C Trace Context headers.
+    return {
+        "traceparent": request.headers.get("traceparent"),
+        "tracestate": request.headers.get("tracestate"),
+    }
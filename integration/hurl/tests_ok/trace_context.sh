@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/trace_context.hurl --trace-context --trace-state hurl=test
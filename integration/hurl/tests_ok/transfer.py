@@ -0,0 +1,19 @@
+import gzip
+
+from app import app
+from flask import make_response, request
+
+
+@app.route("/transfer", methods=["GET", "POST"])
+def transfer():
+    if request.method == "POST":
+        assert request.data == b"Hello"
+    return "Hello World!"
+
+
+@app.route("/transfer/compressed")
+def transfer_compressed():
+    data = gzip.compress(b"Hello World!", mtime=0)
+    resp = make_response(data)
+    resp.headers["Content-Encoding"] = "gzip"
+    return resp
@@ -0,0 +1,16 @@
+from app import app
+from flask import Response
+
+
+@app.route("/transfer-encoding/chunked")
+def transfer_encoding_chunked():
+    def generate():
+        yield "Hello "
+        yield "World!"
+
+    return Response(generate())
+
+
+@app.route("/transfer-encoding/identity")
+def transfer_encoding_identity():
+    return "Hello World!"
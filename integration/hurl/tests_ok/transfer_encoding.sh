@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --no-output tests_ok/transfer_encoding.hurl
@@ -0,0 +1,16 @@
+from app import app
+from flask import Response
+
+
+@app.route("/verify-content-length")
+def verify_content_length():
+    return "Hello World!"
+
+
+@app.route("/verify-content-length/chunked")
+def verify_content_length_chunked():
+    def generate():
+        yield "Hello "
+        yield "World!"
+
+    return Response(generate())
@@ -0,0 +1,70 @@
+#!/usr/bin/env python3
+# usage: ./server.py <port>
+# Start a tunneling proxy (HTTP CONNECT) that only accepts requests with "X-Proxy-Auth: secret" header.
+import socket
+import sys
+import threading
+
+
+def handle(client):
+    with client:
+        request = b""
+        while b"\r\n\r\n" not in request:
+            data = client.recv(4096)
+            if not data:
+                return
+            request += data
+        head, _, rest = request.partition(b"\r\n\r\n")
+        lines = head.decode("latin-1").split("\r\n")
+        method, target, _ = lines[0].split(" ", 2)
+        headers = [line.split(":", 1) for line in lines[1:]]
+        headers = {name.strip().lower(): value.strip() for name, value in headers}
+
+        if method != "CONNECT":
+            client.sendall(
+                b"HTTP/1.1 405 Method Not Allowed\r\nContent-Length: 0\r\n\r\n"
+            )
+            return
+        if headers.get("x-proxy-auth") != "secret":
+            client.sendall(
+                b"HTTP/1.1 407 Proxy Authentication Required\r\nContent-Length: 0\r\n\r\n"
+            )
+            return
+
+        host, port = target.rsplit(":", 1)
+        with socket.create_connection((host, int(port))) as upstream:
+            client.sendall(b"HTTP/1.1 200 Connection Established\r\n\r\n")
+            if rest:
+                upstream.sendall(rest)
+            threading.Thread(target=pipe, args=(upstream, client), daemon=True).start()
+            pipe(client, upstream)
+
+
+def pipe(source, destination):
+    try:
+        while True:
+            data = source.recv(4096)
+            if not data:
+                break
+            destination.sendall(data)
+        destination.shutdown(socket.SHUT_WR)
+    except OSError:
+        pass
+
+
+def main():
+    if len(sys.argv) < 2:
+        print("usage: ./server.py <port>")
+        sys.exit(1)
+    port = int(sys.argv[1])
+
+    print("Starting tunnel proxy")
+    print("  port: " + str(port))
+    with socket.create_server(("127.0.0.1", port)) as server:
+        while True:
+            client, _ = server.accept()
+            threading.Thread(target=handle, args=(client,), daemon=True).start()
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,131 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use base64::engine::general_purpose;
+use base64::Engine;
+
+use crate::http::{Request, Response};
+
+/// Returns a Hurl entry for the HTTP `request` and its `response`.
+///
+/// The response section only asserts the status code of the response.
+pub fn write_entry(request: &Request, response: &Response) -> String {
+    let mut s = format!("{} {}\n", request.method, request.url);
+    for header in &request.headers {
+        let name = escape_key(&header.name);
+        let value = escape_value(&header.value);
+        s.push_str(&format!("{name}: {value}\n"));
+    }
+    if !request.body.is_empty() {
+        s.push_str(&write_body(&request.body));
+        s.push('\n');
+    }
+    s.push_str(&format!("HTTP {}\n", response.status));
+    s
+}
+
+/// Returns a Hurl body for these `bytes`.
+///
+/// Text bodies are written as oneline or multiline strings when they can be written verbatim,
+/// other bodies are written as base64.
+fn write_body(bytes: &[u8]) -> String {
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        if !text.contains("{{") {
+            if !text.contains(['\n', '`', '\\']) {
+                return format!("`{text}`");
+            }
+            if text.ends_with('\n') && !text.contains("```") {
+                return format!("```\n{text}```");
+            }
+        }
+    }
+    let encoded = general_purpose::STANDARD.encode(bytes);
+    format!("base64,{encoded};")
+}
+
+/// Escapes a header name `s` to be used as a Hurl key.
+fn escape_key(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        if matches!(c, '#' | ':' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Escapes a header value `s` to be used as a Hurl value.
+///
+/// As `{{` is the start of a template, we escape the first brace as a unicode character.
+fn escape_value(s: &str) -> String {
+    let mut escaped = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '#' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '{' if chars.peek() == Some(&'{') => escaped.push_str("\\u{7b}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{Header, HeaderVec};
+
+    #[test]
+    fn test_write_entry() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "application/json"));
+        headers.push(Header::new("X-Color", "#ff0000 {{red}}"));
+        let request = Request::new(
+            "POST",
+            "http://localhost:8000/users",
+            headers,
+            br#"{"name":"Bob"}"#.to_vec(),
+        );
+        let response = Response {
+            status: 201,
+            ..Default::default()
+        };
+        assert_eq!(
+            write_entry(&request, &response),
+            r#"POST http://localhost:8000/users
+Content-Type: application/json
+X-Color: \#ff0000 \u{7b}{red}}
+`{"name":"Bob"}`
+HTTP 201
+"#
+        );
+        let content = write_entry(&request, &response);
+        assert!(hurl_core::parser::parse_hurl_file(&content).is_ok());
+    }
+
+    #[test]
+    fn test_write_body() {
+        assert_eq!(write_body(b"Hello"), "`Hello`");
+        assert_eq!(write_body(b"line1\nline2\n"), "```\nline1\nline2\n```");
+        assert_eq!(write_body(b"line1\nline2"), "base64,bGluZTEKbGluZTI=;");
+        assert_eq!(write_body(&[0xff, 0x00]), "base64,/wA=;");
+    }
+}
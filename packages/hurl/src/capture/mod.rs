@@ -0,0 +1,26 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Capture of live HTTP traffic to Hurl files.
+//!
+//! A [`CaptureProxy`] is a local HTTP/1.1 proxy that forwards the requests it receives, and
+//! records each request and its response as a Hurl entry.
+mod hurl;
+mod proxy;
+
+pub use self::hurl::write_entry;
+pub use self::proxy::CaptureProxy;
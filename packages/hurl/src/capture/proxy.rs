@@ -0,0 +1,476 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::io;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::time::Duration;
+
+use crate::capture::write_entry;
+use crate::http::{
+    Body, Client, ClientOptions, Header, HeaderVec, Method, Request, RequestSpec, Response,
+};
+use crate::util::logger::{BaseLogger, Logger, LoggerOptionsBuilder};
+use crate::util::term::{Stderr, WriteMode};
+
+/// Headers that are only meaningful for a single connection, and are neither forwarded nor
+/// recorded.
+const HOP_BY_HOP_HEADERS: [&str; 7] = [
+    "Connection",
+    "Content-Length",
+    "Host",
+    "Keep-Alive",
+    "Proxy-Authorization",
+    "Proxy-Connection",
+    "Transfer-Encoding",
+];
+
+/// Maximum size of a request body accepted by the proxy (the whole body is kept in memory).
+const MAX_BODY_SIZE: usize = 64 * 1024 * 1024;
+
+/// Time to wait for a client to send its request, so an idle connection doesn't block the proxy.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A local HTTP/1.1 proxy that records the HTTP traffic passing through it as Hurl entries.
+///
+/// Each connection serves a single request: responses are sent with a `Connection: close` header.
+/// HTTPS tunneling (through `CONNECT` requests) is not supported.
+pub struct CaptureProxy {
+    listener: TcpListener,
+    client: Client,
+    options: ClientOptions,
+    read_timeout: Duration,
+}
+
+impl CaptureProxy {
+    /// Creates a new capture proxy, listening on `addr`.
+    ///
+    /// `addr` is a `HOST:PORT` address, the host may be omitted (`:8080`) to listen on localhost.
+    pub fn bind(addr: &str) -> Result<Self, io::Error> {
+        let addr = match addr.strip_prefix(':') {
+            Some(port) => format!("127.0.0.1:{port}"),
+            None => addr.to_string(),
+        };
+        let listener = TcpListener::bind(addr)?;
+        let options = ClientOptions {
+            path_as_is: true,
+            ..Default::default()
+        };
+        Ok(CaptureProxy {
+            listener,
+            client: Client::new(),
+            options,
+            read_timeout: READ_TIMEOUT,
+        })
+    }
+
+    /// Returns the local address this proxy is listening on.
+    pub fn local_addr(&self) -> Result<SocketAddr, io::Error> {
+        self.listener.local_addr()
+    }
+
+    /// Serves the incoming requests forever, writing a Hurl entry to `output` for each
+    /// forwarded request.
+    ///
+    /// An error on a connection is reported with `logger`, and doesn't stop the proxy.
+    pub fn run(&mut self, output: &mut dyn Write, logger: &BaseLogger) -> Result<(), io::Error> {
+        let mut first = true;
+        loop {
+            let entry = match self.serve() {
+                Ok(Some(entry)) => entry,
+                Ok(None) => continue,
+                Err(error) => {
+                    logger.warning(&format!("connection error: {error}"));
+                    continue;
+                }
+            };
+            if !first {
+                output.write_all(b"\n")?;
+            }
+            output.write_all(entry.as_bytes())?;
+            output.flush()?;
+            first = false;
+        }
+    }
+
+    /// Accepts a connection, forwards its request and sends back the response.
+    ///
+    /// Returns the Hurl entry of the exchange, or `None` if the request has not been forwarded.
+    pub fn serve(&mut self) -> Result<Option<String>, io::Error> {
+        let (mut stream, _) = self.listener.accept()?;
+        stream.set_read_timeout(Some(self.read_timeout))?;
+        let request = match read_request(&mut stream) {
+            Ok(request) => request,
+            Err(RequestError::BadRequest(message)) => {
+                write_error(&mut stream, 400, &format!("{message}\n"))?;
+                return Ok(None);
+            }
+            Err(RequestError::TooLarge) => {
+                let body = format!("request body exceeds {MAX_BODY_SIZE} bytes\n");
+                write_error(&mut stream, 413, &body)?;
+                return Ok(None);
+            }
+            Err(RequestError::NotImplemented(message)) => {
+                write_error(&mut stream, 501, &format!("{message}\n"))?;
+                return Ok(None);
+            }
+            Err(RequestError::Io(error))
+                if matches!(
+                    error.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                write_error(&mut stream, 408, "request not received in time\n")?;
+                return Ok(None);
+            }
+            Err(RequestError::Io(error)) => return Err(error),
+        };
+
+        // We forward the request with our HTTP client, using a quiet logger.
+        let logger_options = LoggerOptionsBuilder::new().build();
+        let mut logger = Logger::new(&logger_options, Stderr::new(WriteMode::Immediate));
+        let request_spec = RequestSpec {
+            method: Method(request.method.clone()),
+            url: request.url.clone(),
+            headers: request.headers.clone(),
+            body: Body::Binary(request.body.clone()),
+            ..Default::default()
+        };
+        let Ok(call) = self
+            .client
+            .execute(&request_spec, &self.options, &mut logger)
+        else {
+            let body = format!("could not forward request to {}\n", request.url);
+            write_error(&mut stream, 502, &body)?;
+            return Ok(None);
+        };
+
+        write_response(&mut stream, &call.response)?;
+        Ok(Some(write_entry(&request, &call.response)))
+    }
+}
+
+/// Reads an HTTP/1.1 request from a `stream`.
+///
+/// The request URL is made absolute (with the `Host` header if the request target is a path),
+/// and hop-by-hop headers are removed.
+fn read_request(stream: &mut TcpStream) -> Result<Request, RequestError> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let tokens = line.split_whitespace().collect::<Vec<_>>();
+    let [method, target, _version] = tokens[..] else {
+        return Err(RequestError::BadRequest("invalid request line"));
+    };
+    if method == "CONNECT" {
+        return Err(RequestError::NotImplemented(
+            "HTTPS tunneling is not supported",
+        ));
+    }
+
+    let mut all_headers = HeaderVec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(RequestError::BadRequest("invalid header"));
+        };
+        all_headers.push(Header::new(name.trim(), value.trim()));
+    }
+    if all_headers.contains_key("Transfer-Encoding") {
+        return Err(RequestError::NotImplemented(
+            "chunked request body is not supported",
+        ));
+    }
+
+    let url = if target.starts_with('/') {
+        let Some(host) = all_headers.get("Host") else {
+            return Err(RequestError::BadRequest("missing Host header"));
+        };
+        format!("http://{}{target}", host.value)
+    } else {
+        target.to_string()
+    };
+
+    let mut body = vec![];
+    if let Some(length) = all_headers.get("Content-Length") {
+        let Ok(length) = length.value.parse::<usize>() else {
+            return Err(RequestError::BadRequest("invalid Content-Length header"));
+        };
+        if length > MAX_BODY_SIZE {
+            return Err(RequestError::TooLarge);
+        }
+        reader.take(length as u64).read_to_end(&mut body)?;
+        if body.len() < length {
+            return Err(RequestError::BadRequest("incomplete request body"));
+        }
+    }
+
+    let mut headers = HeaderVec::new();
+    all_headers
+        .iter()
+        .filter(|h| !HOP_BY_HOP_HEADERS.iter().any(|name| h.name_eq(name)))
+        .for_each(|h| headers.push(h.clone()));
+
+    Ok(Request::new(method, &url, headers, body))
+}
+
+/// Writes the HTTP `response` to the `stream`.
+///
+/// The response body has already been decoded from its transfer encoding by our HTTP client, so
+/// it is sent back with a `Content-Length` header.
+fn write_response(stream: &mut TcpStream, response: &Response) -> Result<(), io::Error> {
+    let status = response.status;
+    let mut head = format!("HTTP/1.1 {status} {}\r\n", reason_phrase(status));
+    for header in &response.headers {
+        if HOP_BY_HOP_HEADERS.iter().any(|name| header.name_eq(name)) {
+            continue;
+        }
+        head.push_str(&format!("{}: {}\r\n", header.name, header.value));
+    }
+    head.push_str(&format!("Content-Length: {}\r\n", response.body.len()));
+    head.push_str("Connection: close\r\n\r\n");
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(&response.body)?;
+    stream.flush()
+}
+
+/// Writes an error response with a `status` and a text `body` to the `stream`.
+fn write_error(stream: &mut TcpStream, status: u32, body: &str) -> Result<(), io::Error> {
+    let reason = reason_phrase(status);
+    let response = format!(
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes())?;
+    stream.flush()
+}
+
+/// Returns the canonical reason phrase of a `status` code (see [RFC 9110]), or an empty string for
+/// an unknown status code.
+///
+/// [RFC 9110]: https://www.rfc-editor.org/rfc/rfc9110#name-status-codes
+fn reason_phrase(status: u32) -> &'static str {
+    match status {
+        100 => "Continue",
+        101 => "Switching Protocols",
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        203 => "Non-Authoritative Information",
+        204 => "No Content",
+        205 => "Reset Content",
+        206 => "Partial Content",
+        300 => "Multiple Choices",
+        301 => "Moved Permanently",
+        302 => "Found",
+        303 => "See Other",
+        304 => "Not Modified",
+        305 => "Use Proxy",
+        307 => "Temporary Redirect",
+        308 => "Permanent Redirect",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        402 => "Payment Required",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        406 => "Not Acceptable",
+        407 => "Proxy Authentication Required",
+        408 => "Request Timeout",
+        409 => "Conflict",
+        410 => "Gone",
+        411 => "Length Required",
+        412 => "Precondition Failed",
+        413 => "Content Too Large",
+        414 => "URI Too Long",
+        415 => "Unsupported Media Type",
+        416 => "Range Not Satisfiable",
+        417 => "Expectation Failed",
+        421 => "Misdirected Request",
+        422 => "Unprocessable Content",
+        426 => "Upgrade Required",
+        428 => "Precondition Required",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        505 => "HTTP Version Not Supported",
+        _ => "",
+    }
+}
+
+/// Error while reading a request from a client.
+#[derive(Debug)]
+enum RequestError {
+    /// The request is malformed, answered with a `400 Bad Request`.
+    BadRequest(&'static str),
+    /// The request body exceeds [`MAX_BODY_SIZE`], answered with a `413 Content Too Large`.
+    TooLarge,
+    /// The request uses a feature not supported by the proxy, answered with a
+    /// `501 Not Implemented`.
+    NotImplemented(&'static str),
+    /// The connection has failed, no response can be sent.
+    Io(io::Error),
+}
+
+impl From<io::Error> for RequestError {
+    fn from(error: io::Error) -> Self {
+        RequestError::Io(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    /// Starts an HTTP server answering one request with "Hello World!", and returns its address.
+    fn start_hello_server() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+            }
+            let response = "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 12\r\n\r\nHello World!";
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        addr
+    }
+
+    #[test]
+    fn test_capture_one_request() {
+        let server_addr = start_hello_server();
+        let mut proxy = CaptureProxy::bind("127.0.0.1:0").unwrap();
+        let proxy_addr = proxy.local_addr().unwrap();
+
+        let client = thread::spawn(move || {
+            let mut stream = TcpStream::connect(proxy_addr).unwrap();
+            let request = format!(
+                "GET http://{server_addr}/hello HTTP/1.1\r\nHost: {server_addr}\r\nAccept: text/plain\r\nProxy-Connection: Keep-Alive\r\n\r\n"
+            );
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        let entry = proxy.serve().unwrap().unwrap();
+        assert_eq!(
+            entry,
+            format!("GET http://{server_addr}/hello\nAccept: text/plain\nHTTP 200\n")
+        );
+
+        let response = client.join().unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Length: 12\r\n"));
+        assert!(response.ends_with("\r\n\r\nHello World!"));
+    }
+
+    #[test]
+    fn test_capture_connect_not_supported() {
+        let mut proxy = CaptureProxy::bind("127.0.0.1:0").unwrap();
+        let proxy_addr = proxy.local_addr().unwrap();
+
+        let client = thread::spawn(move || {
+            let mut stream = TcpStream::connect(proxy_addr).unwrap();
+            let request = "CONNECT example.org:443 HTTP/1.1\r\nHost: example.org:443\r\n\r\n";
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        assert_eq!(proxy.serve().unwrap(), None);
+        let response = client.join().unwrap();
+        assert!(response.starts_with("HTTP/1.1 501 Not Implemented\r\n"));
+    }
+
+    #[test]
+    fn test_capture_bad_request() {
+        let mut proxy = CaptureProxy::bind("127.0.0.1:0").unwrap();
+        let proxy_addr = proxy.local_addr().unwrap();
+
+        let client = thread::spawn(move || {
+            let mut stream = TcpStream::connect(proxy_addr).unwrap();
+            let request = "GET /hello HTTP/1.1\r\nAccept: text/plain\r\n\r\n";
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        assert_eq!(proxy.serve().unwrap(), None);
+        let response = client.join().unwrap();
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+        assert!(response.ends_with("\r\n\r\nmissing Host header\n"));
+    }
+
+    #[test]
+    fn test_capture_idle_connection() {
+        let mut proxy = CaptureProxy::bind("127.0.0.1:0").unwrap();
+        proxy.read_timeout = Duration::from_millis(100);
+        let proxy_addr = proxy.local_addr().unwrap();
+
+        // The client connects but never sends its request.
+        let client = thread::spawn(move || {
+            let mut stream = TcpStream::connect(proxy_addr).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        assert_eq!(proxy.serve().unwrap(), None);
+        let response = client.join().unwrap();
+        assert!(response.starts_with("HTTP/1.1 408 Request Timeout\r\n"));
+    }
+
+    #[test]
+    fn test_capture_body_too_large() {
+        let mut proxy = CaptureProxy::bind("127.0.0.1:0").unwrap();
+        let proxy_addr = proxy.local_addr().unwrap();
+
+        let client = thread::spawn(move || {
+            let mut stream = TcpStream::connect(proxy_addr).unwrap();
+            let request = "POST http://localhost:8000/hello HTTP/1.1\r\nHost: localhost:8000\r\nContent-Length: 99999999999999\r\n\r\n";
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        assert_eq!(proxy.serve().unwrap(), None);
+        let response = client.join().unwrap();
+        assert!(response.starts_with("HTTP/1.1 413 Content Too Large\r\n"));
+    }
+}
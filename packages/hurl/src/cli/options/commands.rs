@@ -26,6 +26,29 @@ pub fn input_files() -> clap::Arg {
         .num_args(1..)
 }
 
+pub fn abort_on_first_assert_failure_within_entry() -> clap::Arg {
+    clap::Arg::new("abort_on_first_assert_failure_within_entry")
+        .long("abort-on-first-assert-failure-within-entry")
+        .help("Stop evaluating asserts of an entry at the first failure")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn accept_encoding() -> clap::Arg {
+    clap::Arg::new("accept_encoding")
+        .long("accept-encoding")
+        .value_name("ENCODINGS")
+        .help("Request a response compressed with ENCODINGS, or none")
+        .num_args(1)
+}
+
+pub fn assert_file() -> clap::Arg {
+    clap::Arg::new("assert_file")
+        .long("assert-file")
+        .value_name("FILE")
+        .help("Apply the asserts of FILE to the last response of each file")
+        .num_args(1)
+}
+
 pub fn aws_sigv4() -> clap::Arg {
     clap::Arg::new("aws_sigv4")
         .long("aws-sigv4")
@@ -34,6 +57,33 @@ pub fn aws_sigv4() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn body_format() -> clap::Arg {
+    clap::Arg::new("body_format")
+        .long("body-format")
+        .value_name("FORMAT")
+        .default_value("auto")
+        .value_parser(["auto", "text", "binary", "json"])
+        .help("Format of the written response body: auto, text, binary or json")
+        .num_args(1)
+}
+
+pub fn body_only_on_failure() -> clap::Arg {
+    clap::Arg::new("body_only_on_failure")
+        .long("body-only-on-failure")
+        .help("Output the body of the last response only if the run fails")
+        .conflicts_with("parallel")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn buffer_size() -> clap::Arg {
+    clap::Arg::new("buffer_size")
+        .long("buffer-size")
+        .value_name("BYTES")
+        .value_parser(clap::value_parser!(u32).range(1024..=10485760))
+        .help("Size in bytes of the buffer used to read response bodies")
+        .num_args(1)
+}
+
 pub fn cacert_file() -> clap::Arg {
     clap::Arg::new("cacert_file")
         .long("cacert")
@@ -42,6 +92,15 @@ pub fn cacert_file() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn capture_proxy() -> clap::Arg {
+    clap::Arg::new("capture_proxy")
+        .long("capture-proxy")
+        .value_name("[HOST]:PORT")
+        .help("(Experimental) Run a local proxy recording HTTP traffic as a Hurl file")
+        .num_args(1)
+        .hide(true)
+}
+
 pub fn client_cert_file() -> clap::Arg {
     clap::Arg::new("client_cert_file")
         .long("cert")
@@ -67,6 +126,13 @@ pub fn color() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn compress_reports() -> clap::Arg {
+    clap::Arg::new("compress_reports")
+        .long("compress-reports")
+        .help("Write JUnit and TAP reports gzip compressed, with a .gz suffix")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn compressed() -> clap::Arg {
     clap::Arg::new("compressed")
         .long("compressed")
@@ -128,6 +194,13 @@ pub fn delay() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn diagnostics_json() -> clap::Arg {
+    clap::Arg::new("diagnostics_json")
+        .long("diagnostics-json")
+        .help("Output parsing errors and lint warnings as JSON diagnostics")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn error_format() -> clap::Arg {
     clap::Arg::new("error_format")
         .long("error-format")
@@ -138,6 +211,13 @@ pub fn error_format() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn explain() -> clap::Arg {
+    clap::Arg::new("explain")
+        .long("explain")
+        .help("Print the result of each assert, even on success")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn fail_at_end() -> clap::Arg {
     clap::Arg::new("fail_at_end")
         .long("fail-at-end")
@@ -146,6 +226,21 @@ pub fn fail_at_end() -> clap::Arg {
         .hide(true)
 }
 
+pub fn fail_fast() -> clap::Arg {
+    clap::Arg::new("fail_fast")
+        .long("fail-fast")
+        .help("Stop running new files after the first failed file")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn fail_on() -> clap::Arg {
+    clap::Arg::new("fail_on")
+        .long("fail-on")
+        .value_name("CLASSES")
+        .help("Fail on responses with a status in one of the comma separated CLASSES (ex: 4xx,5xx)")
+        .num_args(1)
+}
+
 pub fn file_root() -> clap::Arg {
     clap::Arg::new("file_root")
         .long("file-root")
@@ -188,6 +283,16 @@ pub fn glob() -> clap::Arg {
         .action(clap::ArgAction::Append)
 }
 
+pub fn header_match() -> clap::Arg {
+    clap::Arg::new("header_match")
+        .long("header-match")
+        .value_name("MODE")
+        .default_value("ci")
+        .value_parser(["exact", "ci"])
+        .help("Match header names of queries exactly or case-insensitively")
+        .num_args(1)
+}
+
 pub fn http10() -> clap::Arg {
     clap::Arg::new("http10")
         .long("http1.0")
@@ -217,6 +322,14 @@ pub fn http3() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn idempotency_key_header() -> clap::Arg {
+    clap::Arg::new("idempotency_key_header")
+        .long("idempotency-key-header")
+        .value_name("HEADER_NAME")
+        .help("Add a stable generated key header to mutating requests, reused across retries")
+        .num_args(1)
+}
+
 pub fn ignore_asserts() -> clap::Arg {
     clap::Arg::new("ignore_asserts")
         .long("ignore-asserts")
@@ -232,6 +345,22 @@ pub fn include() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn include_request() -> clap::Arg {
+    clap::Arg::new("include_request")
+        .long("include-request")
+        .help("Include the HTTP request line and headers, and the HTTP response headers in the output")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn input() -> clap::Arg {
+    clap::Arg::new("input")
+        .long("input")
+        .value_name("FILE")
+        .help("(Experimental) Read the response evaluated by --query from FILE")
+        .num_args(1)
+        .hide(true)
+}
+
 pub fn insecure() -> clap::Arg {
     clap::Arg::new("insecure")
         .long("insecure")
@@ -240,6 +369,15 @@ pub fn insecure() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn insecure_host() -> clap::Arg {
+    clap::Arg::new("insecure_host")
+        .long("insecure-host")
+        .value_name("HOST")
+        .help("Allow insecure SSL connections to HOST only")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
 pub fn interactive() -> clap::Arg {
     clap::Arg::new("interactive")
         .long("interactive")
@@ -272,6 +410,49 @@ pub fn json() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn lint() -> clap::Arg {
+    clap::Arg::new("lint")
+        .long("lint")
+        .help("Check files for likely mistakes without running them")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn list_entries() -> clap::Arg {
+    clap::Arg::new("list_entries")
+        .long("list-entries")
+        .help("List the entries of files without running them")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn max_concurrent_per_host() -> clap::Arg {
+    clap::Arg::new("max_concurrent_per_host")
+        .long("max-concurrent-per-host")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u32).range(1..))
+        .help("(Experimental) Maximum number of concurrent requests to the same host in parallel mode")
+        .num_args(1)
+        .hide(true)
+}
+
+pub fn max_header_size() -> clap::Arg {
+    clap::Arg::new("max_header_size")
+        .long("max-header-size")
+        .value_name("BYTES")
+        .value_parser(clap::value_parser!(u64).range(1..))
+        .help("Maximum size in bytes of the response headers")
+        .num_args(1)
+}
+
+pub fn max_pages() -> clap::Arg {
+    clap::Arg::new("max_pages")
+        .long("max-pages")
+        .value_name("NUM")
+        .default_value("10")
+        .value_parser(clap::value_parser!(u32).range(1..))
+        .help("Maximum number of pages to fetch when following pagination links")
+        .num_args(1)
+}
+
 pub fn max_redirects() -> clap::Arg {
     clap::Arg::new("max_redirects")
         .long("max-redirs")
@@ -356,6 +537,20 @@ pub fn noproxy() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn normalize_json_numbers() -> clap::Arg {
+    clap::Arg::new("normalize_json_numbers")
+        .long("normalize-json-numbers")
+        .help("Normalize numbers when comparing JSON bodies")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn normalize_newlines() -> clap::Arg {
+    clap::Arg::new("normalize_newlines")
+        .long("normalize-newlines")
+        .help("Normalize line endings when comparing texts")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn output() -> clap::Arg {
     clap::Arg::new("output")
         .long("output")
@@ -365,6 +560,30 @@ pub fn output() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn output_append_newline() -> clap::Arg {
+    clap::Arg::new("output_append_newline")
+        .long("output-append-newline")
+        .help("Append a newline after the response body written to standard output")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn output_split() -> clap::Arg {
+    clap::Arg::new("output_split")
+        .long("output-split")
+        .value_name("DIR")
+        .help("Write the last response body of each entry to a numbered file in DIR")
+        .conflicts_with("parallel")
+        .num_args(1)
+}
+
+pub fn paginate() -> clap::Arg {
+    clap::Arg::new("paginate")
+        .long("paginate")
+        .value_name("REL")
+        .help("Follow the pagination links with relation type REL and aggregate the pages bodies")
+        .num_args(1)
+}
+
 pub fn parallel() -> clap::Arg {
     clap::Arg::new("parallel")
         .long("parallel")
@@ -373,6 +592,20 @@ pub fn parallel() -> clap::Arg {
         .hide(true)
 }
 
+pub fn parse_only() -> clap::Arg {
+    clap::Arg::new("parse_only")
+        .long("parse-only")
+        .help("Parse files without running them")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn partial_body() -> clap::Arg {
+    clap::Arg::new("partial_body")
+        .long("partial-body")
+        .help("Stop reading response bodies as soon as every body contains assert matches")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn path_as_is() -> clap::Arg {
     clap::Arg::new("path_as_is")
         .long("path-as-is")
@@ -380,6 +613,27 @@ pub fn path_as_is() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn print_curl_on_failure() -> clap::Arg {
+    clap::Arg::new("print_curl_on_failure")
+        .long("print-curl-on-failure")
+        .help("Print the curl command line of each failing entry")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn print_request_duration_warnings() -> clap::Arg {
+    clap::Arg::new("print_request_duration_warnings")
+        .long("print-request-duration-warnings")
+        .help("Print a warning for each entry slower than --slow-warn-ms after the run")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn print_timings() -> clap::Arg {
+    clap::Arg::new("print_timings")
+        .long("print-timings")
+        .help("Print a table of the entries timings after the run")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn proxy() -> clap::Arg {
     clap::Arg::new("proxy")
         .long("proxy")
@@ -389,6 +643,58 @@ pub fn proxy() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn proxy_header() -> clap::Arg {
+    clap::Arg::new("proxy_header")
+        .long("proxy-header")
+        .value_name("HEADER")
+        .help("Pass custom header to proxy")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
+pub fn proxy_tunnel() -> clap::Arg {
+    clap::Arg::new("proxy_tunnel")
+        .long("proxy-tunnel")
+        .help("Tunnel HTTP requests through the proxy with CONNECT")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn query() -> clap::Arg {
+    clap::Arg::new("query")
+        .long("query")
+        .value_name("QUERY")
+        .help("(Experimental) Evaluate a query on a stored response and print the result")
+        .num_args(1)
+        .hide(true)
+}
+
+pub fn randomize_order() -> clap::Arg {
+    clap::Arg::new("randomize_order")
+        .long("randomize-order")
+        .help("Run entries of a file in a random order, keeping dependent entries ordered")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn record() -> clap::Arg {
+    clap::Arg::new("record")
+        .long("record")
+        .value_name("FILE")
+        .help("Record all HTTP exchanges to the cassette FILE")
+        .conflicts_with("parallel")
+        .conflicts_with("replay")
+        .num_args(1)
+}
+
+pub fn replay() -> clap::Arg {
+    clap::Arg::new("replay")
+        .long("replay")
+        .value_name("FILE")
+        .help("Serve HTTP exchanges recorded in the cassette FILE, without network access")
+        .conflicts_with("parallel")
+        .conflicts_with("record")
+        .num_args(1)
+}
+
 pub fn report_html() -> clap::Arg {
     clap::Arg::new("report_html")
         .long("report-html")
@@ -405,6 +711,16 @@ pub fn report_junit() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn report_sort() -> clap::Arg {
+    clap::Arg::new("report_sort")
+        .long("report-sort")
+        .value_name("ORDER")
+        .default_value("index")
+        .value_parser(["index", "name"])
+        .help("Sort the files of JUnit, TAP and HTML reports")
+        .num_args(1)
+}
+
 pub fn report_tap() -> clap::Arg {
     clap::Arg::new("report_tap")
         .long("report-tap")
@@ -422,6 +738,15 @@ pub fn resolve() -> clap::Arg {
         .action(clap::ArgAction::Append)
 }
 
+pub fn resolve_from_file() -> clap::Arg {
+    clap::Arg::new("resolve_from_file")
+        .long("resolve-from-file")
+        .value_name("FILE")
+        .help("Read custom addresses for HOST and PORT pairs from a file")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
 pub fn retry() -> clap::Arg {
     clap::Arg::new("retry")
         .long("retry")
@@ -433,6 +758,17 @@ pub fn retry() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn retry_connect() -> clap::Arg {
+    clap::Arg::new("retry_connect")
+        .long("retry-connect")
+        .value_name("NUM")
+        .default_value("0")
+        .value_parser(clap::value_parser!(i32).range(-1..))
+        .allow_hyphen_values(true)
+        .help("Maximum number of retries on connection errors, 0 for no retries, -1 for unlimited retries")
+        .num_args(1)
+}
+
 pub fn retry_interval() -> clap::Arg {
     clap::Arg::new("retry_interval")
         .long("retry-interval")
@@ -443,6 +779,65 @@ pub fn retry_interval() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn sample() -> clap::Arg {
+    clap::Arg::new("sample")
+        .long("sample")
+        .value_name("PERCENT")
+        .help("Run a random sample of PERCENT% of the input files")
+        .num_args(1)
+}
+
+pub fn seed() -> clap::Arg {
+    clap::Arg::new("seed")
+        .long("seed")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u64))
+        .help("Set the seed used to randomize the order of entries or the sampled files")
+        .num_args(1)
+}
+
+pub fn set_at() -> clap::Arg {
+    clap::Arg::new("set_at")
+        .long("set-at")
+        .value_name("ENTRY_NUMBER:NAME=VALUE")
+        .help("Set a variable from entry ENTRY_NUMBER (starting at 1)")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
+pub fn setup() -> clap::Arg {
+    clap::Arg::new("setup")
+        .long("setup")
+        .value_name("FILE")
+        .help("Run FILE before the Hurl files, using its captures as variables")
+        .num_args(1)
+}
+
+pub fn slow_warn_ms() -> clap::Arg {
+    clap::Arg::new("slow_warn_ms")
+        .long("slow-warn-ms")
+        .value_name("MILLISECONDS")
+        .default_value("1000")
+        .value_parser(clap::value_parser!(u64))
+        .help("Duration above which an entry is reported as slow")
+        .num_args(1)
+}
+
+pub fn sni() -> clap::Arg {
+    clap::Arg::new("sni")
+        .long("sni")
+        .value_name("NAME")
+        .help("Use NAME as TLS server name (SNI) and Host header, instead of the URL host")
+        .num_args(1)
+}
+
+pub fn sort_headers() -> clap::Arg {
+    clap::Arg::new("sort_headers")
+        .long("sort-headers")
+        .help("Sort the HTTP response headers by name in the output")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn ssl_no_revoke() -> clap::Arg {
     clap::Arg::new("ssl_no_revoke")
         .long("ssl-no-revoke")
@@ -450,6 +845,21 @@ pub fn ssl_no_revoke() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn teardown() -> clap::Arg {
+    clap::Arg::new("teardown")
+        .long("teardown")
+        .value_name("FILE")
+        .help("Run FILE after the Hurl files, even if they have failed")
+        .num_args(1)
+}
+
+pub fn teardown_strict() -> clap::Arg {
+    clap::Arg::new("teardown_strict")
+        .long("teardown-strict")
+        .help("Fail the run if the teardown file fails")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn test() -> clap::Arg {
     clap::Arg::new("test")
         .long("test")
@@ -457,6 +867,15 @@ pub fn test() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn timezone() -> clap::Arg {
+    clap::Arg::new("timezone")
+        .long("timezone")
+        .value_name("OFFSET")
+        .default_value("UTC")
+        .help("Set the timezone used to parse and format dates in filters")
+        .num_args(1)
+}
+
 pub fn to_entry() -> clap::Arg {
     clap::Arg::new("to_entry")
         .long("to-entry")
@@ -467,6 +886,21 @@ pub fn to_entry() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn trace_context() -> clap::Arg {
+    clap::Arg::new("trace_context")
+        .long("trace-context")
+        .help("Add a W3C traceparent header to each request")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn trace_state() -> clap::Arg {
+    clap::Arg::new("trace_state")
+        .long("trace-state")
+        .value_name("STATE")
+        .help("Add a W3C tracestate header with STATE to each request, with --trace-context")
+        .num_args(1)
+}
+
 pub fn unix_socket() -> clap::Arg {
     clap::Arg::new("unix_socket")
         .long("unix-socket")
@@ -519,6 +953,20 @@ pub fn verbose() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn verify_content_length() -> clap::Arg {
+    clap::Arg::new("verify_content_length")
+        .long("verify-content-length")
+        .help("Check that each response body length matches its Content-Length header")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn version_json() -> clap::Arg {
+    clap::Arg::new("version_json")
+        .long("version-json")
+        .help("Print version information as JSON")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn very_verbose() -> clap::Arg {
     clap::Arg::new("very_verbose")
         .long("very-verbose")
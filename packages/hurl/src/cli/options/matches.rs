@@ -19,16 +19,22 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, IsTerminal};
 use std::path::{Path, PathBuf};
-use std::time::Duration;
-use std::{env, io};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fs, io};
 
+use chrono::{FixedOffset, Offset, Utc};
+use clap::parser::ValueSource;
 use clap::ArgMatches;
-use hurl::runner::{Input, Value};
+use hurl::http::HeaderMatch;
+use hurl::output::BodyFormat;
+use hurl::runner::{AssertFile, Input, ResponseQuery, Value};
 use hurl_core::ast::Retry;
+use hurl_core::error::Error;
 
 use super::variables::{parse as parse_variable, parse_value};
 use super::CliOptionsError;
-use crate::cli::options::{ErrorFormat, HttpVersion, IpResolve, Output};
+use crate::cli::options::{ErrorFormat, HttpVersion, IpResolve, Output, ReportSort};
 use crate::cli::OutputType;
 
 pub fn cacert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
@@ -48,10 +54,65 @@ pub fn cacert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOption
     }
 }
 
+pub fn abort_on_first_assert_failure_within_entry(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "abort_on_first_assert_failure_within_entry")
+}
+
+pub fn assert_file(arg_matches: &ArgMatches) -> Result<Option<AssertFile>, CliOptionsError> {
+    let Some(filename) = get::<String>(arg_matches, "assert_file") else {
+        return Ok(None);
+    };
+    let content = match fs::read_to_string(&filename) {
+        Ok(content) => content,
+        Err(e) => {
+            let message = format!("Assert file {filename} can not be read: {e}");
+            return Err(CliOptionsError::Error(message));
+        }
+    };
+    match AssertFile::new(&filename, &content) {
+        Ok(assert_file) => Ok(Some(assert_file)),
+        Err(e) => {
+            let message = format!(
+                "Assert file {filename} can not be parsed: {} at line {}, column {}",
+                e.description(),
+                e.pos.line,
+                e.pos.column
+            );
+            Err(CliOptionsError::Error(message))
+        }
+    }
+}
+
+pub fn accept_encoding(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "accept_encoding")
+}
+
 pub fn aws_sigv4(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "aws_sigv4")
 }
 
+pub fn body_format(arg_matches: &ArgMatches) -> BodyFormat {
+    let body_format = get::<String>(arg_matches, "body_format");
+    match body_format.as_deref() {
+        Some("text") => BodyFormat::Text,
+        Some("binary") => BodyFormat::Binary,
+        Some("json") => BodyFormat::Json,
+        _ => BodyFormat::Auto,
+    }
+}
+
+pub fn body_only_on_failure(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "body_only_on_failure")
+}
+
+pub fn buffer_size(arg_matches: &ArgMatches) -> Option<usize> {
+    get::<u32>(arg_matches, "buffer_size").map(|b| b as usize)
+}
+
+pub fn capture_proxy(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "capture_proxy")
+}
+
 pub fn client_cert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
     match get::<String>(arg_matches, "client_cert_file") {
         None => Ok(None),
@@ -96,6 +157,10 @@ pub fn color(arg_matches: &ArgMatches) -> bool {
     io::stdout().is_terminal()
 }
 
+pub fn compress_reports(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "compress_reports")
+}
+
 pub fn compressed(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "compressed")
 }
@@ -130,6 +195,14 @@ pub fn delay(arg_matches: &ArgMatches) -> Duration {
     Duration::from_millis(millis)
 }
 
+pub fn diagnostics_json(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "diagnostics_json")
+}
+
+pub fn explain(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "explain")
+}
+
 pub fn error_format(arg_matches: &ArgMatches) -> ErrorFormat {
     let error_format = get::<String>(arg_matches, "error_format");
     match error_format.as_deref() {
@@ -139,6 +212,35 @@ pub fn error_format(arg_matches: &ArgMatches) -> ErrorFormat {
     }
 }
 
+pub fn fail_fast(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "fail_fast")
+}
+
+/// Returns the list of HTTP status classes (`4` for `4xx` etc...) that make an entry fail.
+pub fn fail_on(arg_matches: &ArgMatches) -> Result<Vec<u32>, CliOptionsError> {
+    let Some(value) = get::<String>(arg_matches, "fail_on") else {
+        return Ok(vec![]);
+    };
+    let mut classes = vec![];
+    for class in value.split(',') {
+        let class = class.trim().to_lowercase();
+        match class.as_str() {
+            "1xx" | "2xx" | "3xx" | "4xx" | "5xx" => {
+                let class = class[..1].parse::<u32>().unwrap();
+                if !classes.contains(&class) {
+                    classes.push(class);
+                }
+            }
+            _ => {
+                return Err(CliOptionsError::Error(format!(
+                    "{class} is not a valid status class (1xx, 2xx, 3xx, 4xx or 5xx)"
+                )))
+            }
+        }
+    }
+    Ok(classes)
+}
+
 pub fn file_root(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "file_root")
 }
@@ -178,6 +280,13 @@ pub fn html_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, CliOptionsE
     }
 }
 
+pub fn header_match(arg_matches: &ArgMatches) -> HeaderMatch {
+    match get::<String>(arg_matches, "header_match").as_deref() {
+        Some("exact") => HeaderMatch::Exact,
+        _ => HeaderMatch::CaseInsensitive,
+    }
+}
+
 pub fn http_version(arg_matches: &ArgMatches) -> Option<HttpVersion> {
     if has_flag(arg_matches, "http3") {
         Some(HttpVersion::V3)
@@ -192,6 +301,10 @@ pub fn http_version(arg_matches: &ArgMatches) -> Option<HttpVersion> {
     }
 }
 
+pub fn idempotency_key_header(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "idempotency_key_header")
+}
+
 pub fn ignore_asserts(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "ignore_asserts")
 }
@@ -200,6 +313,14 @@ pub fn include(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "include")
 }
 
+pub fn include_request(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "include_request")
+}
+
+pub fn input(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "input")
+}
+
 /// Returns the input files from the positional arguments and the glob options
 pub fn input_files(arg_matches: &ArgMatches) -> Result<Vec<Input>, CliOptionsError> {
     let mut files = vec![];
@@ -243,6 +364,26 @@ pub fn junit_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
     get::<String>(arg_matches, "report_junit").map(PathBuf::from)
 }
 
+pub fn lint(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "lint")
+}
+
+pub fn list_entries(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "list_entries")
+}
+
+pub fn max_concurrent_per_host(arg_matches: &ArgMatches) -> Option<usize> {
+    get::<u32>(arg_matches, "max_concurrent_per_host").map(|m| m as usize)
+}
+
+pub fn max_header_size(arg_matches: &ArgMatches) -> Option<u64> {
+    get::<u64>(arg_matches, "max_header_size")
+}
+
+pub fn max_pages(arg_matches: &ArgMatches) -> usize {
+    get::<u32>(arg_matches, "max_pages").unwrap() as usize
+}
+
 pub fn max_redirect(arg_matches: &ArgMatches) -> Option<usize> {
     match get::<i32>(arg_matches, "max_redirects").unwrap() {
         m if m == -1 => None,
@@ -276,6 +417,14 @@ pub fn netrc_optional(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "netrc_optional")
 }
 
+pub fn normalize_json_numbers(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "normalize_json_numbers")
+}
+
+pub fn normalize_newlines(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "normalize_newlines")
+}
+
 pub fn no_proxy(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "noproxy")
 }
@@ -284,6 +433,34 @@ pub fn output(arg_matches: &ArgMatches) -> Option<Output> {
     get::<String>(arg_matches, "output").map(|filename| Output::new(&filename))
 }
 
+pub fn output_split(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, CliOptionsError> {
+    if let Some(dir) = get::<String>(arg_matches, "output_split") {
+        let path = Path::new(&dir);
+        if !path.exists() {
+            match std::fs::create_dir_all(path) {
+                Err(_) => Err(CliOptionsError::Error(format!(
+                    "Output split dir {} can not be created",
+                    path.display()
+                ))),
+                Ok(_) => Ok(Some(path.to_path_buf())),
+            }
+        } else if path.is_dir() {
+            Ok(Some(path.to_path_buf()))
+        } else {
+            Err(CliOptionsError::Error(format!(
+                "{} is not a valid directory",
+                path.display()
+            )))
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+pub fn output_append_newline(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "output_append_newline")
+}
+
 pub fn output_type(arg_matches: &ArgMatches) -> OutputType {
     if has_flag(arg_matches, "json") {
         OutputType::Json
@@ -294,14 +471,38 @@ pub fn output_type(arg_matches: &ArgMatches) -> OutputType {
     }
 }
 
+pub fn paginate(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "paginate")
+}
+
 pub fn parallel(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "parallel")
 }
 
+pub fn parse_only(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "parse_only")
+}
+
+pub fn partial_body(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "partial_body")
+}
+
 pub fn path_as_is(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "path_as_is")
 }
 
+pub fn print_curl_on_failure(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "print_curl_on_failure")
+}
+
+pub fn print_request_duration_warnings(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "print_request_duration_warnings")
+}
+
+pub fn print_timings(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "print_timings")
+}
+
 pub fn progress_bar(arg_matches: &ArgMatches) -> bool {
     test(arg_matches) && !interactive(arg_matches) && !is_ci() && io::stderr().is_terminal()
 }
@@ -310,8 +511,92 @@ pub fn proxy(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "proxy")
 }
 
-pub fn resolves(arg_matches: &ArgMatches) -> Vec<String> {
-    get_strings(arg_matches, "resolve").unwrap_or_default()
+pub fn insecure_hosts(arg_matches: &ArgMatches) -> Vec<String> {
+    get_strings(arg_matches, "insecure_host").unwrap_or_default()
+}
+
+pub fn trace_context(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "trace_context")
+}
+
+pub fn trace_state(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "trace_state")
+}
+
+pub fn proxy_headers(arg_matches: &ArgMatches) -> Vec<String> {
+    get_strings(arg_matches, "proxy_header").unwrap_or_default()
+}
+
+pub fn proxy_tunnel(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "proxy_tunnel")
+}
+
+pub fn query(arg_matches: &ArgMatches) -> Result<Option<ResponseQuery>, CliOptionsError> {
+    let Some(query) = get::<String>(arg_matches, "query") else {
+        return Ok(None);
+    };
+    match ResponseQuery::new(&query) {
+        Ok(query) => Ok(Some(query)),
+        Err(e) => {
+            let message = format!(
+                "Query <{query}> can not be parsed: {} at column {}",
+                e.description(),
+                e.pos.column
+            );
+            Err(CliOptionsError::Error(message))
+        }
+    }
+}
+
+pub fn randomize_order(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "randomize_order")
+}
+
+pub fn record(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "record").map(PathBuf::from)
+}
+
+pub fn replay(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "replay").map(PathBuf::from)
+}
+
+pub fn report_sort(arg_matches: &ArgMatches) -> ReportSort {
+    match get::<String>(arg_matches, "report_sort").as_deref() {
+        Some("name") => ReportSort::Name,
+        _ => ReportSort::Index,
+    }
+}
+
+pub fn resolves(arg_matches: &ArgMatches) -> Result<Vec<String>, CliOptionsError> {
+    let mut resolves = get_strings(arg_matches, "resolve").unwrap_or_default();
+
+    if let Some(filenames) = get_strings(arg_matches, "resolve_from_file") {
+        for f in filenames.iter() {
+            let path = Path::new(&f);
+            let Ok(file) = File::open(path) else {
+                return Err(CliOptionsError::Error(format!(
+                    "Resolve file {} does not exist",
+                    path.display()
+                )));
+            };
+            let reader = BufReader::new(file);
+            for (index, line) in reader.lines().enumerate() {
+                let Ok(line) = line else {
+                    return Err(CliOptionsError::Error(format!(
+                        "Can not parse line {} of {}",
+                        index + 1,
+                        path.display()
+                    )));
+                };
+                let line = line.trim();
+                if line.starts_with('#') || line.is_empty() {
+                    continue;
+                }
+                resolves.push(line.to_string());
+            }
+        }
+    }
+    Ok(resolves)
 }
 
 pub fn retry(arg_matches: &ArgMatches) -> Retry {
@@ -322,11 +607,80 @@ pub fn retry(arg_matches: &ArgMatches) -> Retry {
     }
 }
 
+pub fn retry_connect(arg_matches: &ArgMatches) -> Retry {
+    match get::<i32>(arg_matches, "retry_connect").unwrap() {
+        -1 => Retry::Infinite,
+        0 => Retry::None,
+        r => Retry::Finite(r as usize),
+    }
+}
+
 pub fn retry_interval(arg_matches: &ArgMatches) -> Duration {
     let value = get::<u64>(arg_matches, "retry_interval").unwrap();
     Duration::from_millis(value)
 }
 
+/// Returns the percentage of input files to run, given as `10%` or `10`.
+pub fn sample(arg_matches: &ArgMatches) -> Result<Option<u32>, CliOptionsError> {
+    let Some(value) = get::<String>(arg_matches, "sample") else {
+        return Ok(None);
+    };
+    let percent = value.strip_suffix('%').unwrap_or(&value);
+    match percent.parse::<u32>() {
+        Ok(percent) if (1..=100).contains(&percent) => Ok(Some(percent)),
+        _ => Err(CliOptionsError::Error(format!(
+            "{value} is not a valid sample percentage (from 1% to 100%)"
+        ))),
+    }
+}
+
+/// Returns the seed used to randomize the order of entries.
+///
+/// If no seed is given, we use the current time, so each run has a different order.
+pub fn seed(arg_matches: &ArgMatches) -> u64 {
+    get::<u64>(arg_matches, "seed").unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64)
+    })
+}
+
+/// Returns the variables to set from a given entry, as a list of (entry index, name, value).
+pub fn set_at(arg_matches: &ArgMatches) -> Result<Vec<(usize, String, Value)>, CliOptionsError> {
+    let mut variables = vec![];
+    for s in get_strings(arg_matches, "set_at").unwrap_or_default() {
+        let error = || {
+            CliOptionsError::Error(format!(
+                "{s} is not a valid entry variable (ENTRY_NUMBER:NAME=VALUE)"
+            ))
+        };
+        let (index, variable) = s.split_once(':').ok_or_else(error)?;
+        let index = match index.trim().parse::<usize>() {
+            Ok(index) if index > 0 => index,
+            _ => return Err(error()),
+        };
+        let (name, value) = parse_variable(variable)?;
+        variables.push((index, name, value));
+    }
+    Ok(variables)
+}
+
+pub fn setup(arg_matches: &ArgMatches) -> Option<Input> {
+    get::<String>(arg_matches, "setup").map(|filename| Input::new(&filename))
+}
+
+pub fn slow_warn_ms(arg_matches: &ArgMatches) -> u64 {
+    get::<u64>(arg_matches, "slow_warn_ms").unwrap()
+}
+
+pub fn sni(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "sni")
+}
+
+pub fn sort_headers(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "sort_headers")
+}
+
 pub fn ssl_no_revoke(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "ssl_no_revoke")
 }
@@ -335,6 +689,14 @@ pub fn tap_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
     get::<String>(arg_matches, "report_tap").map(PathBuf::from)
 }
 
+pub fn teardown(arg_matches: &ArgMatches) -> Option<Input> {
+    get::<String>(arg_matches, "teardown").map(|filename| Input::new(&filename))
+}
+
+pub fn teardown_strict(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "teardown_strict")
+}
+
 pub fn test(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "test")
 }
@@ -344,6 +706,19 @@ pub fn timeout(arg_matches: &ArgMatches) -> Duration {
     Duration::from_secs(value)
 }
 
+/// Returns the timezone offset used by date filters, either `UTC` or an offset like `+02:00`.
+pub fn timezone(arg_matches: &ArgMatches) -> Result<FixedOffset, CliOptionsError> {
+    let value = get::<String>(arg_matches, "timezone").unwrap();
+    match value.as_str() {
+        "UTC" | "utc" | "Z" => Ok(Utc.fix()),
+        _ => FixedOffset::from_str(&value).map_err(|_| {
+            CliOptionsError::Error(format!(
+                "Invalid timezone {value}, must be UTC or an offset like +02:00"
+            ))
+        }),
+    }
+}
+
 pub fn to_entry(arg_matches: &ArgMatches) -> Option<usize> {
     get::<u32>(arg_matches, "to_entry").map(|x| x as usize)
 }
@@ -419,6 +794,14 @@ pub fn verbose(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "verbose")
 }
 
+pub fn verify_content_length(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "verify_content_length")
+}
+
+pub fn version_json(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "version_json")
+}
+
 pub fn very_verbose(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "very_verbose")
 }
@@ -461,6 +844,12 @@ fn get<T: Clone + Send + Sync + 'static>(matches: &ArgMatches, name: &str) -> Op
     matches.get_one::<T>(name).cloned()
 }
 
+/// Returns true if the option `name` has been explicitly set (i.e. it does not come from its
+/// default value).
+pub fn is_present(matches: &ArgMatches, name: &str) -> bool {
+    matches!(matches.value_source(name), Some(source) if source != ValueSource::DefaultValue)
+}
+
 fn has_flag(matches: &ArgMatches, name: &str) -> bool {
     matches.get_one::<bool>(name) == Some(&true)
 }
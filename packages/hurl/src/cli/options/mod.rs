@@ -25,10 +25,12 @@ use std::env;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+use chrono::FixedOffset;
 use clap::ArgMatches;
 use hurl::http;
-use hurl::http::RequestedHttpVersion;
-use hurl::runner::{Input, Output};
+use hurl::http::{HeaderMatch, RequestedHttpVersion};
+use hurl::output::BodyFormat;
+use hurl::runner::{sample_indices, AssertFile, Input, Output, ResponseQuery};
 use hurl::util::logger::{LoggerOptions, LoggerOptionsBuilder, Verbosity};
 use hurl::util::path::ContextDir;
 use hurl_core::ast::{Entry, Retry};
@@ -40,11 +42,19 @@ pub use error::CliOptionsError;
 /// Represents the list of all options that can be used in Hurl command line.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CliOptions {
+    pub abort_on_first_assert_failure_within_entry: bool,
+    pub accept_encoding: Option<String>,
+    pub assert_file: Option<AssertFile>,
     pub aws_sigv4: Option<String>,
+    pub body_format: BodyFormat,
+    pub body_only_on_failure: bool,
+    pub buffer_size: Option<usize>,
     pub cacert_file: Option<String>,
+    pub capture_proxy: Option<String>,
     pub client_cert_file: Option<String>,
     pub client_key_file: Option<String>,
     pub color: bool,
+    pub compress_reports: bool,
     pub compressed: bool,
     pub connect_timeout: Duration,
     pub connects_to: Vec<String>,
@@ -52,45 +62,90 @@ pub struct CliOptions {
     pub cookie_input_file: Option<String>,
     pub cookie_output_file: Option<PathBuf>,
     pub delay: Duration,
+    pub diagnostics_json: bool,
     pub error_format: ErrorFormat,
+    pub explain: bool,
+    pub fail_fast: bool,
+    pub fail_on: Vec<u32>,
     pub file_root: Option<String>,
     pub follow_location: bool,
     pub follow_location_trusted: bool,
     pub from_entry: Option<usize>,
+    pub header_match: HeaderMatch,
     pub html_dir: Option<PathBuf>,
     pub http_version: Option<HttpVersion>,
+    pub idempotency_key_header: Option<String>,
     pub ignore_asserts: bool,
     pub include: bool,
+    pub include_request: bool,
+    pub input: Option<String>,
     pub input_files: Vec<Input>,
     pub insecure: bool,
+    pub insecure_hosts: Vec<String>,
     pub interactive: bool,
     pub ip_resolve: Option<IpResolve>,
     pub junit_file: Option<PathBuf>,
+    pub lint: bool,
+    pub list_entries: bool,
+    pub max_concurrent_per_host: Option<usize>,
+    pub max_header_size: Option<u64>,
+    pub max_pages: usize,
     pub max_redirect: Option<usize>,
     pub max_workers: Option<usize>,
     pub netrc: bool,
     pub netrc_file: Option<String>,
     pub netrc_optional: bool,
     pub no_proxy: Option<String>,
+    pub normalize_json_numbers: bool,
+    pub normalize_newlines: bool,
     pub output: Option<Output>,
+    pub output_append_newline: bool,
+    pub output_split: Option<PathBuf>,
     pub output_type: OutputType,
+    pub paginate: Option<String>,
     pub parallel: bool,
+    pub parse_only: bool,
+    pub partial_body: bool,
     pub path_as_is: bool,
+    pub print_curl_on_failure: bool,
+    pub print_request_duration_warnings: bool,
+    pub print_timings: bool,
     pub progress_bar: bool,
     pub proxy: Option<String>,
+    pub proxy_headers: Vec<String>,
+    pub proxy_tunnel: bool,
+    pub query: Option<ResponseQuery>,
+    pub randomize_order: bool,
+    pub record: Option<PathBuf>,
+    pub replay: Option<PathBuf>,
+    pub report_sort: ReportSort,
     pub resolves: Vec<String>,
     pub retry: Retry,
+    pub retry_connect: Retry,
     pub retry_interval: Duration,
+    pub sample: Option<u32>,
+    pub seed: u64,
+    pub set_at: Vec<(usize, String, Value)>,
+    pub setup: Option<Input>,
+    pub slow_warn_ms: u64,
+    pub sni: Option<String>,
+    pub sort_headers: bool,
     pub ssl_no_revoke: bool,
     pub tap_file: Option<PathBuf>,
+    pub teardown: Option<Input>,
+    pub teardown_strict: bool,
     pub test: bool,
     pub timeout: Duration,
+    pub timezone: FixedOffset,
     pub to_entry: Option<usize>,
+    pub trace_context: bool,
+    pub trace_state: Option<String>,
     pub unix_socket: Option<String>,
     pub user: Option<String>,
     pub user_agent: Option<String>,
     pub variables: HashMap<String, Value>,
     pub verbose: bool,
+    pub verify_content_length: bool,
     pub very_verbose: bool,
 }
 
@@ -146,24 +201,78 @@ impl From<ErrorFormat> for hurl::util::logger::ErrorFormat {
 fn get_version() -> String {
     let libcurl_version = http::libcurl_version_info();
     format!(
-        "{} ({}) {}\nFeatures (libcurl):  {}\nFeatures (built-in): brotli",
+        "{} ({}) {}\nFeatures (libcurl):  {}\nFeatures (built-in): {}",
         clap::crate_version!(),
         libcurl_version.host,
         libcurl_version.libraries.join(" "),
-        libcurl_version.features.join(" ")
+        libcurl_version.features.join(" "),
+        get_built_in_features(&libcurl_version.features).join(" ")
     )
 }
 
-pub fn parse() -> Result<CliOptions, CliOptionsError> {
-    let mut command = clap::Command::new("hurl")
+/// Returns the features built in Hurl, given the `libcurl_features` of the libcurl Hurl is
+/// linked to.
+///
+/// Brotli is decoded by Hurl itself (and not by libcurl), the TLS backend depends on the crate
+/// features Hurl has been built with, and HTTP/3 depends on libcurl.
+fn get_built_in_features(libcurl_features: &[String]) -> Vec<&'static str> {
+    let mut features = vec![];
+    if http::DEFAULT_ACCEPT_ENCODING
+        .split(", ")
+        .any(|encoding| encoding == "br")
+    {
+        features.push("brotli");
+    }
+    if cfg!(feature = "vendored-openssl") {
+        features.push("vendored-openssl");
+    } else if cfg!(feature = "ssl") {
+        features.push("openssl");
+    }
+    if libcurl_features.iter().any(|f| f == "HTTP3") {
+        features.push("http3");
+    }
+    features
+}
+
+/// Returns the version information of `--version` as a JSON object.
+fn get_version_json() -> serde_json::Value {
+    let libcurl_version = http::libcurl_version_info();
+    let libcurl_version_number = libcurl_version
+        .libraries
+        .first()
+        .and_then(|l| l.strip_prefix("libcurl/"))
+        .unwrap_or_default();
+    serde_json::json!({
+        "version": clap::crate_version!(),
+        "features": get_built_in_features(&libcurl_version.features),
+        "libcurl": {
+            "version": libcurl_version_number,
+            "host": libcurl_version.host,
+            "libraries": libcurl_version.libraries,
+            "features": libcurl_version.features,
+        },
+    })
+}
+
+/// Returns the clap command of Hurl, with all its arguments.
+fn command() -> clap::Command {
+    clap::Command::new("hurl")
         .version(get_version())
         .disable_colored_help(true)
         .about("Hurl, run and test HTTP requests with plain text")
+        .arg(commands::abort_on_first_assert_failure_within_entry())
+        .arg(commands::accept_encoding())
+        .arg(commands::assert_file())
         .arg(commands::aws_sigv4())
+        .arg(commands::body_format())
+        .arg(commands::body_only_on_failure())
+        .arg(commands::buffer_size())
         .arg(commands::cacert_file())
+        .arg(commands::capture_proxy())
         .arg(commands::client_cert_file())
         .arg(commands::client_key_file())
         .arg(commands::color())
+        .arg(commands::compress_reports())
         .arg(commands::compressed())
         .arg(commands::connect_timeout())
         .arg(commands::connect_to())
@@ -171,65 +280,127 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::cookies_input_file())
         .arg(commands::cookies_output_file())
         .arg(commands::delay())
+        .arg(commands::diagnostics_json())
         .arg(commands::error_format())
+        .arg(commands::explain())
         .arg(commands::fail_at_end())
+        .arg(commands::fail_fast())
+        .arg(commands::fail_on())
         .arg(commands::file_root())
         .arg(commands::follow_location())
         .arg(commands::follow_location_trusted())
         .arg(commands::from_entry())
         .arg(commands::glob())
+        .arg(commands::header_match())
         .arg(commands::http10())
         .arg(commands::http11())
         .arg(commands::http2())
         .arg(commands::http3())
+        .arg(commands::idempotency_key_header())
         .arg(commands::ignore_asserts())
         .arg(commands::include())
+        .arg(commands::include_request())
+        .arg(commands::input())
         .arg(commands::input_files())
         .arg(commands::insecure())
+        .arg(commands::insecure_host())
         .arg(commands::interactive())
         .arg(commands::ipv4())
         .arg(commands::ipv6())
         .arg(commands::json())
+        .arg(commands::lint())
+        .arg(commands::list_entries())
+        .arg(commands::max_concurrent_per_host())
+        .arg(commands::max_header_size())
+        .arg(commands::max_pages())
         .arg(commands::max_redirects())
         .arg(commands::max_time())
         .arg(commands::max_workers())
         .arg(commands::netrc())
         .arg(commands::netrc_file())
         .arg(commands::netrc_optional())
+        .arg(commands::normalize_json_numbers())
+        .arg(commands::normalize_newlines())
         .arg(commands::no_color())
         .arg(commands::no_output())
         .arg(commands::noproxy())
         .arg(commands::output())
+        .arg(commands::output_append_newline())
+        .arg(commands::output_split())
+        .arg(commands::paginate())
         .arg(commands::parallel())
+        .arg(commands::parse_only())
+        .arg(commands::partial_body())
         .arg(commands::path_as_is())
+        .arg(commands::print_curl_on_failure())
+        .arg(commands::print_request_duration_warnings())
+        .arg(commands::print_timings())
         .arg(commands::proxy())
+        .arg(commands::proxy_header())
+        .arg(commands::proxy_tunnel())
+        .arg(commands::query())
+        .arg(commands::randomize_order())
+        .arg(commands::record())
+        .arg(commands::replay())
         .arg(commands::report_html())
         .arg(commands::report_junit())
+        .arg(commands::report_sort())
         .arg(commands::report_tap())
         .arg(commands::resolve())
+        .arg(commands::resolve_from_file())
         .arg(commands::retry())
+        .arg(commands::retry_connect())
         .arg(commands::retry_interval())
+        .arg(commands::sample())
+        .arg(commands::seed())
+        .arg(commands::set_at())
+        .arg(commands::setup())
+        .arg(commands::slow_warn_ms())
+        .arg(commands::sni())
+        .arg(commands::sort_headers())
         .arg(commands::ssl_no_revoke())
+        .arg(commands::teardown())
+        .arg(commands::teardown_strict())
         .arg(commands::test())
+        .arg(commands::timezone())
         .arg(commands::to_entry())
+        .arg(commands::trace_context())
+        .arg(commands::trace_state())
         .arg(commands::unix_socket())
         .arg(commands::user_agent())
         .arg(commands::user())
         .arg(commands::variable())
         .arg(commands::variables_file())
         .arg(commands::verbose())
-        .arg(commands::very_verbose());
+        .arg(commands::verify_content_length())
+        .arg(commands::version_json())
+        .arg(commands::very_verbose())
+}
 
+pub fn parse() -> Result<CliOptions, CliOptionsError> {
+    let mut command = command();
     let arg_matches = command.try_get_matches_from_mut(env::args_os())?;
+    if matches::version_json(&arg_matches) {
+        return Err(CliOptionsError::Info(format!("{}\n", get_version_json())));
+    }
+    #[cfg(feature = "no-tls")]
+    check_tls_options(&arg_matches)?;
+    check_dependent_options(&arg_matches)?;
     let opts = parse_matches(&arg_matches)?;
 
     // If we've no file input (either from the standard input or from the command line arguments),
     // we just print help and exit.
-    if opts.input_files.is_empty() {
+    if opts.input_files.is_empty() && opts.capture_proxy.is_none() && opts.query.is_none() {
         let help = command.render_help().to_string();
         return Err(CliOptionsError::NoInput(help));
     }
 
+    if opts.input.is_some() && opts.query.is_none() {
+        return Err(CliOptionsError::Error(
+            "--input can only be used with --query".to_string(),
+        ));
+    }
+
     if opts.cookie_output_file.is_some() && opts.input_files.len() > 1 {
         return Err(CliOptionsError::Error(
             "Only save cookies for a unique session".to_string(),
@@ -238,12 +409,68 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
     Ok(opts)
 }
 
+/// Returns an error if a TLS option is used, Hurl having been built without TLS support.
+#[cfg(feature = "no-tls")]
+fn check_tls_options(arg_matches: &ArgMatches) -> Result<(), CliOptionsError> {
+    let tls_options = [
+        ("cacert_file", "--cacert"),
+        ("client_cert_file", "--cert"),
+        ("client_key_file", "--key"),
+        ("insecure", "--insecure"),
+        ("insecure_host", "--insecure-host"),
+        ("sni", "--sni"),
+        ("ssl_no_revoke", "--ssl-no-revoke"),
+    ];
+    for (name, flag) in tls_options {
+        if matches::is_present(arg_matches, name) {
+            return Err(CliOptionsError::Error(format!(
+                "{flag} can not be used, this build of Hurl has no TLS support"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Returns an error if an option is used without the option it depends on.
+fn check_dependent_options(arg_matches: &ArgMatches) -> Result<(), CliOptionsError> {
+    if matches::is_present(arg_matches, "diagnostics_json")
+        && !matches::parse_only(arg_matches)
+        && !matches::lint(arg_matches)
+    {
+        return Err(CliOptionsError::Error(
+            "--diagnostics-json can only be used with --parse-only or --lint".to_string(),
+        ));
+    }
+    if matches::is_present(arg_matches, "trace_state") && !matches::trace_context(arg_matches) {
+        return Err(CliOptionsError::Error(
+            "--trace-state can only be used with --trace-context".to_string(),
+        ));
+    }
+    if matches::is_present(arg_matches, "slow_warn_ms")
+        && !matches::print_request_duration_warnings(arg_matches)
+    {
+        return Err(CliOptionsError::Error(
+            "--slow-warn-ms can only be used with --print-request-duration-warnings".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError> {
+    let abort_on_first_assert_failure_within_entry =
+        matches::abort_on_first_assert_failure_within_entry(arg_matches);
+    let accept_encoding = matches::accept_encoding(arg_matches);
+    let assert_file = matches::assert_file(arg_matches)?;
     let aws_sigv4 = matches::aws_sigv4(arg_matches);
+    let body_format = matches::body_format(arg_matches);
+    let body_only_on_failure = matches::body_only_on_failure(arg_matches);
+    let buffer_size = matches::buffer_size(arg_matches);
     let cacert_file = matches::cacert_file(arg_matches)?;
+    let capture_proxy = matches::capture_proxy(arg_matches);
     let client_cert_file = matches::client_cert_file(arg_matches)?;
     let client_key_file = matches::client_key_file(arg_matches)?;
     let color = matches::color(arg_matches);
+    let compress_reports = matches::compress_reports(arg_matches);
     let compressed = matches::compressed(arg_matches);
     let connect_timeout = matches::connect_timeout(arg_matches);
     let connects_to = matches::connects_to(arg_matches);
@@ -251,51 +478,112 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let cookie_input_file = matches::cookie_input_file(arg_matches);
     let cookie_output_file = matches::cookie_output_file(arg_matches);
     let delay = matches::delay(arg_matches);
+    let diagnostics_json = matches::diagnostics_json(arg_matches);
     let error_format = matches::error_format(arg_matches);
+    let explain = matches::explain(arg_matches);
+    let fail_fast = matches::fail_fast(arg_matches);
+    let fail_on = matches::fail_on(arg_matches)?;
     let file_root = matches::file_root(arg_matches);
     let (follow_location, follow_location_trusted) = matches::follow_location(arg_matches);
     let from_entry = matches::from_entry(arg_matches);
+    let header_match = matches::header_match(arg_matches);
     let html_dir = matches::html_dir(arg_matches)?;
     let http_version = matches::http_version(arg_matches);
+    let idempotency_key_header = matches::idempotency_key_header(arg_matches);
     let ignore_asserts = matches::ignore_asserts(arg_matches);
     let include = matches::include(arg_matches);
+    let include_request = matches::include_request(arg_matches);
+    let input = matches::input(arg_matches);
     let input_files = matches::input_files(arg_matches)?;
     let insecure = matches::insecure(arg_matches);
+    let insecure_hosts = matches::insecure_hosts(arg_matches);
     let interactive = matches::interactive(arg_matches);
     let ip_resolve = matches::ip_resolve(arg_matches);
     let junit_file = matches::junit_file(arg_matches);
+    let lint = matches::lint(arg_matches);
+    let list_entries = matches::list_entries(arg_matches);
+    let max_concurrent_per_host = matches::max_concurrent_per_host(arg_matches);
+    let max_header_size = matches::max_header_size(arg_matches);
+    let max_pages = matches::max_pages(arg_matches);
     let max_redirect = matches::max_redirect(arg_matches);
     let max_workers = matches::max_workers(arg_matches);
     let netrc = matches::netrc(arg_matches);
     let netrc_file = matches::netrc_file(arg_matches)?;
     let netrc_optional = matches::netrc_optional(arg_matches);
     let no_proxy = matches::no_proxy(arg_matches);
+    let normalize_json_numbers = matches::normalize_json_numbers(arg_matches);
+    let normalize_newlines = matches::normalize_newlines(arg_matches);
+    let paginate = matches::paginate(arg_matches);
     let parallel = matches::parallel(arg_matches);
+    let parse_only = matches::parse_only(arg_matches);
+    let partial_body = matches::partial_body(arg_matches);
     let path_as_is = matches::path_as_is(arg_matches);
+    let print_curl_on_failure = matches::print_curl_on_failure(arg_matches);
+    let print_request_duration_warnings = matches::print_request_duration_warnings(arg_matches);
+    let print_timings = matches::print_timings(arg_matches);
     let progress_bar = matches::progress_bar(arg_matches);
     let proxy = matches::proxy(arg_matches);
+    let proxy_headers = matches::proxy_headers(arg_matches);
+    let proxy_tunnel = matches::proxy_tunnel(arg_matches);
+    let query = matches::query(arg_matches)?;
+    let randomize_order = matches::randomize_order(arg_matches);
+    let record = matches::record(arg_matches);
+    let replay = matches::replay(arg_matches);
+    let report_sort = matches::report_sort(arg_matches);
     let output = matches::output(arg_matches);
+    let output_append_newline = matches::output_append_newline(arg_matches);
+    let output_split = matches::output_split(arg_matches)?;
     let output_type = matches::output_type(arg_matches);
-    let resolves = matches::resolves(arg_matches);
+    let resolves = matches::resolves(arg_matches)?;
     let retry = matches::retry(arg_matches);
+    let retry_connect = matches::retry_connect(arg_matches);
     let retry_interval = matches::retry_interval(arg_matches);
+    let sample = matches::sample(arg_matches)?;
+    let seed = matches::seed(arg_matches);
+    // With a sample, we only keep a subset of the input files, deterministically selected by the seed.
+    let input_files = match sample {
+        Some(percent) => sample_indices(input_files.len(), percent, seed)
+            .into_iter()
+            .map(|index| input_files[index].clone())
+            .collect(),
+        None => input_files,
+    };
+    let set_at = matches::set_at(arg_matches)?;
+    let setup = matches::setup(arg_matches);
+    let slow_warn_ms = matches::slow_warn_ms(arg_matches);
+    let sni = matches::sni(arg_matches);
+    let sort_headers = matches::sort_headers(arg_matches);
     let ssl_no_revoke = matches::ssl_no_revoke(arg_matches);
     let tap_file = matches::tap_file(arg_matches);
+    let teardown = matches::teardown(arg_matches);
+    let teardown_strict = matches::teardown_strict(arg_matches);
     let test = matches::test(arg_matches);
     let timeout = matches::timeout(arg_matches);
+    let timezone = matches::timezone(arg_matches)?;
     let to_entry = matches::to_entry(arg_matches);
+    let trace_context = matches::trace_context(arg_matches);
+    let trace_state = matches::trace_state(arg_matches);
     let unix_socket = matches::unix_socket(arg_matches);
     let user = matches::user(arg_matches);
     let user_agent = matches::user_agent(arg_matches);
     let variables = matches::variables(arg_matches)?;
     let verbose = matches::verbose(arg_matches);
+    let verify_content_length = matches::verify_content_length(arg_matches);
     let very_verbose = matches::very_verbose(arg_matches);
     Ok(CliOptions {
+        abort_on_first_assert_failure_within_entry,
+        accept_encoding,
+        assert_file,
         aws_sigv4,
+        body_format,
+        body_only_on_failure,
+        buffer_size,
         cacert_file,
+        capture_proxy,
         client_cert_file,
         client_key_file,
         color,
+        compress_reports,
         compressed,
         connect_timeout,
         connects_to,
@@ -303,49 +591,103 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         cookie_input_file,
         cookie_output_file,
         delay,
+        diagnostics_json,
         error_format,
+        explain,
+        fail_fast,
+        fail_on,
         file_root,
         follow_location,
         follow_location_trusted,
         from_entry,
+        header_match,
         html_dir,
         http_version,
+        idempotency_key_header,
         ignore_asserts,
         include,
+        include_request,
+        input,
         input_files,
         insecure,
+        insecure_hosts,
         interactive,
         ip_resolve,
         junit_file,
+        lint,
+        list_entries,
+        max_concurrent_per_host,
+        max_header_size,
+        max_pages,
         max_redirect,
         max_workers,
         netrc,
         netrc_file,
         netrc_optional,
         no_proxy,
+        normalize_json_numbers,
+        normalize_newlines,
+        paginate,
+        partial_body,
         path_as_is,
+        print_curl_on_failure,
+        print_request_duration_warnings,
+        print_timings,
         parallel,
+        parse_only,
         progress_bar,
         proxy,
+        proxy_headers,
+        proxy_tunnel,
+        query,
+        randomize_order,
+        record,
+        replay,
+        report_sort,
         output,
+        output_append_newline,
+        output_split,
         output_type,
         resolves,
         retry,
+        retry_connect,
         retry_interval,
+        sample,
+        seed,
+        set_at,
+        setup,
+        slow_warn_ms,
+        sni,
+        sort_headers,
         ssl_no_revoke,
         tap_file,
+        teardown,
+        teardown_strict,
         test,
         timeout,
+        timezone,
         to_entry,
+        trace_context,
+        trace_state,
         unix_socket,
         user,
         user_agent,
         variables,
         verbose,
+        verify_content_length,
         very_verbose,
     })
 }
 
+/// Order of the files in the reports.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReportSort {
+    /// Files are sorted in execution order.
+    Index,
+    /// Files are sorted by filename.
+    Name,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OutputType {
     ResponseBody,
@@ -355,7 +697,12 @@ pub enum OutputType {
 
 impl CliOptions {
     pub fn to_runner_options(&self, filename: &Input, current_dir: &Path) -> RunnerOptions {
+        let abort_on_first_assert_failure_within_entry =
+            self.abort_on_first_assert_failure_within_entry;
+        let accept_encoding = self.accept_encoding.clone();
+        let assert_file = self.assert_file.clone();
         let aws_sigv4 = self.aws_sigv4.clone();
+        let buffer_size = self.buffer_size;
         let cacert_file = self.cacert_file.clone();
         let client_cert_file = self.client_cert_file.clone();
         let client_key_file = self.client_key_file.clone();
@@ -373,24 +720,33 @@ impl CliOptions {
         let continue_on_error = self.continue_on_error;
         let cookie_input_file = self.cookie_input_file.clone();
         let delay = self.delay;
+        let explain = self.explain;
+        let fail_on = self.fail_on.clone();
         let follow_location = self.follow_location;
         let follow_location_trusted = self.follow_location_trusted;
         let from_entry = self.from_entry;
+        let header_match = self.header_match;
         let http_version = match self.http_version {
             Some(version) => version.into(),
             None => RequestedHttpVersion::default(),
         };
+        let idempotency_key_header = self.idempotency_key_header.clone();
         let ignore_asserts = self.ignore_asserts;
         let insecure = self.insecure;
+        let insecure_hosts = self.insecure_hosts.clone();
         let ip_resolve = match self.ip_resolve {
             Some(ip) => ip.into(),
             None => http::IpResolve::default(),
         };
+        let max_header_size = self.max_header_size;
+        let max_pages = self.max_pages;
         let max_redirect = self.max_redirect;
         let netrc = self.netrc;
         let netrc_file = self.netrc_file.clone();
         let netrc_optional = self.netrc_optional;
         let no_proxy = self.no_proxy.clone();
+        let normalize_json_numbers = self.normalize_json_numbers;
+        let normalize_newlines = self.normalize_newlines;
         // FIXME:
         // When used globally (on the command line), `--output` writes the last successful request
         // to `output` file. We don't want to output every entry's response, so we initialise
@@ -422,6 +778,15 @@ impl CliOptions {
         //   entry_last.output = /tmp/out.bin
         // ```
         let output = None;
+        let paginate = self.paginate.clone();
+        // Bodies written to the standard output, to a file, to a cassette or to an HTML report
+        // must be fully read.
+        let partial_body = self.partial_body
+            && self.output_type == OutputType::NoOutput
+            && self.output.is_none()
+            && self.output_split.is_none()
+            && self.record.is_none()
+            && self.html_dir.is_none();
         let path_as_is = self.path_as_is;
         let post_entry = if self.interactive {
             Some(cli::interactive::post_entry as fn() -> bool)
@@ -433,19 +798,35 @@ impl CliOptions {
         } else {
             None
         };
+        let print_curl_on_failure = self.print_curl_on_failure;
         let proxy = self.proxy.clone();
+        let proxy_headers = self.proxy_headers.clone();
+        let proxy_tunnel = self.proxy_tunnel;
+        let randomize_order = self.randomize_order;
         let resolves = self.resolves.clone();
         let retry = self.retry;
+        let retry_connect = self.retry_connect;
         let retry_interval = self.retry_interval;
+        let seed = self.seed;
+        let set_at = self.set_at.clone();
+        let sni = self.sni.clone();
         let ssl_no_revoke = self.ssl_no_revoke;
         let timeout = self.timeout;
+        let timezone = self.timezone;
         let to_entry = self.to_entry;
+        let trace_context = self.trace_context;
+        let trace_state = self.trace_state.clone();
         let unix_socket = self.unix_socket.clone();
         let user = self.user.clone();
         let user_agent = self.user_agent.clone();
+        let verify_content_length = self.verify_content_length;
 
         RunnerOptionsBuilder::new()
+            .abort_on_first_assert_failure_within_entry(abort_on_first_assert_failure_within_entry)
+            .accept_encoding(accept_encoding)
+            .assert_file(assert_file)
             .aws_sigv4(aws_sigv4)
+            .buffer_size(buffer_size)
             .cacert_file(cacert_file)
             .client_cert_file(client_cert_file)
             .client_key_file(client_key_file)
@@ -456,32 +837,55 @@ impl CliOptions {
             .continue_on_error(continue_on_error)
             .context_dir(&context_dir)
             .cookie_input_file(cookie_input_file)
+            .explain(explain)
+            .fail_on(&fail_on)
             .follow_location(follow_location)
             .follow_location_trusted(follow_location_trusted)
             .from_entry(from_entry)
+            .header_match(header_match)
             .http_version(http_version)
+            .idempotency_key_header(idempotency_key_header)
             .ignore_asserts(ignore_asserts)
             .insecure(insecure)
+            .insecure_hosts(&insecure_hosts)
             .ip_resolve(ip_resolve)
+            .max_header_size(max_header_size)
+            .max_pages(max_pages)
             .max_redirect(max_redirect)
             .netrc(netrc)
             .netrc_file(netrc_file)
             .netrc_optional(netrc_optional)
             .no_proxy(no_proxy)
+            .normalize_json_numbers(normalize_json_numbers)
+            .normalize_newlines(normalize_newlines)
             .output(output)
+            .paginate(paginate)
+            .partial_body(partial_body)
             .path_as_is(path_as_is)
             .post_entry(post_entry)
             .pre_entry(pre_entry)
+            .print_curl_on_failure(print_curl_on_failure)
             .proxy(proxy)
+            .proxy_headers(&proxy_headers)
+            .proxy_tunnel(proxy_tunnel)
+            .randomize_order(randomize_order)
             .resolves(&resolves)
             .retry(retry)
+            .retry_connect(retry_connect)
             .retry_interval(retry_interval)
+            .seed(seed)
+            .set_at(&set_at)
+            .sni(sni)
             .ssl_no_revoke(ssl_no_revoke)
             .timeout(timeout)
+            .timezone(timezone)
             .to_entry(to_entry)
+            .trace_context(trace_context)
+            .trace_state(trace_state)
             .unix_socket(unix_socket)
             .user(user)
             .user_agent(user_agent)
+            .verify_content_length(verify_content_length)
             .build()
     }
 
@@ -504,3 +908,140 @@ impl CliOptions {
             .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arg_matches(args: &[&str]) -> ArgMatches {
+        command()
+            .try_get_matches_from([&["hurl"], args].concat())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_is_present() {
+        let matches = arg_matches(&["--insecure", "--slow-warn-ms", "500", "foo.hurl"]);
+        assert!(matches::is_present(&matches, "insecure"));
+        assert!(matches::is_present(&matches, "slow_warn_ms"));
+        assert!(!matches::is_present(&matches, "follow_location"));
+
+        // Options with a default value are not present if not set explicitly.
+        let matches = arg_matches(&["foo.hurl"]);
+        assert!(!matches::is_present(&matches, "slow_warn_ms"));
+    }
+
+    #[test]
+    fn test_check_dependent_options() {
+        assert!(check_dependent_options(&arg_matches(&["foo.hurl"])).is_ok());
+        assert!(check_dependent_options(&arg_matches(&[
+            "--parse-only",
+            "--diagnostics-json",
+            "foo.hurl"
+        ]))
+        .is_ok());
+        assert!(check_dependent_options(&arg_matches(&[
+            "--lint",
+            "--diagnostics-json",
+            "foo.hurl"
+        ]))
+        .is_ok());
+
+        let err =
+            check_dependent_options(&arg_matches(&["--diagnostics-json", "foo.hurl"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "error: --diagnostics-json can only be used with --parse-only or --lint"
+        );
+
+        assert!(check_dependent_options(&arg_matches(&[
+            "--trace-context",
+            "--trace-state",
+            "vendor=value",
+            "foo.hurl"
+        ]))
+        .is_ok());
+        let err =
+            check_dependent_options(&arg_matches(&["--trace-state", "vendor=value", "foo.hurl"]))
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "error: --trace-state can only be used with --trace-context"
+        );
+
+        assert!(check_dependent_options(&arg_matches(&[
+            "--print-request-duration-warnings",
+            "--slow-warn-ms",
+            "400",
+            "foo.hurl"
+        ]))
+        .is_ok());
+        let err = check_dependent_options(&arg_matches(&["--slow-warn-ms", "400", "foo.hurl"]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "error: --slow-warn-ms can only be used with --print-request-duration-warnings"
+        );
+    }
+
+    #[test]
+    fn test_sequential_only_options() {
+        let args = ["hurl", "--parallel", "--output-split", "out", "foo.hurl"];
+        assert!(command().try_get_matches_from(args).is_err());
+        let args = ["hurl", "--parallel", "--body-only-on-failure", "foo.hurl"];
+        assert!(command().try_get_matches_from(args).is_err());
+    }
+
+    #[test]
+    fn test_built_in_features() {
+        let features = get_built_in_features(&[]);
+        assert!(features.contains(&"brotli"));
+        assert!(!features.contains(&"http3"));
+        if cfg!(feature = "vendored-openssl") {
+            assert!(features.contains(&"vendored-openssl"));
+        } else if cfg!(feature = "ssl") {
+            assert!(features.contains(&"openssl"));
+        } else {
+            assert!(!features.contains(&"openssl"));
+        }
+
+        let features = get_built_in_features(&["HTTP2".to_string(), "HTTP3".to_string()]);
+        assert!(features.contains(&"http3"));
+    }
+
+    #[test]
+    fn test_get_version_json() {
+        let version = get_version_json();
+        let keys = version.as_object().unwrap().keys().collect::<Vec<_>>();
+        assert_eq!(keys, ["features", "libcurl", "version"]);
+        let keys = version["libcurl"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["features", "host", "libraries", "version"]);
+        assert_eq!(version["version"], clap::crate_version!());
+        assert!(version["features"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("brotli")));
+    }
+
+    #[test]
+    #[cfg(feature = "no-tls")]
+    fn test_check_tls_options() {
+        assert!(check_tls_options(&arg_matches(&["foo.hurl"])).is_ok());
+        assert!(check_tls_options(&arg_matches(&["--location", "foo.hurl"])).is_ok());
+
+        let err = check_tls_options(&arg_matches(&["--insecure", "foo.hurl"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "error: --insecure can not be used, this build of Hurl has no TLS support"
+        );
+        let err = check_tls_options(&arg_matches(&["--cacert", "ca.pem", "foo.hurl"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "error: --cacert can not be used, this build of Hurl has no TLS support"
+        );
+    }
+}
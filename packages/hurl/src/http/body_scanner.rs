@@ -0,0 +1,133 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::http::header::CONTENT_ENCODING;
+use crate::http::{mimetype, Header, CONTENT_TYPE};
+
+/// Scans a response body, received chunk by chunk, for a list of needles.
+///
+/// The scanner keeps the tail of the previously scanned chunks, so a needle can be found even if
+/// it spans multiple chunks.
+pub struct BodyScanner {
+    needles: Vec<Vec<u8>>,
+    tail: Vec<u8>,
+}
+
+impl BodyScanner {
+    /// Creates a new scanner looking for every string of `needles`.
+    pub fn new(needles: &[String]) -> Self {
+        let needles = needles.iter().map(|n| n.as_bytes().to_vec()).collect();
+        BodyScanner {
+            needles,
+            tail: vec![],
+        }
+    }
+
+    /// Scans a new `chunk` of the body, and returns `true` if every needle has been found.
+    pub fn scan(&mut self, chunk: &[u8]) -> bool {
+        if self.is_complete() {
+            return true;
+        }
+        let mut window = std::mem::take(&mut self.tail);
+        window.extend_from_slice(chunk);
+        self.needles.retain(|needle| !contains(&window, needle));
+
+        // We only need to keep enough bytes to find the longest remaining needle across the
+        // next chunk boundary.
+        let keep = self
+            .needles
+            .iter()
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+            .saturating_sub(1);
+        let start = window.len().saturating_sub(keep);
+        self.tail = window.split_off(start);
+        self.is_complete()
+    }
+
+    /// Returns `true` if every needle has been found.
+    pub fn is_complete(&self) -> bool {
+        self.needles.is_empty()
+    }
+}
+
+/// Returns `true` if `haystack` contains `needle`.
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    needle.is_empty() || haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+/// Returns `true` if the raw body bytes of a response with this header `line` can be scanned.
+///
+/// A compressed body, or a body that is not encoded in UTF-8, must be fully read to be decoded.
+pub fn is_scannable_header(line: &str) -> bool {
+    let Some(header) = Header::parse(line) else {
+        return true;
+    };
+    if header.name_eq(CONTENT_ENCODING) {
+        return header.value.trim().eq_ignore_ascii_case("identity");
+    }
+    if header.name_eq(CONTENT_TYPE) {
+        return match mimetype::charset(&header.value) {
+            Some(charset) => {
+                let charset = charset.trim_matches('"');
+                charset.eq_ignore_ascii_case("utf-8") || charset.eq_ignore_ascii_case("utf8")
+            }
+            None => true,
+        };
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_single_chunk() {
+        let mut scanner = BodyScanner::new(&["world".to_string()]);
+        assert!(!scanner.is_complete());
+        assert!(scanner.scan(b"Hello world!"));
+        assert!(scanner.is_complete());
+
+        let mut scanner = BodyScanner::new(&["Bob".to_string()]);
+        assert!(!scanner.scan(b"Hello world!"));
+    }
+
+    #[test]
+    fn test_scan_across_chunks() {
+        let mut scanner = BodyScanner::new(&["needle".to_string(), "haystack".to_string()]);
+        assert!(!scanner.scan(b"a hay"));
+        assert!(!scanner.scan(b"stack with a nee"));
+        assert!(!scanner.scan(b"d"));
+        assert!(scanner.scan(b"le in it"));
+    }
+
+    #[test]
+    fn test_is_scannable_header() {
+        assert!(is_scannable_header("Content-Length: 12"));
+        assert!(is_scannable_header("Content-Type: text/plain"));
+        assert!(is_scannable_header(
+            "Content-Type: text/plain; charset=UTF-8"
+        ));
+        assert!(is_scannable_header("content-encoding: identity"));
+        assert!(!is_scannable_header(
+            "Content-Type: text/plain; charset=ISO-8859-1"
+        ));
+        assert!(!is_scannable_header("Content-Encoding: gzip"));
+    }
+}
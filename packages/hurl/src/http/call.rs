@@ -16,11 +16,13 @@
  *
  */
 
+use serde::{Deserialize, Serialize};
+
 use crate::http::{Request, Response, Timings};
 
 /// Holds an HTTP request and the corresponding HTTP response.
 /// The request and responses are the runtime, evaluated datas created by an HTTP exchange.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Call {
     /// The real HTTP request (vs the specified request in a Hurl file source)
     pub request: Request,
@@ -0,0 +1,207 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Record and replay of HTTP exchanges.
+//!
+//! A [`Cassette`] holds HTTP exchanges recorded by a [`RecordingClient`]. The cassette can be
+//! saved to a JSON file and served back later by a [`ReplayClient`], without any network access.
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::http::{Call, Client, ClientOptions, Cookie, HttpClient, HttpError, RequestSpec};
+use crate::util::logger::Logger;
+
+/// A list of recorded HTTP exchanges.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+/// A recorded HTTP exchange: a request and the list of calls executed for this request (there
+/// are many calls when redirections are followed).
+///
+/// Requests are matched on their method, URL and body.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub method: String,
+    pub url: String,
+    pub body: Vec<u8>,
+    pub calls: Vec<Call>,
+}
+
+impl Cassette {
+    /// Reads a cassette from the JSON file `path`.
+    pub fn read(path: &Path) -> Result<Cassette, io::Error> {
+        let data = fs::read(path)?;
+        let cassette = serde_json::from_slice(&data)?;
+        Ok(cassette)
+    }
+
+    /// Writes this cassette to the JSON file `path`.
+    pub fn write(&self, path: &Path) -> Result<(), io::Error> {
+        let data = serde_json::to_vec_pretty(self)?;
+        fs::write(path, data)
+    }
+}
+
+impl Interaction {
+    /// Returns `true` if this interaction has been recorded for `method`, `url` and `body`.
+    fn matches(&self, method: &str, url: &str, body: &[u8]) -> bool {
+        self.method == method && self.url == url && self.body == body
+    }
+}
+
+/// Returns the method, the URL (with its query string parameters) and the body of `request_spec`,
+/// used to match recorded interactions.
+fn request_key(request_spec: &RequestSpec) -> (String, String, Vec<u8>) {
+    let method = request_spec.method.to_string();
+    let mut url = request_spec.url.clone();
+    if !request_spec.querystring.is_empty() {
+        let params = request_spec
+            .querystring
+            .iter()
+            .map(|p| format!("{}={}", p.name, p.value))
+            .collect::<Vec<_>>()
+            .join("&");
+        url.push(if url.contains('?') { '&' } else { '?' });
+        url.push_str(&params);
+    }
+    (method, url, request_spec.body.bytes())
+}
+
+/// An HTTP client that records all the HTTP exchanges executed by an inner client to a
+/// [`Cassette`].
+pub struct RecordingClient {
+    client: Box<dyn HttpClient>,
+    cassette: Cassette,
+}
+
+impl RecordingClient {
+    /// Creates a new recording client, using the libcurl HTTP client and appending exchanges to
+    /// `cassette`.
+    pub fn new(cassette: Cassette) -> Self {
+        RecordingClient::with_client(Box::new(Client::new()), cassette)
+    }
+
+    /// Creates a new recording client, using `client` to execute requests and appending exchanges
+    /// to `cassette`.
+    pub fn with_client(client: Box<dyn HttpClient>, cassette: Cassette) -> Self {
+        RecordingClient { client, cassette }
+    }
+
+    /// Returns the cassette with all the recorded exchanges.
+    pub fn into_cassette(self) -> Cassette {
+        self.cassette
+    }
+}
+
+impl HttpClient for RecordingClient {
+    fn execute_with_redirect(
+        &mut self,
+        request_spec: &RequestSpec,
+        options: &ClientOptions,
+        logger: &mut Logger,
+    ) -> Result<Vec<Call>, HttpError> {
+        let calls = self
+            .client
+            .execute_with_redirect(request_spec, options, logger)?;
+        let (method, url, body) = request_key(request_spec);
+        self.cassette.interactions.push(Interaction {
+            method,
+            url,
+            body,
+            calls: calls.clone(),
+        });
+        Ok(calls)
+    }
+
+    fn get_cookie_storage(&mut self) -> Vec<Cookie> {
+        self.client.get_cookie_storage()
+    }
+
+    fn add_cookie(&mut self, cookie: &Cookie, options: &ClientOptions) {
+        self.client.add_cookie(cookie, options);
+    }
+
+    fn clear_cookie_storage(&mut self, options: &ClientOptions) {
+        self.client.clear_cookie_storage(options);
+    }
+}
+
+/// An HTTP client that serves the HTTP exchanges recorded in a [`Cassette`], without any network
+/// access.
+///
+/// Each recorded interaction is served once, in the order of recording: the same request executed
+/// twice is served with the two successive recorded responses.
+pub struct ReplayClient {
+    cassette: Cassette,
+    cookies: Vec<Cookie>,
+}
+
+impl ReplayClient {
+    /// Creates a new replay client serving the exchanges of `cassette`.
+    pub fn new(cassette: Cassette) -> Self {
+        ReplayClient {
+            cassette,
+            cookies: vec![],
+        }
+    }
+
+    /// Returns the cassette with the exchanges that have not been served yet.
+    pub fn into_cassette(self) -> Cassette {
+        self.cassette
+    }
+}
+
+impl HttpClient for ReplayClient {
+    fn execute_with_redirect(
+        &mut self,
+        request_spec: &RequestSpec,
+        _options: &ClientOptions,
+        logger: &mut Logger,
+    ) -> Result<Vec<Call>, HttpError> {
+        let (method, url, body) = request_key(request_spec);
+        let index = self
+            .cassette
+            .interactions
+            .iter()
+            .position(|i| i.matches(&method, &url, &body));
+        match index {
+            Some(index) => {
+                logger.debug(&format!("* Replay recorded response for {method} {url}"));
+                let interaction = self.cassette.interactions.remove(index);
+                Ok(interaction.calls)
+            }
+            None => Err(HttpError::NoRecordedResponse { method, url }),
+        }
+    }
+
+    fn get_cookie_storage(&mut self) -> Vec<Cookie> {
+        self.cookies.clone()
+    }
+
+    fn add_cookie(&mut self, cookie: &Cookie, _options: &ClientOptions) {
+        self.cookies.push(cookie.clone());
+    }
+
+    fn clear_cookie_storage(&mut self, _options: &ClientOptions) {
+        self.cookies.clear();
+    }
+}
@@ -18,10 +18,12 @@
 use core::fmt;
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
+
 /// [Cookie](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie) returned by
 /// the server with `Set-Cookie` header, and saved in the cookie storage of the internal HTTP
 /// engine.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Cookie {
     /// Defines the host to which the cookie will be sent.
     pub domain: String,
@@ -16,16 +16,23 @@
  *
  */
 use std::ffi::{CStr, CString};
+use std::os::raw::c_long;
 use std::ptr;
 use std::time::Duration;
 
 use curl::easy::Easy;
 use curl::Error;
-use curl_sys::{curl_certinfo, curl_off_t, curl_slist, CURLINFO, CURLOPT_NETRC_FILE};
+use curl_sys::{
+    curl_certinfo, curl_off_t, curl_slist, CURLoption, CURLINFO, CURLOPTTYPE_LONG,
+    CURLOPTTYPE_OBJECTPOINT, CURLOPT_NETRC_FILE,
+};
 
 /// Some definitions not present in curl-sys
 const CURLINFO_OFF_T: CURLINFO = 0x600000;
 
+const CURLINFO_SIZE_UPLOAD_T: CURLINFO = CURLINFO_OFF_T + 7;
+const CURLINFO_SIZE_DOWNLOAD_T: CURLINFO = CURLINFO_OFF_T + 8;
+
 const CURLINFO_TOTAL_TIME_T: CURLINFO = CURLINFO_OFF_T + 50;
 const CURLINFO_NAMELOOKUP_TIME_T: CURLINFO = CURLINFO_OFF_T + 51;
 const CURLINFO_CONNECT_TIME_T: CURLINFO = CURLINFO_OFF_T + 52;
@@ -33,6 +40,11 @@ const CURLINFO_PRETRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 53;
 const CURLINFO_STARTTRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 54;
 const CURLINFO_APPCONNECT_TIME_T: CURLINFO = CURLINFO_OFF_T + 56;
 
+const CURLOPT_PROXYHEADER: CURLoption = CURLOPTTYPE_OBJECTPOINT + 228;
+const CURLOPT_HEADEROPT: CURLoption = CURLOPTTYPE_LONG + 229;
+
+const CURLHEADER_SEPARATE: c_long = 1;
+
 /// Represents certificate information.
 /// `data` has format "name:content";
 #[derive(Clone)]
@@ -180,6 +192,64 @@ pub fn netrc_file(easy: &mut Easy, filename: &str) -> Result<(), Error> {
     })
 }
 
+/// Sets custom HTTP headers to pass to proxies, (and only to proxies).
+///
+/// The headers `list` must be kept alive while `easy` is used.
+pub fn proxy_headers(easy: &mut Easy, list: &StringList) -> Result<(), Error> {
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_HEADEROPT, CURLHEADER_SEPARATE)
+    })?;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_PROXYHEADER, list.raw)
+    })
+}
+
+/// A libcurl linked list of strings.
+///
+/// Contrary to [`curl::easy::List`], the raw list can be passed to options that are not
+/// exposed by the curl crate (like `CURLOPT_PROXYHEADER`).
+#[derive(Debug)]
+pub struct StringList {
+    raw: *mut curl_slist,
+}
+
+// The list is only owned and mutated by its `StringList`, like [`curl::easy::List`].
+unsafe impl Send for StringList {}
+
+impl StringList {
+    /// Creates a new empty list.
+    pub fn new() -> StringList {
+        StringList {
+            raw: ptr::null_mut(),
+        }
+    }
+
+    /// Appends some `data` to this list.
+    pub fn append(&mut self, data: &str) -> Result<(), Error> {
+        let data = CString::new(data)?;
+        unsafe {
+            let raw = curl_sys::curl_slist_append(self.raw, data.as_ptr());
+            if raw.is_null() {
+                return Err(Error::new(curl_sys::CURLE_OUT_OF_MEMORY));
+            }
+            self.raw = raw;
+        }
+        Ok(())
+    }
+}
+
+impl Default for StringList {
+    fn default() -> Self {
+        StringList::new()
+    }
+}
+
+impl Drop for StringList {
+    fn drop(&mut self) {
+        unsafe { curl_sys::curl_slist_free_all(self.raw) }
+    }
+}
+
 /// Converts an instance of libcurl linked list [`curl_slist`] to a vec of [`String`].
 fn to_list(slist: *mut curl_slist) -> Vec<String> {
     let mut data = vec![];
@@ -210,6 +280,27 @@ fn cvt(easy: &Easy, rc: curl_sys::CURLcode) -> Result<(), Error> {
     Err(err)
 }
 
+/// Get the number of uploaded bytes.
+///
+/// Returns the total amount of body bytes that were uploaded in the previous transfer.
+///
+/// Corresponds to [`CURLINFO_SIZE_UPLOAD_T`] and may return an error if the
+/// option isn't supported.
+pub fn size_upload_t(easy: &mut Easy) -> Result<u64, Error> {
+    getopt_off_t(easy, CURLINFO_SIZE_UPLOAD_T).map(|size| size as u64)
+}
+
+/// Get the number of downloaded bytes.
+///
+/// Returns the total amount of body bytes that were downloaded in the previous transfer, before
+/// any decompression.
+///
+/// Corresponds to [`CURLINFO_SIZE_DOWNLOAD_T`] and may return an error if the
+/// option isn't supported.
+pub fn size_download_t(easy: &mut Easy) -> Result<u64, Error> {
+    getopt_off_t(easy, CURLINFO_SIZE_DOWNLOAD_T).map(|size| size as u64)
+}
+
 fn getopt_off_t(easy: &mut Easy, opt: CURLINFO) -> Result<curl_off_t, Error> {
     unsafe {
         let mut p = 0 as curl_off_t;
@@ -283,7 +374,7 @@ mod tests {
     use std::ffi::CString;
     use std::ptr;
 
-    use super::to_list;
+    use super::{to_list, StringList};
 
     #[test]
     fn convert_curl_slist_to_vec() {
@@ -305,4 +396,16 @@ mod tests {
             curl_sys::curl_slist_free_all(slist);
         }
     }
+
+    #[test]
+    fn append_to_string_list() {
+        let mut list = StringList::new();
+        list.append("X-Proxy-Auth: secret").unwrap();
+        list.append("X-Foo: bar").unwrap();
+        assert_eq!(
+            to_list(list.raw),
+            vec!["X-Proxy-Auth: secret".to_string(), "X-Foo: bar".to_string()]
+        );
+        assert!(list.append("X-Bar: \0").is_err());
+    }
 }
@@ -20,10 +20,19 @@ use crate::http::RequestedHttpVersion;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HttpError {
+    /// The received body length `actual` doesn't match the `Content-Length` header value `expected`.
+    ContentLengthMismatch {
+        expected: String,
+        actual: u64,
+    },
     CouldNotParseResponse,
     CouldNotUncompressResponse {
         description: String,
     },
+    /// The cumulative size of the response headers exceeds `max_header_size` bytes.
+    HeaderSizeExceeded {
+        max_header_size: u64,
+    },
     InvalidCharset {
         charset: String,
     },
@@ -38,7 +47,14 @@ pub enum HttpError {
         option: String,
         minimum_version: String,
     },
+    /// There is no recorded response for the request `method` `url` in the replayed cassette.
+    NoRecordedResponse {
+        method: String,
+        url: String,
+    },
     StatuslineIsMissing,
+    /// Hurl has been built without TLS support and `url` can't be requested.
+    TlsNotSupported(String),
     TooManyRedirect,
     UnsupportedContentEncoding {
         description: String,
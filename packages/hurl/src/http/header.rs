@@ -16,7 +16,11 @@
  *
  */
 use core::fmt;
+use std::collections::HashMap;
 use std::slice::Iter;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
 
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Accept-Encoding>
 pub const ACCEPT_ENCODING: &str = "Accept-Encoding";
@@ -26,19 +30,35 @@ pub const AUTHORIZATION: &str = "Authorization";
 pub const COOKIE: &str = "Cookie";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding>
 pub const CONTENT_ENCODING: &str = "Content-Encoding";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Length>
+pub const CONTENT_LENGTH: &str = "Content-Length";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Type>
 pub const CONTENT_TYPE: &str = "Content-Type";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Expect>
 pub const EXPECT: &str = "Expect";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Link>
+pub const LINK: &str = "Link";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Location>
 pub const LOCATION: &str = "Location";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie>
 pub const SET_COOKIE: &str = "Set-Cookie";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Transfer-Encoding>
+pub const TRANSFER_ENCODING: &str = "Transfer-Encoding";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/User-Agent>
 pub const USER_AGENT: &str = "User-Agent";
 
+/// How an HTTP header name is matched when looking up headers by name.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeaderMatch {
+    /// Header names are compared case-insensitively, as specified by HTTP.
+    #[default]
+    CaseInsensitive,
+    /// Header names must have the exact same casing.
+    Exact,
+}
+
 /// Represents an HTTP header.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Header {
     pub name: String,
     pub value: String,
@@ -69,11 +89,25 @@ impl Header {
 
 /// Represents an ordered list of [`Header`].
 /// The headers are sorted by insertion order.
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+///
+/// Lookups by name use an index (lowercased name to headers positions) that is built lazily on
+/// the first lookup, updated on push and invalidated when headers are removed.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "Vec<Header>", into = "Vec<Header>")]
 pub struct HeaderVec {
     headers: Vec<Header>,
+    index: OnceLock<HashMap<String, Vec<usize>>>,
+}
+
+impl PartialEq for HeaderVec {
+    fn eq(&self, other: &Self) -> bool {
+        // The index is a cache and is not part of the equality.
+        self.headers == other.headers
+    }
 }
 
+impl Eq for HeaderVec {}
+
 impl HeaderVec {
     /// Creates an empty [`HeaderVec`].
     pub fn new() -> Self {
@@ -85,17 +119,34 @@ impl HeaderVec {
     /// If there are multiple headers associated with `name`, then the first one is returned.
     /// Use [HeaderVec::get_all] to get all values associated with a given key.
     pub fn get(&self, name: &str) -> Option<&Header> {
-        self.headers.iter().find(|h| h.name_eq(name))
+        self.indices(name)
+            .and_then(|indices| indices.first())
+            .map(|&i| &self.headers[i])
     }
 
     /// Returns a list of header associated with `name`.
     pub fn get_all(&self, name: &str) -> Vec<&Header> {
-        self.headers.iter().filter(|h| h.name_eq(name)).collect()
+        match self.indices(name) {
+            Some(indices) => indices.iter().map(|&i| &self.headers[i]).collect(),
+            None => vec![],
+        }
     }
 
     /// Returns true if there is at least one header with the specified `name`.
     pub fn contains_key(&self, name: &str) -> bool {
-        self.headers.iter().any(|h| h.name_eq(name))
+        self.indices(name).is_some()
+    }
+
+    /// Returns the positions of the headers named `name`, in insertion order.
+    fn indices(&self, name: &str) -> Option<&Vec<usize>> {
+        let index = self.index.get_or_init(|| {
+            let mut index = HashMap::<String, Vec<usize>>::new();
+            for (i, h) in self.headers.iter().enumerate() {
+                index.entry(h.name.to_lowercase()).or_default().push(i);
+            }
+            index
+        });
+        index.get(&name.to_lowercase())
     }
 
     /// Retains only the header specified by the predicate.
@@ -104,6 +155,7 @@ impl HeaderVec {
         F: FnMut(&Header) -> bool,
     {
         self.headers.retain(|h| f(h));
+        self.index.take();
     }
 
     /// Returns an iterator over all the headers.
@@ -126,6 +178,12 @@ impl HeaderVec {
 
     /// Push a new `header` into the headers list.
     pub fn push(&mut self, header: Header) {
+        if let Some(index) = self.index.get_mut() {
+            index
+                .entry(header.name.to_lowercase())
+                .or_default()
+                .push(self.headers.len());
+        }
         self.headers.push(header);
     }
 
@@ -136,6 +194,19 @@ impl HeaderVec {
             .map(|h| h.value.as_str())
             .collect::<Vec<_>>()
     }
+
+    /// Returns all headers values, matching header names with `header_match`.
+    pub fn values_matching(&self, name: &str, header_match: HeaderMatch) -> Vec<&str> {
+        match header_match {
+            HeaderMatch::CaseInsensitive => self.values(name),
+            HeaderMatch::Exact => self
+                .get_all(name)
+                .iter()
+                .filter(|h| h.name == name)
+                .map(|h| h.value.as_str())
+                .collect::<Vec<_>>(),
+        }
+    }
 }
 
 impl<'a> IntoIterator for &'a HeaderVec {
@@ -147,9 +218,24 @@ impl<'a> IntoIterator for &'a HeaderVec {
     }
 }
 
+impl From<Vec<Header>> for HeaderVec {
+    fn from(headers: Vec<Header>) -> Self {
+        HeaderVec {
+            headers,
+            index: OnceLock::new(),
+        }
+    }
+}
+
+impl From<HeaderVec> for Vec<Header> {
+    fn from(headers: HeaderVec) -> Self {
+        headers.headers
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::http::header::HeaderVec;
+    use crate::http::header::{HeaderMatch, HeaderVec};
     use crate::http::Header;
 
     #[test]
@@ -187,6 +273,36 @@ mod tests {
         assert_eq!(headers.len(), 3);
     }
 
+    #[test]
+    fn test_values_matching() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("X-Request-ID", "aaa"));
+        headers.push(Header::new("x-request-id", "bbb"));
+        headers.push(Header::new("Content-Type", "text/plain"));
+
+        // Original casing is preserved.
+        assert_eq!(
+            headers.iter().map(|h| h.name.as_str()).collect::<Vec<_>>(),
+            vec!["X-Request-ID", "x-request-id", "Content-Type"]
+        );
+
+        let ci = HeaderMatch::CaseInsensitive;
+        assert_eq!(
+            headers.values_matching("X-REQUEST-ID", ci),
+            vec!["aaa", "bbb"]
+        );
+        assert_eq!(
+            headers.values_matching("content-type", ci),
+            vec!["text/plain"]
+        );
+
+        let exact = HeaderMatch::Exact;
+        assert_eq!(headers.values_matching("X-Request-ID", exact), vec!["aaa"]);
+        assert_eq!(headers.values_matching("x-request-id", exact), vec!["bbb"]);
+        assert!(headers.values_matching("X-REQUEST-ID", exact).is_empty());
+        assert!(headers.values_matching("content-type", exact).is_empty());
+    }
+
     #[test]
     fn test_iter() {
         let data = [("foo", "xxx"), ("bar", "yyy0"), ("baz", "yyy1")];
@@ -208,4 +324,73 @@ mod tests {
             i += 1;
         }
     }
+
+    #[test]
+    fn test_repeated_names_after_lookup() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Set-Cookie", "a=1"));
+        headers.push(Header::new("foo", "xxx"));
+        headers.push(Header::new("set-cookie", "b=2"));
+
+        // First lookup builds the index.
+        assert_eq!(headers.values("SET-COOKIE"), vec!["a=1", "b=2"]);
+
+        // Pushing after a lookup keeps the index up to date and the insertion order.
+        headers.push(Header::new("SET-COOKIE", "c=3"));
+        headers.push(Header::new("bar", "yyy"));
+        assert_eq!(headers.values("set-cookie"), vec!["a=1", "b=2", "c=3"]);
+        assert_eq!(
+            headers.get("Set-Cookie"),
+            Some(&Header::new("Set-Cookie", "a=1"))
+        );
+        assert_eq!(headers.get("BAR"), Some(&Header::new("bar", "yyy")));
+
+        // Removing headers after a lookup invalidates the index.
+        headers.retain(|h| h.value != "a=1" && !h.name_eq("foo"));
+        assert_eq!(headers.values("set-cookie"), vec!["b=2", "c=3"]);
+        assert_eq!(
+            headers.get("Set-Cookie"),
+            Some(&Header::new("set-cookie", "b=2"))
+        );
+        assert!(!headers.contains_key("foo"));
+        assert_eq!(
+            headers.iter().map(|h| h.value.as_str()).collect::<Vec<_>>(),
+            vec!["b=2", "c=3", "yyy"]
+        );
+    }
+
+    #[test]
+    fn test_eq_ignores_index() {
+        let mut headers1 = HeaderVec::new();
+        headers1.push(Header::new("foo", "xxx"));
+        let headers2 = headers1.clone();
+
+        assert!(headers1.contains_key("foo"));
+        assert_eq!(headers1, headers2);
+    }
+
+    #[test]
+    fn test_many_headers_many_lookups() {
+        let count = 2_000;
+        let mut headers = HeaderVec::new();
+        for i in 0..count {
+            headers.push(Header::new(
+                &format!("x-header-{}", i % 500),
+                &i.to_string(),
+            ));
+        }
+
+        // With a linear scan, this would be ~ count * lookups comparisons (each one lowercasing
+        // names); with the index, it's a map lookup each time.
+        for _ in 0..50 {
+            for i in 0..500 {
+                let name = format!("X-Header-{i}");
+                let values = headers.values(&name);
+                assert_eq!(values.len(), count / 500);
+                assert_eq!(values[0], i.to_string());
+                assert_eq!(headers.get(&name).unwrap().value, i.to_string());
+            }
+        }
+        assert!(!headers.contains_key("x-header-500"));
+    }
 }
@@ -0,0 +1,148 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+use std::sync::{Condvar, Mutex};
+
+/// Limits the number of concurrent requests to the same host, across threads.
+///
+/// A host limiter acts as a semaphore per host: each request acquires a [`HostPermit`] for
+/// its host before being sent, blocking while `max` requests to this host are in flight. Requests
+/// to other hosts are not affected.
+#[derive(Debug)]
+pub struct HostLimiter {
+    /// Maximum number of concurrent requests per host.
+    max: usize,
+    /// Number of in-flight requests, by host.
+    in_flight: Mutex<HashMap<String, usize>>,
+    /// Signaled each time a permit is released.
+    released: Condvar,
+}
+
+/// A permit to send a request to a host, released when dropped.
+#[derive(Debug)]
+pub struct HostPermit<'a> {
+    limiter: &'a HostLimiter,
+    host: String,
+}
+
+impl HostLimiter {
+    /// Creates a new host limiter, allowing at most `max` concurrent requests per host.
+    pub fn new(max: usize) -> Self {
+        HostLimiter {
+            max: max.max(1),
+            in_flight: Mutex::new(HashMap::new()),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Acquires a permit to send a request to `host`, blocking until one is available.
+    pub fn acquire(&self, host: &str) -> HostPermit<'_> {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        while in_flight.get(host).copied().unwrap_or(0) >= self.max {
+            in_flight = self.released.wait(in_flight).unwrap();
+        }
+        *in_flight.entry(host.to_string()).or_insert(0) += 1;
+        HostPermit {
+            limiter: self,
+            host: host.to_string(),
+        }
+    }
+
+    /// Releases a permit for `host`, waking up the threads waiting for a permit.
+    fn release(&self, host: &str) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if let Some(count) = in_flight.get_mut(host) {
+            *count -= 1;
+            if *count == 0 {
+                in_flight.remove(host);
+            }
+        }
+        self.released.notify_all();
+    }
+}
+
+impl Drop for HostPermit<'_> {
+    fn drop(&mut self) {
+        self.limiter.release(&self.host);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Counts the current and maximum number of concurrent requests to a host.
+    #[derive(Default)]
+    struct Probe {
+        current: AtomicUsize,
+        max: AtomicUsize,
+    }
+
+    impl Probe {
+        fn enter(&self) {
+            let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max.fetch_max(current, Ordering::SeqCst);
+        }
+
+        fn leave(&self) {
+            self.current.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn limit_concurrent_requests_per_host() {
+        let limiter = Arc::new(HostLimiter::new(2));
+        let foo = Arc::new(Probe::default());
+        let bar = Arc::new(Probe::default());
+        let all = Arc::new(Probe::default());
+
+        let threads = (0..8)
+            .map(|i| {
+                let limiter = limiter.clone();
+                let all = all.clone();
+                let (host, probe) = if i % 2 == 0 {
+                    ("foo.com", foo.clone())
+                } else {
+                    ("bar.com", bar.clone())
+                };
+                thread::spawn(move || {
+                    let _permit = limiter.acquire(host);
+                    probe.enter();
+                    all.enter();
+                    thread::sleep(Duration::from_millis(50));
+                    all.leave();
+                    probe.leave();
+                })
+            })
+            .collect::<Vec<_>>();
+        for t in threads {
+            t.join().unwrap();
+        }
+
+        // No more than 2 requests for a host, while requests to the other host proceed.
+        assert_eq!(foo.max.load(Ordering::SeqCst), 2);
+        assert_eq!(bar.max.load(Ordering::SeqCst), 2);
+        assert!(all.max.load(Ordering::SeqCst) > 2);
+        assert!(limiter.in_flight.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,267 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::iter::Peekable;
+use std::str::Chars;
+
+use url::Url;
+
+use crate::http::header::LINK;
+use crate::http::Response;
+
+/// Represents a link of a `Link` HTTP header.
+///
+/// See <https://datatracker.ietf.org/doc/html/rfc8288> (previously RFC 5988).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Link {
+    /// The target URL of the link, as written between `<` and `>`.
+    pub url: String,
+    /// The link parameters (`rel`, `title`, `type` etc...), in order of appearance.
+    pub params: Vec<(String, String)>,
+}
+
+impl Link {
+    /// Returns `true` if this link has the relation type `rel`.
+    ///
+    /// A `rel` parameter can hold multiple relation types separated by spaces and relation types
+    /// are compared case-insensitively.
+    pub fn has_rel(&self, rel: &str) -> bool {
+        self.params
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("rel"))
+            .flat_map(|(_, value)| value.split_ascii_whitespace())
+            .any(|value| value.eq_ignore_ascii_case(rel))
+    }
+
+    /// Parses a `Link` header value, that can contain multiple links separated by commas.
+    ///
+    /// Malformed links are ignored.
+    pub fn parse(value: &str) -> Vec<Link> {
+        let mut links = vec![];
+        let mut chars = value.chars().peekable();
+        loop {
+            skip_while(&mut chars, |c| c.is_whitespace() || c == ',');
+            match chars.next() {
+                None => break,
+                Some('<') => {}
+                Some(_) => {
+                    // Not a link, we skip to the next one.
+                    skip_while(&mut chars, |c| c != ',');
+                    continue;
+                }
+            }
+            let url = take_while(&mut chars, |c| c != '>');
+            if chars.next().is_none() {
+                break;
+            }
+            let params = parse_params(&mut chars);
+            links.push(Link {
+                url: url.trim().to_string(),
+                params,
+            });
+        }
+        links
+    }
+}
+
+/// Parses the parameters of a link, until the next link or the end of the header value.
+fn parse_params(chars: &mut Peekable<Chars>) -> Vec<(String, String)> {
+    let mut params = vec![];
+    loop {
+        skip_while(chars, char::is_whitespace);
+        if chars.next_if_eq(&';').is_none() {
+            // End of this link (`,`) or garbage, that we skip.
+            skip_while(chars, |c| c != ',');
+            return params;
+        }
+        skip_while(chars, char::is_whitespace);
+        let name = take_while(chars, |c| c != '=' && c != ';' && c != ',');
+        let name = name.trim().to_string();
+        let value = if chars.next_if_eq(&'=').is_some() {
+            skip_while(chars, char::is_whitespace);
+            if chars.next_if_eq(&'"').is_some() {
+                parse_quoted_string(chars)
+            } else {
+                let value = take_while(chars, |c| c != ';' && c != ',');
+                value.trim().to_string()
+            }
+        } else {
+            String::new()
+        };
+        if !name.is_empty() {
+            params.push((name, value));
+        }
+    }
+}
+
+/// Parses a quoted string, the opening quote being already consumed.
+fn parse_quoted_string(chars: &mut Peekable<Chars>) -> String {
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => break,
+            '\\' => {
+                if let Some(c) = chars.next() {
+                    value.push(c);
+                }
+            }
+            c => value.push(c),
+        }
+    }
+    value
+}
+
+fn skip_while(chars: &mut Peekable<Chars>, predicate: impl Fn(char) -> bool) {
+    while chars.next_if(|c| predicate(*c)).is_some() {}
+}
+
+fn take_while(chars: &mut Peekable<Chars>, predicate: impl Fn(char) -> bool) -> String {
+    let mut value = String::new();
+    while let Some(c) = chars.next_if(|c| predicate(*c)) {
+        value.push(c);
+    }
+    value
+}
+
+impl Response {
+    /// Returns the links of this response `Link` headers.
+    pub fn links(&self) -> Vec<Link> {
+        self.headers
+            .get_all(LINK)
+            .iter()
+            .flat_map(|h| Link::parse(&h.value))
+            .collect()
+    }
+
+    /// Returns the first link of this response with the relation type `rel`.
+    pub fn get_link(&self, rel: &str) -> Option<Link> {
+        self.links().into_iter().find(|link| link.has_rel(rel))
+    }
+
+    /// Returns the URL of the first link of this response with the relation type `rel`.
+    ///
+    /// Absolute URLs are returned as is, relative URLs are resolved against the response URL.
+    pub fn get_link_url(&self, rel: &str) -> Option<String> {
+        let link = self.get_link(rel)?;
+        let url = match Url::parse(&link.url) {
+            Err(url::ParseError::RelativeUrlWithoutBase) => Url::parse(&self.url)
+                .and_then(|base| base.join(&link.url))
+                .map_or(link.url, |url| url.to_string()),
+            _ => link.url,
+        };
+        Some(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{Header, HeaderVec};
+
+    #[test]
+    fn parse_link() {
+        assert_eq!(
+            Link::parse(r#"<https://x?page=2>; rel="next""#),
+            vec![Link {
+                url: "https://x?page=2".to_string(),
+                params: vec![("rel".to_string(), "next".to_string())],
+            }]
+        );
+        assert_eq!(
+            Link::parse(
+                r#"<https://api.github.com/repos?page=3&per_page=100>; rel="next", <https://api.github.com/repos?page=50&per_page=100>;rel=last;title="a, \"b\"; c""#
+            ),
+            vec![
+                Link {
+                    url: "https://api.github.com/repos?page=3&per_page=100".to_string(),
+                    params: vec![("rel".to_string(), "next".to_string())],
+                },
+                Link {
+                    url: "https://api.github.com/repos?page=50&per_page=100".to_string(),
+                    params: vec![
+                        ("rel".to_string(), "last".to_string()),
+                        ("title".to_string(), "a, \"b\"; c".to_string())
+                    ],
+                }
+            ]
+        );
+        assert_eq!(
+            Link::parse("garbage, </page/2>; rel=next"),
+            vec![Link {
+                url: "/page/2".to_string(),
+                params: vec![("rel".to_string(), "next".to_string())],
+            }]
+        );
+        assert!(Link::parse("").is_empty());
+        assert!(Link::parse("<https://x").is_empty());
+    }
+
+    #[test]
+    fn has_rel() {
+        let link = Link {
+            url: "https://x?page=2".to_string(),
+            params: vec![("rel".to_string(), "next  Last".to_string())],
+        };
+        assert!(link.has_rel("next"));
+        assert!(link.has_rel("last"));
+        assert!(!link.has_rel("prev"));
+    }
+
+    #[test]
+    fn get_link() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Link", r#"<https://x?page=1>; rel="prev""#));
+        headers.push(Header::new("Link", r#"<https://x?page=3>; rel="next""#));
+        let response = Response {
+            headers,
+            ..Default::default()
+        };
+        assert_eq!(
+            response.get_link("next").unwrap().url,
+            "https://x?page=3".to_string()
+        );
+        assert_eq!(
+            response.get_link("prev").unwrap().url,
+            "https://x?page=1".to_string()
+        );
+        assert!(response.get_link("last").is_none());
+    }
+
+    #[test]
+    fn get_link_url() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new(
+            "Link",
+            r#"<https://x?page=2>; rel="next", </items?page=9>; rel="last""#,
+        ));
+        let response = Response {
+            headers,
+            url: "https://x/items?page=1".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            response.get_link_url("next"),
+            Some("https://x?page=2".to_string())
+        );
+        // Relative links are resolved against the response URL.
+        assert_eq!(
+            response.get_link_url("last"),
+            Some("https://x/items?page=9".to_string())
+        );
+        assert_eq!(response.get_link_url("prev"), None);
+    }
+}
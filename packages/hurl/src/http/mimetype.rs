@@ -46,6 +46,39 @@ pub fn charset(mime_type: &str) -> Option<String> {
     None
 }
 
+/// Magic numbers used to guess a mime-type from the first bytes of a content.
+/// See <https://en.wikipedia.org/wiki/List_of_file_signatures>
+const SIGNATURES: [(&[u8], &str); 12] = [
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"%PDF-", "application/pdf"),
+    (b"\x1f\x8b", "application/gzip"),
+    (b"PK\x03\x04", "application/zip"),
+    (b"BZh", "application/x-bzip2"),
+    (b"\xfd7zXZ\x00", "application/x-xz"),
+    (b"\x28\xb5\x2f\xfd", "application/zstd"),
+    (b"\x00asm", "application/wasm"),
+    (b"\x7fELF", "application/x-elf"),
+];
+
+/// Guesses the mime-type of `data` by sniffing its first bytes (magic numbers), independently
+/// of any `Content-Type` header. Returns `None` if no known signature matches.
+pub fn sniff(data: &[u8]) -> Option<&'static str> {
+    if let Some((_, mime_type)) = SIGNATURES
+        .iter()
+        .find(|(signature, _)| data.starts_with(signature))
+    {
+        return Some(mime_type);
+    }
+    // RIFF containers hold the actual format at offset 8.
+    if data.starts_with(b"RIFF") && data.len() >= 12 && &data[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+    None
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -74,4 +107,23 @@ pub mod tests {
             Some("utf-8".to_string())
         );
     }
+
+    #[test]
+    pub fn test_sniff() {
+        let png = [0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00];
+        assert_eq!(sniff(&png), Some("image/png"));
+
+        let pdf = b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n";
+        assert_eq!(sniff(pdf), Some("application/pdf"));
+
+        let gzip = [0x1f, 0x8b, 0x08, 0x00, 0xed, 0x0c, 0x84, 0x5f, 0x00, 0x03];
+        assert_eq!(sniff(&gzip), Some("application/gzip"));
+
+        let webp = b"RIFF\x24\x00\x00\x00WEBPVP8 ";
+        assert_eq!(sniff(webp), Some("image/webp"));
+
+        assert_eq!(sniff(b"Hello World!"), None);
+        assert_eq!(sniff(b"\x89PN"), None);
+        assert_eq!(sniff(b""), None);
+    }
 }
@@ -18,27 +18,35 @@
 //! Various HTTP structures like requests, responses, cookies etc.
 //!
 //! The Hurl HTTP engine is not public. It's a wrapper around libcurl and only the models
-//! returned by an HTTP exchange are exposed.
+//! returned by an HTTP exchange are exposed, with the [`HttpClient`] trait that allows running
+//! Hurl files with another HTTP engine.
 pub use self::call::Call;
+pub use self::cassette::{Cassette, RecordingClient, ReplayClient};
 pub use self::certificate::Certificate;
 pub(crate) use self::client::Client;
+pub use self::client::HttpClient;
 pub use self::cookie::{CookieAttribute, ResponseCookie};
 pub use self::core::Cookie;
-pub(crate) use self::core::{Param, RequestCookie};
-pub(crate) use self::error::HttpError;
+pub use self::core::{Param, RequestCookie};
+pub use self::error::HttpError;
 pub use self::header::{
-    Header, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, USER_AGENT,
+    Header, HeaderMatch, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_ENCODING,
+    CONTENT_LENGTH, CONTENT_TYPE, COOKIE, EXPECT, USER_AGENT,
 };
-pub(crate) use self::options::{ClientOptions, Verbosity};
+pub(crate) use self::host_limiter::HostLimiter;
+pub use self::link::Link;
+pub use self::options::{ClientOptions, Verbosity, DEFAULT_ACCEPT_ENCODING};
 pub use self::request::{IpResolve, Request, RequestedHttpVersion};
-pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
+pub use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
 pub use self::response::{HttpVersion, Response};
 #[cfg(test)]
 pub use self::tests::*;
-pub use self::timings::Timings;
+pub use self::timings::{TimingPhases, Timings};
 pub use self::version::libcurl_version_info;
 
+mod body_scanner;
 mod call;
+mod cassette;
 mod certificate;
 mod client;
 mod cookie;
@@ -48,6 +56,8 @@ mod easy_ext;
 mod error;
 mod header;
 mod headers_helper;
+mod host_limiter;
+mod link;
 mod mimetype;
 mod options;
 mod request;
@@ -18,13 +18,21 @@
 use std::time::Duration;
 
 use hurl_core::ast::Retry;
+use url::Url;
 
 use crate::http::request::RequestedHttpVersion;
 use crate::http::IpResolve;
 
+/// Value of the `Accept-Encoding` header sent to request a compressed response.
+pub const DEFAULT_ACCEPT_ENCODING: &str = "gzip, deflate, br";
+
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
+    /// Value of the `Accept-Encoding` header added to the requests that don't define it.
+    pub accept_encoding: Option<String>,
     pub aws_sigv4: Option<String>,
+    pub body_contains: Vec<String>,
+    pub buffer_size: Option<usize>,
     pub cacert_file: Option<String>,
     pub client_cert_file: Option<String>,
     pub client_key_file: Option<String>,
@@ -36,7 +44,9 @@ pub struct ClientOptions {
     pub follow_location_trusted: bool,
     pub http_version: RequestedHttpVersion,
     pub insecure: bool,
+    pub insecure_hosts: Vec<String>,
     pub ip_resolve: IpResolve,
+    pub max_header_size: Option<u64>,
     pub max_redirect: Option<usize>,
     pub netrc: bool,
     pub netrc_file: Option<String>,
@@ -44,14 +54,18 @@ pub struct ClientOptions {
     pub no_proxy: Option<String>,
     pub path_as_is: bool,
     pub proxy: Option<String>,
+    pub proxy_headers: Vec<String>,
+    pub proxy_tunnel: bool,
     pub resolves: Vec<String>,
     pub retry: Retry,
+    pub sni: Option<String>,
     pub ssl_no_revoke: bool,
     pub timeout: Duration,
     pub unix_socket: Option<String>,
     pub user: Option<String>,
     pub user_agent: Option<String>,
     pub verbosity: Option<Verbosity>,
+    pub verify_content_length: bool,
 }
 
 // FIXME/ we could implement copy here
@@ -64,7 +78,10 @@ pub enum Verbosity {
 impl Default for ClientOptions {
     fn default() -> Self {
         ClientOptions {
+            accept_encoding: None,
             aws_sigv4: None,
+            body_contains: vec![],
+            buffer_size: None,
             cacert_file: None,
             client_cert_file: None,
             client_key_file: None,
@@ -76,7 +93,9 @@ impl Default for ClientOptions {
             follow_location_trusted: false,
             http_version: RequestedHttpVersion::default(),
             insecure: false,
+            insecure_hosts: vec![],
             ip_resolve: IpResolve::default(),
+            max_header_size: None,
             max_redirect: Some(50),
             netrc: false,
             netrc_file: None,
@@ -84,19 +103,45 @@ impl Default for ClientOptions {
             no_proxy: None,
             path_as_is: false,
             proxy: None,
+            proxy_headers: vec![],
+            proxy_tunnel: false,
             resolves: vec![],
             retry: Retry::None,
+            sni: None,
             ssl_no_revoke: false,
             timeout: Duration::from_secs(300),
             unix_socket: None,
             user: None,
             user_agent: None,
             verbosity: None,
+            verify_content_length: false,
         }
     }
 }
 
 impl ClientOptions {
+    /// Returns true if certificates verification must be skipped for a request to `url`.
+    ///
+    /// Verification is skipped for all requests with `insecure`, or only for requests whose
+    /// URL host is one of `insecure_hosts`.
+    pub fn is_insecure(&self, url: &str) -> bool {
+        if self.insecure {
+            return true;
+        }
+        if self.insecure_hosts.is_empty() {
+            return false;
+        }
+        let Ok(url) = Url::parse(url) else {
+            return false;
+        };
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        self.insecure_hosts
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case(host))
+    }
+
     /// Returns the list of options for the curl command line equivalent to this [`ClientOptions`].
     pub fn curl_args(&self) -> Vec<String> {
         let mut arguments = vec![];
@@ -120,9 +165,15 @@ impl ClientOptions {
         if self.compressed {
             arguments.push("--compressed".to_string());
         }
+        if let Some(ref accept_encoding) = self.accept_encoding {
+            if accept_encoding != DEFAULT_ACCEPT_ENCODING {
+                arguments.push("--header".to_string());
+                arguments.push(format!("'Accept-Encoding: {accept_encoding}'"));
+            }
+        }
         if self.connect_timeout != ClientOptions::default().connect_timeout {
             arguments.push("--connect-timeout".to_string());
-            arguments.push(self.connect_timeout.as_secs().to_string());
+            arguments.push(self.connect_timeout.as_secs_f64().to_string());
         }
         for connect in self.connects_to.iter() {
             arguments.push("--connect-to".to_string());
@@ -177,13 +228,20 @@ impl ClientOptions {
             arguments.push("--proxy".to_string());
             arguments.push(format!("'{proxy}'"));
         }
+        for header in self.proxy_headers.iter() {
+            arguments.push("--proxy-header".to_string());
+            arguments.push(format!("'{header}'"));
+        }
+        if self.proxy_tunnel {
+            arguments.push("--proxytunnel".to_string());
+        }
         for resolve in self.resolves.iter() {
             arguments.push("--resolve".to_string());
             arguments.push(resolve.clone());
         }
         if self.timeout != ClientOptions::default().timeout {
             arguments.push("--timeout".to_string());
-            arguments.push(self.timeout.as_secs().to_string());
+            arguments.push(self.timeout.as_secs_f64().to_string());
         }
         if let Some(ref unix_socket) = self.unix_socket {
             arguments.push("--unix-socket".to_string());
@@ -211,7 +269,10 @@ mod tests {
 
         assert_eq!(
             ClientOptions {
+                accept_encoding: Some("gzip".to_string()),
                 aws_sigv4: None,
+                body_contains: vec![],
+                buffer_size: Some(65536),
                 cacert_file: None,
                 client_cert_file: None,
                 client_key_file: None,
@@ -223,29 +284,37 @@ mod tests {
                 follow_location_trusted: false,
                 http_version: RequestedHttpVersion::Http10,
                 insecure: true,
+                insecure_hosts: vec![],
                 ip_resolve: IpResolve::IpV6,
+                max_header_size: None,
                 max_redirect: Some(10),
                 netrc: false,
                 netrc_file: Some("/var/run/netrc".to_string()),
                 netrc_optional: true,
                 path_as_is: true,
                 proxy: Some("localhost:3128".to_string()),
+                proxy_headers: vec!["X-Proxy-Auth: secret".to_string()],
+                proxy_tunnel: true,
                 no_proxy: None,
                 resolves: vec![
                     "foo.com:80:192.168.0.1".to_string(),
                     "bar.com:443:127.0.0.1".to_string()
                 ],
                 retry: Retry::None,
+                sni: None,
                 ssl_no_revoke: false,
                 timeout: Duration::from_secs(10),
                 unix_socket: Some("/var/run/example.sock".to_string()),
                 user: Some("user:password".to_string()),
                 user_agent: Some("my-useragent".to_string()),
                 verbosity: None,
+                verify_content_length: false,
             }
             .curl_args(),
             [
                 "--compressed",
+                "--header",
+                "'Accept-Encoding: gzip'",
                 "--connect-timeout",
                 "20",
                 "--connect-to",
@@ -264,6 +333,9 @@ mod tests {
                 "--path-as-is",
                 "--proxy",
                 "'localhost:3128'",
+                "--proxy-header",
+                "'X-Proxy-Auth: secret'",
+                "--proxytunnel",
                 "--resolve",
                 "foo.com:80:192.168.0.1",
                 "--resolve",
@@ -280,4 +352,40 @@ mod tests {
             .map(|a| a.to_string())
         );
     }
+
+    #[test]
+    fn test_curl_args_sub_second_timeouts() {
+        let options = ClientOptions {
+            connect_timeout: Duration::from_millis(200),
+            timeout: Duration::from_millis(1500),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.curl_args(),
+            ["--connect-timeout", "0.2", "--timeout", "1.5"].map(|a| a.to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_insecure() {
+        let options = ClientOptions::default();
+        assert!(!options.is_insecure("https://example.com/hello"));
+
+        let options = ClientOptions {
+            insecure: true,
+            ..Default::default()
+        };
+        assert!(options.is_insecure("https://example.com/hello"));
+
+        let options = ClientOptions {
+            insecure_hosts: vec!["example.com".to_string(), "localhost".to_string()],
+            ..Default::default()
+        };
+        assert!(options.is_insecure("https://example.com/hello"));
+        assert!(options.is_insecure("https://EXAMPLE.com:8443/hello"));
+        assert!(options.is_insecure("https://localhost:8001/hello"));
+        assert!(!options.is_insecure("https://foo.example.com/hello"));
+        assert!(!options.is_insecure("https://127.0.0.1:8001/hello"));
+        assert!(!options.is_insecure("not an url"));
+    }
 }
@@ -17,6 +17,8 @@
  */
 use std::fmt;
 
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::http::core::*;
@@ -28,7 +30,7 @@ use crate::http::HttpError;
 /// It's different from `crate::http::RequestSpec` which is the request asked to be executed by our
 /// user. For instance, in the request spec, headers implicitly added by curl are not present, while
 /// they will be present in the [`Request`] instances.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Request {
     pub url: String,
     pub method: String,
@@ -36,7 +38,7 @@ pub struct Request {
     pub body: Vec<u8>,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum RequestedHttpVersion {
     #[default]
     Default, // The effective HTTP version will be chosen by libcurl
@@ -124,6 +126,27 @@ impl Request {
         };
         Ok(format!("{scheme}://{host}{port}"))
     }
+
+    /// Returns method, url and HTTP headers from this HTTP request.
+    pub fn get_request_line_headers(&self, color: bool) -> String {
+        let mut str = String::new();
+        let request_line = format!("{} {}\n", self.method, self.url);
+        let request_line = if color {
+            format!("{}", request_line.yellow().bold())
+        } else {
+            request_line
+        };
+        str.push_str(&request_line);
+        for header in self.headers.iter() {
+            let header_line = if color {
+                format!("{}: {}\n", header.name.cyan().bold(), header.value)
+            } else {
+                format!("{}: {}\n", header.name, header.value)
+            };
+            str.push_str(&header_line);
+        }
+        str
+    }
 }
 
 fn parse_cookies(s: &str) -> Vec<RequestCookie> {
@@ -276,4 +299,16 @@ mod tests {
             "https://localhost:8000".to_string()
         );
     }
+
+    #[test]
+    fn test_get_request_line_headers() {
+        assert_eq!(
+            hello_request().get_request_line_headers(false),
+            "GET http://localhost:8000/hello\n\
+             Host: localhost:8000\n\
+             Accept: */*\n\
+             User-Agent: hurl/1.0\n\
+             content-type: application/json\n"
+        );
+    }
 }
@@ -17,6 +17,7 @@
  */
 use core::fmt;
 
+use crate::http::client::match_cookie;
 use crate::http::core::*;
 use crate::http::header::HeaderVec;
 
@@ -31,6 +32,9 @@ pub struct RequestSpec {
     pub form: Vec<Param>,
     pub multipart: Vec<MultipartParam>,
     pub cookies: Vec<RequestCookie>,
+    /// Cookies scoped by domain and path, only sent if they match the request URL, like cookies
+    /// of a cookie storage.
+    pub scoped_cookies: Vec<Cookie>,
     pub body: Body,
     /// This is the implicit content type of the request: this content type is implicitly set when
     /// the request use a "typed" body: form, JSON, multipart, multiline string with hint. This
@@ -49,6 +53,7 @@ impl Default for RequestSpec {
             form: vec![],
             multipart: vec![],
             cookies: vec![],
+            scoped_cookies: vec![],
             body: Body::Binary(vec![]),
             implicit_content_type: None,
         }
@@ -79,6 +84,23 @@ pub enum Body {
     File(Vec<u8>, String),
 }
 
+impl RequestSpec {
+    /// Returns the cookies of this request, with the scoped cookies matching the request URL.
+    pub fn matching_cookies(&self) -> Vec<RequestCookie> {
+        let mut cookies = self.cookies.clone();
+        cookies.extend(
+            self.scoped_cookies
+                .iter()
+                .filter(|c| match_cookie(c, &self.url))
+                .map(|c| RequestCookie {
+                    name: c.name.clone(),
+                    value: c.value.clone(),
+                }),
+        );
+        cookies
+    }
+}
+
 impl Body {
     pub fn bytes(&self) -> Vec<u8> {
         match self {
@@ -166,7 +166,7 @@ impl Header {
 
 impl Param {
     pub fn curl_arg_escape(&self) -> String {
-        let name = &self.name;
+        let name = escape_url(&self.name);
         let value = escape_url(&self.value);
         format!("{name}={value}")
     }
@@ -375,6 +375,14 @@ pub mod tests {
             .curl_arg_escape(),
             "param4=1%2C2%2C3".to_string()
         );
+        assert_eq!(
+            Param {
+                name: "a b&c".to_string(),
+                value: "x=1&y=2 #z".to_string(),
+            }
+            .curl_arg_escape(),
+            "a%20b%26c=x%3D1%26y%3D2%20%23z".to_string()
+        );
     }
 
     #[test]
@@ -18,21 +18,32 @@
 use std::fmt;
 use std::time::Duration;
 
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
 use crate::http::certificate::Certificate;
-use crate::http::HeaderVec;
+use crate::http::header::{CONTENT_LENGTH, TRANSFER_ENCODING};
+use crate::http::{HeaderVec, HttpError};
 
 /// Represents a runtime HTTP response.
 /// This is a real response, that has been executed by our HTTP client.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Response {
     pub version: HttpVersion,
     pub status: u32,
     pub headers: HeaderVec,
-    pub body: Vec<u8>,
+    pub body: Bytes,
     pub duration: Duration,
     pub url: String,
     /// The end-user certificate, in the response certificate chain
     pub certificate: Option<Certificate>,
+    /// The number of request body bytes sent over the network
+    #[serde(default)]
+    pub bytes_sent: u64,
+    /// The number of response body bytes received over the network (compressed if the body is
+    /// compressed)
+    #[serde(default)]
+    pub bytes_received: u64,
 }
 
 impl Default for Response {
@@ -41,10 +52,12 @@ impl Default for Response {
             version: HttpVersion::Http10,
             status: 200,
             headers: HeaderVec::new(),
-            body: vec![],
+            body: Bytes::new(),
             duration: Default::default(),
             url: String::new(),
             certificate: None,
+            bytes_sent: 0,
+            bytes_received: 0,
         }
     }
 }
@@ -55,7 +68,7 @@ impl Response {
         version: HttpVersion,
         status: u32,
         headers: HeaderVec,
-        body: Vec<u8>,
+        body: Bytes,
         duration: Duration,
         url: &str,
         certificate: Option<Certificate>,
@@ -68,13 +81,50 @@ impl Response {
             duration,
             url: url.to_string(),
             certificate,
+            bytes_sent: 0,
+            bytes_received: 0,
+        }
+    }
+
+    /// Returns the transfer encodings of this response (like `chunked`), as received in the
+    /// `Transfer-Encoding` headers, or `identity` if the body has been transferred as is.
+    pub fn transfer_encoding(&self) -> String {
+        let values = self.headers.values(TRANSFER_ENCODING);
+        if values.is_empty() {
+            return "identity".to_string();
+        }
+        values
+            .iter()
+            .map(|v| v.trim())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Checks that the length of the received body matches the `Content-Length` header, if any.
+    ///
+    /// This can be used to detect truncated responses. Responses without body (`1xx`, `204` and
+    /// `304` status code) are not checked.
+    pub fn verify_content_length(&self) -> Result<(), HttpError> {
+        if self.status < 200 || self.status == 204 || self.status == 304 {
+            return Ok(());
+        }
+        let Some(header) = self.headers.get(CONTENT_LENGTH) else {
+            return Ok(());
+        };
+        let actual = self.body.len() as u64;
+        match header.value.trim().parse::<u64>() {
+            Ok(expected) if expected == actual => Ok(()),
+            _ => Err(HttpError::ContentLengthMismatch {
+                expected: header.value.clone(),
+                actual,
+            }),
         }
     }
 }
 
 /// Represents the HTTP version of a HTTP transaction.
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/Evolution_of_HTTP>
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HttpVersion {
     Http10,
     Http11,
@@ -111,4 +161,74 @@ mod tests {
         assert_eq!(response.headers.values("Content-Length"), vec!["12"]);
         assert!(response.headers.values("Unknown").is_empty());
     }
+
+    #[test]
+    fn body_slices_share_buffer() {
+        let response = Response {
+            body: Bytes::from(b"Hello World!".to_vec()),
+            ..Default::default()
+        };
+
+        // Cloning the response or slicing its body doesn't copy the underlying buffer.
+        let cloned = response.clone();
+        assert_eq!(cloned.body.as_ptr(), response.body.as_ptr());
+        let world = response.body.slice(6..);
+        assert_eq!(world, b"World!".as_slice());
+        assert_eq!(world.as_ptr(), response.body[6..].as_ptr());
+
+        // Without content encoding, the uncompressed body is the response body itself.
+        let body = response.uncompress_body().unwrap();
+        assert_eq!(body.as_ptr(), response.body.as_ptr());
+    }
+
+    #[test]
+    fn transfer_encoding() {
+        let response = Response::default();
+        assert_eq!(response.transfer_encoding(), "identity");
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Transfer-Encoding", "chunked"));
+        let response = Response {
+            headers,
+            ..Default::default()
+        };
+        assert_eq!(response.transfer_encoding(), "chunked");
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Transfer-Encoding", "gzip"));
+        headers.push(Header::new("transfer-encoding", " chunked"));
+        let response = Response {
+            headers,
+            ..Default::default()
+        };
+        assert_eq!(response.transfer_encoding(), "gzip, chunked");
+    }
+
+    #[test]
+    fn verify_content_length() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Length", "12"));
+        let mut response = Response {
+            headers,
+            body: Bytes::from(b"Hello World!".to_vec()),
+            ..Default::default()
+        };
+        assert!(response.verify_content_length().is_ok());
+
+        response.body = Bytes::from(b"Hello".to_vec());
+        assert_eq!(
+            response.verify_content_length(),
+            Err(HttpError::ContentLengthMismatch {
+                expected: "12".to_string(),
+                actual: 5
+            })
+        );
+
+        // Responses without Content-Length header or without body are not checked.
+        response.status = 304;
+        assert!(response.verify_content_length().is_ok());
+        response.status = 200;
+        response.headers = HeaderVec::new();
+        assert!(response.verify_content_length().is_ok());
+    }
 }
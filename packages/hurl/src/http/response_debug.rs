@@ -40,14 +40,17 @@ impl Response {
     }
 
     pub fn log_info_all(&self, logger: &mut Logger) {
-        let status_line = self.get_status_line_headers(logger.color);
+        let status_line = self.get_status_line_headers(logger.color, false);
         logger.info(&status_line);
         self.log_body(false, logger);
         logger.info("");
     }
 
     /// Returns status, version and HTTP headers from this HTTP response.
-    pub fn get_status_line_headers(&self, color: bool) -> String {
+    ///
+    /// If `sort_headers` is true, headers are sorted by name (case-insensitive), headers with the
+    /// same name being kept in their original order. Otherwise, headers are in insertion order.
+    pub fn get_status_line_headers(&self, color: bool, sort_headers: bool) -> String {
         let mut str = String::new();
         let status_line = format!("{} {}\n", self.version, self.status);
         let status_line = if color {
@@ -56,7 +59,11 @@ impl Response {
             status_line
         };
         str.push_str(&status_line);
-        for header in self.headers.iter() {
+        let mut headers = self.headers.iter().collect::<Vec<_>>();
+        if sort_headers {
+            headers.sort_by_key(|h| h.name.to_lowercase());
+        }
+        for header in headers {
             let header_line = if color {
                 format!("{}: {}\n", header.name.cyan().bold(), header.value)
             } else {
@@ -23,6 +23,7 @@
 /// See https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding
 use std::io::prelude::*;
 
+use bytes::Bytes;
 use encoding::DecoderTrap;
 
 use crate::http::{mimetype, HttpError, Response};
@@ -78,12 +79,22 @@ impl Response {
         self.headers.content_type().map_or(false, mimetype::is_html)
     }
 
+    /// Returns the mime-type guessed from the response body bytes (uncompressed), regardless
+    /// of the `Content-Type` header.
+    pub fn sniff_content_type(&self) -> Result<Option<&'static str>, HttpError> {
+        let body = self.uncompress_body()?;
+        Ok(mimetype::sniff(&body))
+    }
+
     /// Decompresses HTTP body response.
-    pub fn uncompress_body(&self) -> Result<Vec<u8>, HttpError> {
+    ///
+    /// If the response has no content encoding, the returned bytes share the response body
+    /// buffer and no copy is made.
+    pub fn uncompress_body(&self) -> Result<Bytes, HttpError> {
         let encodings = self.headers.content_encoding()?;
         let mut data = self.body.clone();
         for encoding in &encodings {
-            data = encoding.decode(&data)?;
+            data = encoding.decode(&data)?.into();
         }
         Ok(data)
     }
@@ -215,10 +226,14 @@ pub mod tests {
             body: vec![
                 0x21, 0x2c, 0x00, 0x04, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x57, 0x6f, 0x72, 0x6c,
                 0x64, 0x21, 0x03,
-            ],
+            ]
+            .into(),
             ..Default::default()
         };
-        assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
+        assert_eq!(
+            response.uncompress_body().unwrap(),
+            b"Hello World!".as_slice()
+        );
 
         let mut headers = HeaderVec::new();
         headers.push(Header::new("Content-Encoding", "br, identity"));
@@ -227,16 +242,23 @@ pub mod tests {
             body: vec![
                 0x21, 0x2c, 0x00, 0x04, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x57, 0x6f, 0x72, 0x6c,
                 0x64, 0x21, 0x03,
-            ],
+            ]
+            .into(),
             ..Default::default()
         };
-        assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
+        assert_eq!(
+            response.uncompress_body().unwrap(),
+            b"Hello World!".as_slice()
+        );
 
         let response = Response {
-            body: b"Hello World!".to_vec(),
+            body: Bytes::from_static(b"Hello World!"),
             ..Default::default()
         };
-        assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
+        assert_eq!(
+            response.uncompress_body().unwrap(),
+            b"Hello World!".as_slice()
+        );
     }
 
     #[test]
@@ -286,7 +308,7 @@ pub mod tests {
 
     fn hello_response() -> Response {
         Response {
-            body: b"Hello World!".to_vec(),
+            body: Bytes::from_static(b"Hello World!"),
             ..Default::default()
         }
     }
@@ -297,7 +319,7 @@ pub mod tests {
 
         Response {
             headers,
-            body: vec![0x63, 0x61, 0x66, 0xc3, 0xa9],
+            body: vec![0x63, 0x61, 0x66, 0xc3, 0xa9].into(),
             ..Default::default()
         }
     }
@@ -311,7 +333,7 @@ pub mod tests {
 
         Response {
             headers,
-            body: vec![0x63, 0x61, 0x66, 0xe9],
+            body: vec![0x63, 0x61, 0x66, 0xe9].into(),
             ..Default::default()
         }
     }
@@ -375,7 +397,7 @@ pub mod tests {
         assert_eq!(
             Response {
                 headers,
-                body: b"Hello World!".to_vec(),
+                body: Bytes::from_static(b"Hello World!"),
                 ..Default::default()
             }
             .headers
@@ -392,7 +414,7 @@ pub mod tests {
     pub fn test_invalid_decoding() {
         assert_eq!(
             Response {
-                body: vec![0x63, 0x61, 0x66, 0xe9],
+                body: vec![0x63, 0x61, 0x66, 0xe9].into(),
                 ..Default::default()
             }
             .text()
@@ -412,7 +434,7 @@ pub mod tests {
         assert_eq!(
             Response {
                 headers,
-                body: vec![0x63, 0x61, 0x66, 0xc3, 0xa9],
+                body: vec![0x63, 0x61, 0x66, 0xc3, 0xa9].into(),
                 ..Default::default()
             }
             .text()
@@ -41,7 +41,8 @@ pub fn json_http_response() -> Response {
 }
 "#
             .to_string(),
-        ),
+        )
+        .into(),
         ..Default::default()
     }
 }
@@ -62,7 +63,8 @@ pub fn xml_two_users_http_response() -> Response {
 </users>
 "#
             .to_string(),
-        ),
+        )
+        .into(),
         ..Default::default()
     }
 }
@@ -84,7 +86,8 @@ pub fn xml_three_users_http_response() -> Response {
 </users>
 "#
             .to_string(),
-        ),
+        )
+        .into(),
         ..Default::default()
     }
 }
@@ -96,7 +99,7 @@ pub fn hello_http_response() -> Response {
 
     Response {
         headers,
-        body: String::into_bytes(String::from("Hello World!")),
+        body: String::into_bytes(String::from("Hello World!")).into(),
         ..Default::default()
     }
 }
@@ -108,7 +111,7 @@ pub fn bytes_http_response() -> Response {
 
     Response {
         headers,
-        body: vec![255],
+        body: vec![255].into(),
         ..Default::default()
     }
 }
@@ -121,7 +124,8 @@ pub fn html_http_response() -> Response {
         headers,
         body: String::into_bytes(String::from(
             "<html><head><meta charset=\"UTF-8\"></head><body><br></body></html>",
-        )),
+        ))
+        .into(),
         ..Default::default()
     }
 }
@@ -19,6 +19,7 @@ use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use curl::easy::Easy;
+use serde::{Deserialize, Serialize};
 
 use crate::http::easy_ext;
 
@@ -26,7 +27,7 @@ use crate::http::easy_ext;
 // See [`easy_ext::namelookup_time_t`], [`easy_ext::connect_time_t`], [`easy_ext::app_connect_time_t`],
 // [`easy_ext::pre_transfer_time_t`], [`easy_ext::start_transfer_time_t`] and [`easy_ext::total_time_t`]
 // for fields definition.
-#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct Timings {
     pub begin_call: DateTime<Utc>,
     pub end_call: DateTime<Utc>,
@@ -72,4 +73,89 @@ impl Timings {
             total,
         }
     }
+
+    /// Returns the duration of each phase of the transfer.
+    ///
+    /// Contrary to the libcurl timings, which are measured from the start of the transfer, phases
+    /// are deltas between two consecutive timings. For a consistent set of timings, the sum of
+    /// the phases is equal to the `total` timing.
+    pub fn phases(&self) -> TimingPhases {
+        // Without TLS, `app_connect` is zero, so each step is clamped to the previous one.
+        let dns_end = self.name_lookup;
+        let connect_end = self.connect.max(dns_end);
+        let tls_end = self.app_connect.max(connect_end);
+        let ttfb_end = self.start_transfer.max(tls_end);
+        TimingPhases {
+            dns: dns_end,
+            connect: connect_end - dns_end,
+            tls: tls_end - connect_end,
+            ttfb: ttfb_end - tls_end,
+            transfer: self.total.saturating_sub(ttfb_end),
+        }
+    }
+}
+
+/// Durations of the successive phases of an HTTP transfer, derived from [`Timings`].
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct TimingPhases {
+    /// Time spent resolving the host name.
+    pub dns: Duration,
+    /// Time spent establishing the TCP connection, after name resolution.
+    pub connect: Duration,
+    /// Time spent on the SSL/TLS handshake, zero without TLS.
+    pub tls: Duration,
+    /// Time to first byte: from the established connection to the first byte received.
+    pub ttfb: Duration,
+    /// Time spent receiving the response, after the first byte.
+    pub transfer: Duration,
+}
+
+impl TimingPhases {
+    /// Returns the sum of all the phases.
+    pub fn total(&self) -> Duration {
+        self.dns + self.connect + self.tls + self.ttfb + self.transfer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(app_connect: u64) -> Timings {
+        Timings {
+            name_lookup: Duration::from_micros(1_200),
+            connect: Duration::from_micros(3_500),
+            app_connect: Duration::from_micros(app_connect),
+            pre_transfer: Duration::from_micros(9_100),
+            start_transfer: Duration::from_micros(25_000),
+            total: Duration::from_micros(27_300),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn phases_with_tls() {
+        let timings = timings(9_000);
+        let phases = timings.phases();
+        assert_eq!(
+            phases,
+            TimingPhases {
+                dns: Duration::from_micros(1_200),
+                connect: Duration::from_micros(2_300),
+                tls: Duration::from_micros(5_500),
+                ttfb: Duration::from_micros(16_000),
+                transfer: Duration::from_micros(2_300),
+            }
+        );
+        assert_eq!(phases.total(), timings.total);
+    }
+
+    #[test]
+    fn phases_without_tls() {
+        let timings = timings(0);
+        let phases = timings.phases();
+        assert_eq!(phases.tls, Duration::ZERO);
+        assert_eq!(phases.ttfb, Duration::from_micros(21_500));
+        assert_eq!(phases.total(), timings.total);
+    }
 }
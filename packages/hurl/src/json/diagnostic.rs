@@ -0,0 +1,84 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::Pos;
+
+use crate::linter::Diagnostic;
+
+impl Diagnostic {
+    /// Serializes a [`Diagnostic`] to a JSON representation.
+    ///
+    /// Positions are converted to the Language Server Protocol conventions: lines and characters
+    /// are zero-based.
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut map = serde_json::Map::new();
+        map.insert(
+            "filename".to_string(),
+            serde_json::Value::String(self.filename.clone()),
+        );
+        map.insert(
+            "severity".to_string(),
+            serde_json::Value::String(self.severity.as_str().to_string()),
+        );
+        map.insert(
+            "message".to_string(),
+            serde_json::Value::String(self.message.clone()),
+        );
+        let mut range = serde_json::Map::new();
+        range.insert("start".to_string(), pos_to_json(self.source_info.start));
+        range.insert("end".to_string(), pos_to_json(self.source_info.end));
+        map.insert("range".to_string(), serde_json::Value::Object(range));
+        serde_json::Value::Object(map)
+    }
+}
+
+fn pos_to_json(pos: Pos) -> serde_json::Value {
+    let mut map = serde_json::Map::new();
+    let line = pos.line.saturating_sub(1);
+    let character = pos.column.saturating_sub(1);
+    map.insert(
+        "line".to_string(),
+        serde_json::Value::Number(serde_json::Number::from(line)),
+    );
+    map.insert(
+        "character".to_string(),
+        serde_json::Value::Number(serde_json::Number::from(character)),
+    );
+    serde_json::Value::Object(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::SourceInfo;
+
+    use super::*;
+    use crate::linter::Severity;
+
+    #[test]
+    fn test_diagnostic_to_json() {
+        let diagnostic = Diagnostic {
+            filename: "test.hurl".to_string(),
+            severity: Severity::Error,
+            message: "Parsing method: the HTTP method <get> is not valid".to_string(),
+            source_info: SourceInfo::new(Pos::new(2, 1), Pos::new(2, 4)),
+        };
+        assert_eq!(
+            diagnostic.to_json().to_string(),
+            r#"{"filename":"test.hurl","message":"Parsing method: the HTTP method <get> is not valid","range":{"end":{"character":3,"line":1},"start":{"character":0,"line":1}},"severity":"error"}"#
+        );
+    }
+}
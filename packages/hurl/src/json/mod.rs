@@ -16,5 +16,6 @@
  *
  */
 
+mod diagnostic;
 mod result;
 mod value;
@@ -21,7 +21,7 @@ use serde_json::Number;
 
 use crate::http::{
     Call, Certificate, Cookie, Header, HttpVersion, Param, Request, RequestCookie, Response,
-    ResponseCookie, Timings,
+    ResponseCookie, TimingPhases, Timings,
 };
 use crate::runner::{AssertResult, CaptureResult, EntryResult, HurlResult, Input};
 use crate::util::logger;
@@ -313,6 +313,28 @@ impl Timings {
             "total".to_string(),
             serde_json::Value::Number(Number::from(value)),
         );
+        map.insert("phases".to_string(), self.phases().to_json());
+        serde_json::Value::Object(map)
+    }
+}
+
+impl TimingPhases {
+    /// Serializes the phases as deltas in milliseconds, with the sum of the phases as `total`.
+    fn to_json(&self) -> serde_json::Value {
+        let mut map = serde_json::Map::new();
+        let phases = [
+            ("dns", self.dns),
+            ("connect", self.connect),
+            ("tls", self.tls),
+            ("ttfb", self.ttfb),
+            ("transfer", self.transfer),
+            ("total", self.total()),
+        ];
+        for (name, duration) in phases {
+            let value = duration.as_micros() as f64 / 1000.0;
+            let value = Number::from_f64(value).unwrap_or(Number::from(0));
+            map.insert(name.to_string(), serde_json::Value::Number(value));
+        }
         serde_json::Value::Object(map)
     }
 }
@@ -340,6 +362,9 @@ impl AssertResult {
 
         let success = self.error().is_none();
         map.insert("success".to_string(), serde_json::Value::Bool(success));
+        if self.is_soft() {
+            map.insert("soft".to_string(), serde_json::Value::Bool(true));
+        }
 
         if let Some(err) = self.error() {
             let message = logger::error_string(
@@ -39,10 +39,15 @@
 //! The main function of this crate is [`runner::run`].
 //!
 //! This crate works on Windows, macOS and Linux.
+#[cfg(all(feature = "no-tls", feature = "ssl"))]
+compile_error!("feature \"no-tls\" must be used with `--no-default-features`");
+
+pub mod capture;
 mod html;
 pub mod http;
 mod json;
 mod jsonpath;
+pub mod linter;
 pub mod output;
 #[doc(hidden)]
 pub mod parallel;
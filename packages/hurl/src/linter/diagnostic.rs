@@ -0,0 +1,57 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+use hurl_core::error::Error;
+
+/// Represents a parsing error or a lint warning on a Hurl file, in a format suitable for editors
+/// integration (see the [Language Server Protocol](https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#diagnostic)).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub filename: String,
+    pub severity: Severity,
+    pub message: String,
+    pub source_info: SourceInfo,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Diagnostic {
+    /// Creates a new diagnostic of `severity` from an `error` of the file `filename`.
+    pub fn new<E: Error>(filename: &str, severity: Severity, error: &E) -> Diagnostic {
+        let message = format!("{}: {}", error.description(), error.fixme());
+        Diagnostic {
+            filename: filename.to_string(),
+            severity,
+            message,
+            source_info: error.source_info(),
+        }
+    }
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Linter for likely mistakes in Hurl files.
+//!
+//! Contrary to parsing errors, a Hurl file with lint warnings is syntactically valid and can be
+//! run, but it probably doesn't do what its author expects.
+use hurl_core::ast::SourceInfo;
+use hurl_core::error::Error;
+
+pub use self::diagnostic::{Diagnostic, Severity};
+pub use self::rules::check_hurl_file;
+
+mod diagnostic;
+mod rules;
+
+/// Represents a lint warning on a Hurl file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warning {
+    pub source_info: SourceInfo,
+    pub inner: WarningKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WarningKind {
+    /// A request header is defined more than once.
+    DuplicateHeader { name: String },
+    /// A captured value is never used in the file.
+    UnusedCapture { name: String },
+    /// An assert or a body is checked against a response which has always an empty body.
+    AssertOnEmptyBody { status: u64 },
+    /// A request body looks like JSON, but is sent without a `Content-Type` header.
+    MissingContentType,
+}
+
+impl Warning {
+    /// Creates a new warning for the position `source_info`, of type `inner`.
+    pub fn new(source_info: SourceInfo, inner: WarningKind) -> Warning {
+        Warning { source_info, inner }
+    }
+}
+
+impl Error for Warning {
+    fn source_info(&self) -> SourceInfo {
+        self.source_info
+    }
+
+    fn description(&self) -> String {
+        match self.inner {
+            WarningKind::DuplicateHeader { .. } => "Duplicate header".to_string(),
+            WarningKind::UnusedCapture { .. } => "Unused capture".to_string(),
+            WarningKind::AssertOnEmptyBody { .. } => "Assert on empty body".to_string(),
+            WarningKind::MissingContentType => "Missing Content-Type".to_string(),
+        }
+    }
+
+    fn fixme(&self) -> String {
+        match &self.inner {
+            WarningKind::DuplicateHeader { name } => {
+                format!("header <{name}> is already defined for this request")
+            }
+            WarningKind::UnusedCapture { name } => {
+                format!("capture <{name}> is never used")
+            }
+            WarningKind::AssertOnEmptyBody { status } => {
+                format!("a response with status {status} has no body")
+            }
+            WarningKind::MissingContentType => {
+                "JSON body is sent without a Content-Type header".to_string()
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::*;
+use regex::Regex;
+
+use crate::linter::{Warning, WarningKind};
+
+/// Returns the lint warnings for the `hurl_file`, parsed from `content`.
+pub fn check_hurl_file(hurl_file: &HurlFile, content: &str) -> Vec<Warning> {
+    let mut warnings = vec![];
+    for entry in &hurl_file.entries {
+        warnings.append(&mut check_request(&entry.request));
+        if let Some(response) = &entry.response {
+            warnings.append(&mut check_response(response));
+            warnings.append(&mut check_captures(response, content));
+        }
+    }
+    warnings
+}
+
+fn check_request(request: &Request) -> Vec<Warning> {
+    let mut warnings = vec![];
+    let mut names = vec![];
+    for header in &request.headers {
+        let name = header.key.to_string().to_lowercase();
+        if names.contains(&name) {
+            let name = header.key.to_string();
+            warnings.push(Warning::new(
+                header.key.source_info,
+                WarningKind::DuplicateHeader { name },
+            ));
+        } else {
+            names.push(name);
+        }
+    }
+
+    if let Some(body) = &request.body {
+        if is_json_like(&body.value) && !names.iter().any(|n| n == "content-type") {
+            warnings.push(Warning::new(
+                body_source_info(body),
+                WarningKind::MissingContentType,
+            ));
+        }
+    }
+    warnings
+}
+
+/// Returns true if `bytes` looks like JSON but is not a JSON body (for which Hurl already sets
+/// an implicit `Content-Type`).
+fn is_json_like(bytes: &Bytes) -> bool {
+    match bytes {
+        Bytes::File(File { filename, .. }) => filename.to_string().ends_with(".json"),
+        Bytes::OnelineString(value)
+        | Bytes::MultilineString(MultilineString::Text(Text { value, .. })) => {
+            let value = value.to_string();
+            let value = value.trim_start();
+            value.starts_with('{') || value.starts_with('[')
+        }
+        _ => false,
+    }
+}
+
+fn check_response(response: &Response) -> Vec<Warning> {
+    let mut warnings = vec![];
+    // A 204 No Content response has always an empty body.
+    let StatusValue::Specific(status @ 204) = response.status.value else {
+        return warnings;
+    };
+    if let Some(body) = &response.body {
+        warnings.push(Warning::new(
+            body_source_info(body),
+            WarningKind::AssertOnEmptyBody { status },
+        ));
+    }
+    for assert in response.asserts().iter().chain(&response.soft_asserts()) {
+        if matches!(
+            assert.query.value,
+            QueryValue::Jsonpath { .. } | QueryValue::Jsonpointer { .. } | QueryValue::Xpath { .. }
+        ) {
+            warnings.push(Warning::new(
+                assert.query.source_info,
+                WarningKind::AssertOnEmptyBody { status },
+            ));
+        }
+    }
+    warnings
+}
+
+fn check_captures(response: &Response, content: &str) -> Vec<Warning> {
+    response
+        .captures()
+        .iter()
+        .filter(|capture| !is_variable_used(&capture.name.to_string(), content))
+        .map(|capture| {
+            let name = capture.name.to_string();
+            Warning::new(
+                capture.name.source_info,
+                WarningKind::UnusedCapture { name },
+            )
+        })
+        .collect()
+}
+
+/// Returns true if the variable `name` is used in `content`, either in a template or in a
+/// variable query.
+fn is_variable_used(name: &str, content: &str) -> bool {
+    let name = regex::escape(name);
+    let re = Regex::new(&format!(r#"\{{\{{\s*{name}\s*\}}\}}|variable\s+"{name}""#)).unwrap();
+    re.is_match(content)
+}
+
+fn body_source_info(body: &Body) -> SourceInfo {
+    let pos = body.space0.source_info.end;
+    SourceInfo::new(pos, pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser::parse_hurl_file;
+
+    use super::*;
+
+    fn check(content: &str) -> Vec<Warning> {
+        let hurl_file = parse_hurl_file(content).unwrap();
+        check_hurl_file(&hurl_file, content)
+    }
+
+    fn pos(line: usize, column: usize) -> Pos {
+        Pos::new(line, column)
+    }
+
+    #[test]
+    fn test_clean_file() {
+        let content = r#"POST http://localhost:8000/login
+Content-Type: application/json
+Accept: application/json
+```
+{"user": "bob"}
+```
+HTTP 200
+[Captures]
+token: jsonpath "$.token"
+csrf: header "X-Csrf"
+
+PUT http://localhost:8000/profile
+Authorization: Bearer {{ token }}
+{
+    "name": "Bob"
+}
+HTTP 204
+[Asserts]
+header "X-Csrf" == "{{csrf}}"
+body == ""
+"#;
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_header() {
+        let content = "GET http://localhost:8000\nAccept: text/html\naccept: */*\n";
+        let warnings = check(content);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].inner,
+            WarningKind::DuplicateHeader {
+                name: "accept".to_string()
+            }
+        );
+        assert_eq!(warnings[0].source_info.start, pos(3, 1));
+    }
+
+    #[test]
+    fn test_unused_capture() {
+        let content = r#"GET http://localhost:8000/a
+HTTP 200
+[Captures]
+id: jsonpath "$.id"
+name: jsonpath "$.name"
+count: jsonpath "$.count"
+
+GET http://localhost:8000/b/{{id}}
+HTTP 200
+[Asserts]
+variable "count" == 2
+"#;
+        let warnings = check(content);
+        assert_eq!(
+            warnings,
+            vec![Warning::new(
+                SourceInfo::new(pos(5, 1), pos(5, 5)),
+                WarningKind::UnusedCapture {
+                    name: "name".to_string()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn test_assert_on_empty_body() {
+        let content = r#"DELETE http://localhost:8000/a
+HTTP 204
+[Asserts]
+status == 204
+jsonpath "$.id" == 1
+xpath "string(//h1)" == "Deleted"
+`Deleted`
+"#;
+        let warnings = check(content);
+        let kind = WarningKind::AssertOnEmptyBody { status: 204 };
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.source_info.start, &w.inner))
+                .collect::<Vec<_>>(),
+            vec![(pos(7, 1), &kind), (pos(5, 1), &kind), (pos(6, 1), &kind)]
+        );
+
+        // Same asserts on a 200 response are fine.
+        let content = content.replace("HTTP 204", "HTTP 200");
+        assert!(check(&content).is_empty());
+    }
+
+    #[test]
+    fn test_missing_content_type() {
+        let content = r#"POST http://localhost:8000/a
+```
+{"id": 1}
+```
+
+POST http://localhost:8000/b
+file,data.json;
+
+POST http://localhost:8000/c
+content-type: application/json
+file,data.json;
+
+POST http://localhost:8000/d
+{"id": 1}
+
+POST http://localhost:8000/e
+`Hello`
+"#;
+        let warnings = check(content);
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (w.source_info.start, &w.inner))
+                .collect::<Vec<_>>(),
+            vec![
+                (pos(2, 1), &WarningKind::MissingContentType),
+                (pos(7, 1), &WarningKind::MissingContentType),
+            ]
+        );
+    }
+}
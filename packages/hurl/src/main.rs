@@ -19,18 +19,19 @@ mod cli;
 mod run;
 
 use std::io::prelude::*;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 use std::{env, process};
 
 use crate::cli::CliError;
 use colored::control;
+use hurl::output;
 use hurl::report::{html, junit, tap};
 use hurl::runner;
 use hurl::runner::{HurlResult, Input};
 use hurl::util::logger::BaseLogger;
 
-use crate::cli::options::CliOptionsError;
+use crate::cli::options::{CliOptionsError, ReportSort};
 
 const EXIT_OK: i32 = 0;
 const EXIT_ERROR_COMMANDLINE: i32 = 1;
@@ -53,7 +54,7 @@ struct HurlRun {
 fn main() {
     init_colored();
 
-    let opts = match cli::options::parse() {
+    let mut opts = match cli::options::parse() {
         Ok(v) => v,
         Err(e) => match e {
             CliOptionsError::Info(_) => {
@@ -76,35 +77,81 @@ fn main() {
     let current_dir = current_dir.as_path();
     let start = Instant::now();
 
+    if opts.lint || opts.parse_only {
+        let result = run::check(&opts.input_files, &opts);
+        unwrap_or_exit_cli(result, &base_logger);
+        process::exit(EXIT_OK);
+    }
+
+    if opts.list_entries {
+        let result = run::list_entries(&opts.input_files, &opts);
+        unwrap_or_exit_cli(result, &base_logger);
+        process::exit(EXIT_OK);
+    }
+
+    if let Some(addr) = &opts.capture_proxy {
+        base_logger.info(&format!("Capturing HTTP traffic on {addr}"));
+        let result = run::capture(addr, &opts, &base_logger);
+        unwrap_or_exit_cli(result, &base_logger);
+        process::exit(EXIT_OK);
+    }
+
+    if let Some(query) = &opts.query {
+        let result = run::query(query, &opts);
+        unwrap_or_exit_cli(result, &base_logger);
+        process::exit(EXIT_OK);
+    }
+
+    if let Some(setup) = &opts.setup {
+        let result = run::run_setup(setup, current_dir, &opts);
+        opts.variables = unwrap_or_exit_cli(result, &base_logger);
+    }
+
+    if let Some(percent) = opts.sample {
+        base_logger.debug(&format!(
+            "Running a {percent}% sample of the input files ({} files, seed {})",
+            opts.input_files.len(),
+            opts.seed
+        ));
+    }
+
     let runs = if opts.parallel {
         run::run_par(&opts.input_files, current_dir, &opts)
     } else {
         run::run_seq(&opts.input_files, current_dir, &opts)
     };
-    let runs = match runs {
-        Ok(r) => r,
-        Err(CliError::IO(msg)) => exit_with_error(&msg, EXIT_ERROR_PARSING, &base_logger),
-        // In case of parsing error, there is no error because the display of parsing error has been
-        // done in the execution of the Hurl files, inside the crates (and not in the main).
-        Err(CliError::Parsing) => exit_with_error("", EXIT_ERROR_PARSING, &base_logger),
-        Err(CliError::Runtime(msg)) => exit_with_error(&msg, EXIT_ERROR_RUNTIME, &base_logger),
+
+    // The teardown file is always run after the Hurl files, even if they have failed.
+    let teardown_code = match &opts.teardown {
+        Some(teardown) => {
+            let main_runs = runs.as_deref().unwrap_or_default();
+            let result = run::run_teardown(teardown, current_dir, &opts, main_runs);
+            teardown_exit_code(teardown, &result, opts.teardown_strict, &base_logger)
+        }
+        None => EXIT_OK,
     };
+    let runs = unwrap_or_exit_cli(runs, &base_logger);
+
+    // Reports can list the files in another order than the execution one.
+    let report_runs = sort_report_runs(&runs, opts.report_sort);
 
-    if let Some(filename) = opts.junit_file {
+    if let Some(filename) = &opts.junit_file {
+        let filename = report_filename(filename, opts.compress_reports);
         base_logger.debug(&format!("Writing JUnit report to {}", filename.display()));
-        let result = create_junit_report(&runs, &filename);
+        let result = create_junit_report(&report_runs, &filename);
         unwrap_or_exit(result, EXIT_ERROR_UNDEFINED, &base_logger);
     }
 
-    if let Some(filename) = opts.tap_file {
+    if let Some(filename) = &opts.tap_file {
+        let filename = report_filename(filename, opts.compress_reports);
         base_logger.debug(&format!("Writing TAP report to {}", filename.display()));
-        let result = create_tap_report(&runs, &filename);
+        let result = create_tap_report(&report_runs, &filename);
         unwrap_or_exit(result, EXIT_ERROR_UNDEFINED, &base_logger);
     }
 
     if let Some(dir) = opts.html_dir {
         base_logger.debug(&format!("Writing HTML report to {}", dir.display()));
-        let result = create_html_report(&runs, &dir);
+        let result = create_html_report(&report_runs, &dir);
         unwrap_or_exit(result, EXIT_ERROR_UNDEFINED, &base_logger);
     }
 
@@ -120,7 +167,25 @@ fn main() {
         base_logger.info(summary.as_str());
     }
 
-    process::exit(exit_code(&runs));
+    if opts.print_timings {
+        for run in runs.iter() {
+            let table = output::get_timings_table(&run.hurl_result);
+            base_logger.info(&format!("{}:\n{table}", run.filename));
+        }
+    }
+
+    if opts.print_request_duration_warnings {
+        for warning in get_slow_entries_warnings(&runs, opts.slow_warn_ms) {
+            base_logger.warning(&warning);
+        }
+    }
+
+    // A teardown failure makes the whole run fail only with `--teardown-strict`.
+    let code = exit_code(&runs);
+    if code == EXIT_OK && opts.teardown_strict {
+        process::exit(teardown_code);
+    }
+    process::exit(code);
 }
 
 #[cfg(target_family = "unix")]
@@ -145,6 +210,18 @@ where
     }
 }
 
+/// Unwraps a `result` of a Hurl command or exit with the exit code of its [`CliError`].
+fn unwrap_or_exit_cli<T>(result: Result<T, CliError>, logger: &BaseLogger) -> T {
+    match result {
+        Ok(v) => v,
+        Err(CliError::IO(msg)) => exit_with_error(&msg, EXIT_ERROR_PARSING, logger),
+        // In case of parsing error, there is no error because the display of parsing error has been
+        // done in the execution of the Hurl files, inside the crates (and not in the main).
+        Err(CliError::Parsing) => exit_with_error("", EXIT_ERROR_PARSING, logger),
+        Err(CliError::Runtime(msg)) => exit_with_error(&msg, EXIT_ERROR_RUNTIME, logger),
+    }
+}
+
 /// Prints an error message and exits the current process with an exit code.
 fn exit_with_error(message: &str, code: i32, logger: &BaseLogger) -> ! {
     if !message.is_empty() {
@@ -153,8 +230,30 @@ fn exit_with_error(message: &str, code: i32, logger: &BaseLogger) -> ! {
     process::exit(code);
 }
 
+/// Returns the report `filename`, with a `.gz` extension appended if the report is `compress`ed.
+fn report_filename(filename: &Path, compress: bool) -> PathBuf {
+    if !compress {
+        return filename.to_path_buf();
+    }
+    let mut filename = filename.as_os_str().to_os_string();
+    filename.push(".gz");
+    PathBuf::from(filename)
+}
+
+/// Returns the `runs` in the order of the reports, given a `sort` order.
+///
+/// Entries are not named, so runs are sorted by filename, entries being kept in execution order.
+/// The JSON output is not sorted, as it is written while the files are run.
+fn sort_report_runs(runs: &[HurlRun], sort: ReportSort) -> Vec<&HurlRun> {
+    let mut runs = runs.iter().collect::<Vec<_>>();
+    if sort == ReportSort::Name {
+        runs.sort_by_key(|r| r.filename.to_string());
+    }
+    runs
+}
+
 /// Create a JUnit report for this run.
-fn create_junit_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError> {
+fn create_junit_report(runs: &[&HurlRun], filename: &Path) -> Result<(), CliError> {
     let testcases = runs
         .iter()
         .map(|r| junit::Testcase::from(&r.hurl_result, &r.content, &r.filename))
@@ -164,7 +263,7 @@ fn create_junit_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError
 }
 
 /// Create a TAP report for this run.
-fn create_tap_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError> {
+fn create_tap_report(runs: &[&HurlRun], filename: &Path) -> Result<(), CliError> {
     let testcases = runs
         .iter()
         .map(|r| tap::Testcase::from(&r.hurl_result, &r.filename))
@@ -174,7 +273,7 @@ fn create_tap_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError>
 }
 
 /// Create an HTML report for this run.
-fn create_html_report(runs: &[HurlRun], dir_path: &Path) -> Result<(), CliError> {
+fn create_html_report(runs: &[&HurlRun], dir_path: &Path) -> Result<(), CliError> {
     // We ensure that the containing folder exists.
     std::fs::create_dir_all(dir_path.join("store")).unwrap();
 
@@ -210,6 +309,37 @@ fn exit_code(runs: &[HurlRun]) -> i32 {
     }
 }
 
+/// Returns the exit code of the teardown file `teardown` run `result`, logging its failure if any.
+///
+/// With `strict`, a teardown failure is logged as an error, otherwise as a warning.
+fn teardown_exit_code(
+    teardown: &Input,
+    result: &Result<HurlRun, CliError>,
+    strict: bool,
+    logger: &BaseLogger,
+) -> i32 {
+    let (code, message) = match result {
+        Ok(run) => (exit_code(std::slice::from_ref(run)), String::new()),
+        Err(CliError::IO(msg)) => (EXIT_ERROR_PARSING, msg.clone()),
+        Err(CliError::Parsing) => (EXIT_ERROR_PARSING, String::new()),
+        Err(CliError::Runtime(msg)) => (EXIT_ERROR_RUNTIME, msg.clone()),
+    };
+    if code == EXIT_OK {
+        return code;
+    }
+    let message = if message.is_empty() {
+        format!("Teardown file {teardown} has failed")
+    } else {
+        format!("Teardown file {teardown} has failed: {message}")
+    };
+    if strict {
+        logger.error(&message);
+    } else {
+        logger.warning(&message);
+    }
+    code
+}
+
 fn create_cookies_file(runs: &[HurlRun], filename: &Path) -> Result<(), CliError> {
     let mut file = match std::fs::File::create(filename) {
         Err(why) => {
@@ -262,6 +392,26 @@ fn get_summary(runs: &[HurlRun], duration: u128) -> String {
     )
 }
 
+/// Returns a warning message for each entry of these `runs` slower than `threshold` milliseconds.
+fn get_slow_entries_warnings(runs: &[HurlRun], threshold: u64) -> Vec<String> {
+    let mut warnings = vec![];
+    for run in runs.iter() {
+        for entry in run.hurl_result.entries.iter() {
+            if entry.time_in_ms > threshold as u128 {
+                warnings.push(format!(
+                    "{}:{}:{} entry {} took {} ms (more than {threshold} ms)",
+                    run.filename,
+                    entry.source_info.start.line,
+                    entry.source_info.start.column,
+                    entry.entry_index,
+                    entry.time_in_ms
+                ));
+            }
+        }
+    }
+    warnings
+}
+
 #[cfg(test)]
 pub mod tests {
     use hurl::runner::EntryResult;
@@ -281,6 +431,7 @@ pub mod tests {
                 errors: vec![],
                 time_in_ms: 0,
                 compressed: false,
+                curl_cmd: String::new(),
             };
             HurlRun {
                 content: String::new(),
@@ -319,4 +470,71 @@ pub mod tests {
             Duration:        200 ms\n"
         );
     }
+
+    #[test]
+    fn create_slow_entries_warnings() {
+        let new_entry = |entry_index: usize, line: usize, time_in_ms: u128| EntryResult {
+            entry_index,
+            source_info: SourceInfo::new(Pos::new(line, 1), Pos::new(line, 4)),
+            calls: vec![],
+            captures: vec![],
+            asserts: vec![],
+            errors: vec![],
+            time_in_ms,
+            compressed: false,
+            curl_cmd: String::new(),
+        };
+        let runs = vec![HurlRun {
+            content: String::new(),
+            filename: Input::new("foo.hurl"),
+            hurl_result: HurlResult {
+                entries: vec![
+                    new_entry(1, 1, 20),
+                    new_entry(2, 4, 1500),
+                    new_entry(3, 8, 1000),
+                ],
+                time_in_ms: 0,
+                success: true,
+                cookies: vec![],
+                timestamp: 1,
+            },
+        }];
+        assert_eq!(
+            get_slow_entries_warnings(&runs, 1000),
+            vec!["foo.hurl:4:1 entry 2 took 1500 ms (more than 1000 ms)"]
+        );
+        assert!(get_slow_entries_warnings(&runs, 2000).is_empty());
+    }
+
+    #[test]
+    fn sort_runs_for_report() {
+        fn new_run(filename: &str) -> HurlRun {
+            HurlRun {
+                content: String::new(),
+                filename: Input::new(filename),
+                hurl_result: HurlResult {
+                    entries: vec![],
+                    time_in_ms: 0,
+                    success: true,
+                    cookies: vec![],
+                    timestamp: 1,
+                },
+            }
+        }
+
+        let runs = vec![new_run("c.hurl"), new_run("a.hurl"), new_run("b.hurl")];
+        let filenames = |runs: Vec<&HurlRun>| {
+            runs.iter()
+                .map(|r| r.filename.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            filenames(sort_report_runs(&runs, ReportSort::Index)),
+            vec!["c.hurl", "a.hurl", "b.hurl"]
+        );
+        assert_eq!(
+            filenames(sort_report_runs(&runs, ReportSort::Name)),
+            vec!["a.hurl", "b.hurl", "c.hurl"]
+        );
+    }
 }
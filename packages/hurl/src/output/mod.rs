@@ -23,10 +23,13 @@
 //! [`self::json::write_json`]
 //! - raw: the last response of a run is serialized to a file. The body can be automatically uncompress
 //! or written as it [`self::raw::write_last_body`]
+//! - timings: a table of the duration of each entry of a run [`self::timings::get_timings_table`]
 mod error;
 mod json;
 mod raw;
+mod timings;
 
 pub use self::error::Error;
 pub use self::json::write_json;
-pub use self::raw::write_last_body;
+pub use self::raw::{write_entries_bodies, write_last_body, BodyFormat, OutputOptions};
+pub use self::timings::get_timings_table;
@@ -15,21 +15,56 @@
  * limitations under the License.
  *
  */
+use std::path::Path;
+
+use bytes::Bytes;
+use encoding::DecoderTrap;
 use hurl_core::ast::{Pos, SourceInfo};
 
+use crate::http::{HttpError, Response};
 use crate::output::Error;
 use crate::runner;
 use crate::runner::{HurlResult, Output};
+use crate::util::hexdump::hexdump;
 use crate::util::term::Stdout;
 
-/// Writes the `hurl_result` last response to the file `filename_out`.
+/// The format used to write a response body.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BodyFormat {
+    /// The body bytes are written as is.
+    #[default]
+    Auto,
+    /// The body is decoded as text, given the response charset, and written as UTF-8.
+    Text,
+    /// The body is written as a hexdump.
+    Binary,
+    /// The body is decoded as text, and written as pretty-printed JSON.
+    Json,
+}
+
+/// Options used to write the last response of a run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OutputOptions {
+    /// Writes a newline after the body, only when the output is the standard output.
+    pub append_newline: bool,
+    /// The format in which the body is written.
+    pub body_format: BodyFormat,
+    /// Uses color to write the request and response headers.
+    pub color: bool,
+    /// Writes the HTTP response status line and headers before the body.
+    pub include_headers: bool,
+    /// Writes the HTTP request line and headers before the response headers.
+    pub include_request: bool,
+    /// Sorts the response headers by name.
+    pub sort_headers: bool,
+}
+
+/// Writes the `hurl_result` last response to the file `filename_out`, given output `options`.
 ///
-/// If `filename_out` is `None`, standard output is used. If `include_headers` is true, the last
-/// HTTP response headers are written before the body response.
+/// If `filename_out` is `None`, standard output is used.
 pub fn write_last_body(
     hurl_result: &HurlResult,
-    include_headers: bool,
-    color: bool,
+    options: &OutputOptions,
     filename_out: Option<&Output>,
     stdout: &mut Stdout,
 ) -> Result<(), Error> {
@@ -41,48 +76,187 @@ pub fn write_last_body(
         return Ok(());
     };
     let response = &call.response;
-    let mut output = vec![];
+    let body = get_body(response, last_entry.compressed, options.body_format)?;
 
     // If include options is set, we output the HTTP response headers
-    // with status and version (to mimic curl outputs)
-    if include_headers {
-        let mut text = response.get_status_line_headers(color);
+    // with status and version (to mimic curl outputs), optionally preceded by the HTTP request
+    // line and headers.
+    let output = if options.include_headers || options.include_request {
+        let mut text = String::new();
+        if options.include_request {
+            text.push_str(&call.request.get_request_line_headers(options.color));
+            text.push('\n');
+        }
+        text.push_str(&response.get_status_line_headers(options.color, options.sort_headers));
         text.push('\n');
-        output.append(&mut text.into_bytes());
-    }
-    if last_entry.compressed {
-        let mut bytes = match response.uncompress_body() {
-            Ok(b) => b,
-            Err(e) => {
-                // FIXME: we convert to a runner::Error to be able to use fixme!
-                // We may pass a [`SourceInfo`] as a parameter of this method to make
-                // a more accurate error
-                let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
-                let error = runner::Error::new(source_info, e.into(), false);
-                return Err(error.into());
-            }
+        let mut output = text.into_bytes();
+        output.extend_from_slice(&body);
+        output.into()
+    } else {
+        body
+    };
+    let out = filename_out.unwrap_or(&Output::Stdout);
+    let output = if options.append_newline && matches!(out, Output::Stdout) {
+        let mut output = output.to_vec();
+        output.push(b'\n');
+        output.into()
+    } else {
+        output
+    };
+    out.write(&output, stdout, None)?;
+    Ok(())
+}
+
+/// Writes the last response body of each entry of `hurl_result` to a numbered file in `dir`.
+///
+/// Files are named after the entry index, shifted by `offset`, zero-padded to keep them sorted
+/// in entry order (`0001.bin`, `0002.bin` etc...). When an entry has been retried, only its last
+/// run is kept. Bodies are written in the format `body_format`. Returns the offset to use for the
+/// entries of a next run.
+pub fn write_entries_bodies(
+    hurl_result: &HurlResult,
+    body_format: BodyFormat,
+    dir: &Path,
+    offset: usize,
+    stdout: &mut Stdout,
+) -> Result<usize, Error> {
+    let mut next_offset = offset;
+    for entry in hurl_result.entries.iter() {
+        let Some(call) = entry.calls.last() else {
+            continue;
         };
-        output.append(&mut bytes);
+        let body = get_body(&call.response, entry.compressed, body_format)?;
+        let index = offset + entry.entry_index;
+        let output = Output::File(dir.join(format!("{index:04}.bin")));
+        output.write(&body, stdout, None)?;
+        next_offset = next_offset.max(index);
+    }
+    Ok(next_offset)
+}
+
+/// Returns the body of this `response`, uncompressed if `compressed` is true, in the format `body_format`.
+fn get_body(
+    response: &Response,
+    compressed: bool,
+    body_format: BodyFormat,
+) -> Result<Bytes, Error> {
+    // The body is a cheap shared reference to the response body, we don't copy it.
+    let body = if compressed {
+        response.uncompress_body().map_err(http_error)?
     } else {
-        let bytes = &response.body;
-        output.extend(bytes);
+        response.body.clone()
+    };
+    format_body(response, body, body_format)
+}
+
+/// Formats the `body` bytes of the `response` given a `body_format`.
+fn format_body(response: &Response, body: Bytes, body_format: BodyFormat) -> Result<Bytes, Error> {
+    match body_format {
+        BodyFormat::Auto => Ok(body),
+        BodyFormat::Text => Ok(decode_text(response, &body)?.into()),
+        BodyFormat::Binary => Ok(hexdump(&body).into()),
+        BodyFormat::Json => {
+            let text = decode_text(response, &body)?;
+            if serde_json::from_str::<serde::de::IgnoredAny>(&text).is_err() {
+                return Err(Error::new("response body is not a valid JSON"));
+            }
+            Ok(pretty_json(&text).into())
+        }
     }
-    match filename_out {
-        Some(out) => out.write(&output, stdout, None)?,
-        None => Output::Stdout.write(&output, stdout, None)?,
+}
+
+/// Decodes `body` bytes to text, using the charset of the `response`.
+fn decode_text(response: &Response, body: &[u8]) -> Result<String, Error> {
+    let encoding = response.headers.character_encoding().map_err(http_error)?;
+    encoding.decode(body, DecoderTrap::Replace).map_err(|_| {
+        http_error(HttpError::InvalidDecoding {
+            charset: encoding.name().to_string(),
+        })
+    })
+}
+
+/// Converts a HTTP error to an output error.
+fn http_error(e: HttpError) -> Error {
+    // FIXME: we convert to a runner::Error to be able to use fixme!
+    // We may pass a [`SourceInfo`] as a parameter of this method to make
+    // a more accurate error
+    let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+    let error = runner::Error::new(source_info, e.into(), false);
+    error.into()
+}
+
+/// Pretty prints a valid JSON `text`, with an indentation of two spaces.
+///
+/// Contrary to a serialization, keys order and numbers are kept as is.
+fn pretty_json(text: &str) -> String {
+    let mut json = String::new();
+    let mut indent = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = text.chars().peekable();
+    let new_line = |json: &mut String, indent: usize| {
+        json.push('\n');
+        json.push_str(&"  ".repeat(indent));
+    };
+    while let Some(c) = chars.next() {
+        if in_string {
+            json.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => {
+                in_string = true;
+                json.push(c);
+            }
+            '{' | '[' => {
+                json.push(c);
+                // Empty objects and arrays are kept on one line.
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                if let Some(&next) = chars.peek() {
+                    if next == '}' || next == ']' {
+                        json.push(next);
+                        chars.next();
+                        continue;
+                    }
+                }
+                indent += 1;
+                new_line(&mut json, indent);
+            }
+            '}' | ']' => {
+                indent = indent.saturating_sub(1);
+                new_line(&mut json, indent);
+                json.push(c);
+            }
+            ',' => {
+                json.push(c);
+                new_line(&mut json, indent);
+            }
+            ':' => json.push_str(": "),
+            c if c.is_whitespace() => {}
+            c => json.push(c),
+        }
     }
-    Ok(())
+    json
 }
 
 #[cfg(test)]
-mod tests {
+pub mod tests {
+    use bytes::Bytes;
+
     use crate::http::{Call, Header, HeaderVec, HttpVersion, Request, Response};
-    use crate::output::write_last_body;
+    use crate::output::{write_entries_bodies, write_last_body, BodyFormat, Error, OutputOptions};
     use crate::runner::{EntryResult, HurlResult, Output};
     use crate::util::term::{Stdout, WriteMode};
     use hurl_core::ast::{Pos, SourceInfo};
 
-    fn hurl_result() -> HurlResult {
+    pub fn hurl_result() -> HurlResult {
         let mut headers = HeaderVec::new();
         headers.push(Header::new("x-foo", "xxx"));
         headers.push(Header::new("x-bar", "yyy0"));
@@ -110,6 +284,7 @@ mod tests {
                     errors: vec![],
                     time_in_ms: 0,
                     compressed: false,
+                    curl_cmd: String::new(),
                 },
                 EntryResult {
                     entry_index: 2,
@@ -129,6 +304,7 @@ mod tests {
                     errors: vec![],
                     time_in_ms: 0,
                     compressed: false,
+                    curl_cmd: String::new(),
                 },
                 EntryResult {
                     entry_index: 3,
@@ -144,10 +320,12 @@ mod tests {
                             version: HttpVersion::Http3,
                             status: 204,
                             headers,
-                            body: b"{\"say\": \"Hello World!\"}".into(),
+                            body: Bytes::from_static(b"{\"say\": \"Hello World!\"}"),
                             duration: Default::default(),
                             url: "".to_string(),
                             certificate: None,
+                            bytes_sent: 0,
+                            bytes_received: 0,
                         },
                         timings: Default::default(),
                     }],
@@ -156,6 +334,7 @@ mod tests {
                     errors: vec![],
                     time_in_ms: 0,
                     compressed: false,
+                    curl_cmd: String::new(),
                 },
             ],
             time_in_ms: 100,
@@ -168,12 +347,14 @@ mod tests {
     #[test]
     fn write_last_body_with_headers() {
         let result = hurl_result();
-        let include_header = true;
-        let color = false;
+        let options = OutputOptions {
+            include_headers: true,
+            ..OutputOptions::default()
+        };
         let output = Some(Output::Stdout);
         let mut stdout = Stdout::new(WriteMode::Buffered);
 
-        write_last_body(&result, include_header, color, output.as_ref(), &mut stdout).unwrap();
+        write_last_body(&result, &options, output.as_ref(), &mut stdout).unwrap();
         let stdout = String::from_utf8(stdout.buffer().to_vec()).unwrap();
         assert_eq!(
             stdout,
@@ -187,4 +368,209 @@ mod tests {
              {\"say\": \"Hello World!\"}"
         );
     }
+
+    #[test]
+    fn write_last_body_with_sorted_headers() {
+        let result = hurl_result();
+        let options = OutputOptions {
+            include_headers: true,
+            sort_headers: true,
+            ..OutputOptions::default()
+        };
+        let output = Some(Output::Stdout);
+        let mut stdout = Stdout::new(WriteMode::Buffered);
+
+        write_last_body(&result, &options, output.as_ref(), &mut stdout).unwrap();
+        let stdout = String::from_utf8(stdout.buffer().to_vec()).unwrap();
+        assert_eq!(
+            stdout,
+            "HTTP/3 204\n\
+             x-bar: yyy0\n\
+             x-bar: yyy1\n\
+             x-bar: yyy2\n\
+             x-baz: zzz\n\
+             x-foo: xxx\n\
+             \n\
+             {\"say\": \"Hello World!\"}"
+        );
+    }
+
+    #[test]
+    fn write_last_body_without_headers() {
+        let result = hurl_result();
+        let options = OutputOptions {
+            ..OutputOptions::default()
+        };
+        let output = Some(Output::Stdout);
+        let mut stdout = Stdout::new(WriteMode::Buffered);
+
+        write_last_body(&result, &options, output.as_ref(), &mut stdout).unwrap();
+        assert_eq!(stdout.buffer(), b"{\"say\": \"Hello World!\"}");
+    }
+
+    #[test]
+    fn write_last_body_append_newline() {
+        let result = hurl_result();
+        let options = OutputOptions {
+            append_newline: true,
+            ..OutputOptions::default()
+        };
+        let output = Some(Output::Stdout);
+        let mut stdout = Stdout::new(WriteMode::Buffered);
+
+        write_last_body(&result, &options, output.as_ref(), &mut stdout).unwrap();
+        assert_eq!(stdout.buffer(), b"{\"say\": \"Hello World!\"}\n");
+
+        // No newline is added when writing to a file.
+        let file = std::env::temp_dir().join("hurl_write_last_body_append_newline.json");
+        let output = Some(Output::File(file.clone()));
+        let mut stdout = Stdout::new(WriteMode::Buffered);
+
+        write_last_body(&result, &options, output.as_ref(), &mut stdout).unwrap();
+        assert!(stdout.buffer().is_empty());
+        assert_eq!(
+            std::fs::read(&file).unwrap(),
+            b"{\"say\": \"Hello World!\"}"
+        );
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn write_entries_bodies_to_numbered_files() {
+        let mut result = hurl_result();
+        result.entries[0].calls[0].response.body = Bytes::from_static(b"first");
+        // The second entry is retried, only its last run is kept.
+        let mut retry = result.entries[1].clone();
+        result.entries[1].calls[0].response.body = Bytes::from_static(b"second (first try)");
+        retry.calls[0].response.body = Bytes::from_static(b"second");
+        result.entries.insert(2, retry);
+
+        let dir = std::env::temp_dir().join(format!("hurl-output-split-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut stdout = Stdout::new(WriteMode::Buffered);
+
+        let offset = write_entries_bodies(&result, BodyFormat::Auto, &dir, 0, &mut stdout).unwrap();
+        assert_eq!(offset, 3);
+        assert_eq!(std::fs::read(dir.join("0001.bin")).unwrap(), b"first");
+        assert_eq!(std::fs::read(dir.join("0002.bin")).unwrap(), b"second");
+        assert_eq!(
+            std::fs::read(dir.join("0003.bin")).unwrap(),
+            b"{\"say\": \"Hello World!\"}"
+        );
+
+        // Indices of a next run are shifted.
+        let offset =
+            write_entries_bodies(&result, BodyFormat::Auto, &dir, offset, &mut stdout).unwrap();
+        assert_eq!(offset, 6);
+        assert_eq!(std::fs::read(dir.join("0004.bin")).unwrap(), b"first");
+        assert!(stdout.buffer().is_empty());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn write_last_body_with_request() {
+        let mut result = hurl_result();
+        result.entries[2].calls[0]
+            .request
+            .headers
+            .push(Header::new("Accept", "*/*"));
+        let options = OutputOptions {
+            include_request: true,
+            ..OutputOptions::default()
+        };
+        let output = Some(Output::Stdout);
+        let mut stdout = Stdout::new(WriteMode::Buffered);
+
+        write_last_body(&result, &options, output.as_ref(), &mut stdout).unwrap();
+        let stdout = String::from_utf8(stdout.buffer().to_vec()).unwrap();
+        assert_eq!(
+            stdout,
+            "GET https://baz.com\n\
+             Accept: */*\n\
+             \n\
+             HTTP/3 204\n\
+             x-foo: xxx\n\
+             x-bar: yyy0\n\
+             x-bar: yyy1\n\
+             x-bar: yyy2\n\
+             x-baz: zzz\n\
+             \n\
+             {\"say\": \"Hello World!\"}"
+        );
+    }
+
+    fn write_body_format(result: &HurlResult, body_format: BodyFormat) -> Result<Vec<u8>, Error> {
+        let output = Some(Output::Stdout);
+        let mut stdout = Stdout::new(WriteMode::Buffered);
+        write_last_body(
+            result,
+            &OutputOptions {
+                body_format,
+                ..OutputOptions::default()
+            },
+            output.as_ref(),
+            &mut stdout,
+        )?;
+        Ok(stdout.buffer().to_vec())
+    }
+
+    #[test]
+    fn write_last_body_format_auto() {
+        let result = hurl_result();
+        assert_eq!(
+            write_body_format(&result, BodyFormat::Auto).unwrap(),
+            b"{\"say\": \"Hello World!\"}"
+        );
+    }
+
+    #[test]
+    fn write_last_body_format_text() {
+        let mut result = hurl_result();
+        let response = &mut result.entries[2].calls[0].response;
+        response.headers.push(Header::new(
+            "Content-Type",
+            "text/plain; charset=iso-8859-1",
+        ));
+        response.body = Bytes::from_static(b"caf\xe9");
+        assert_eq!(
+            write_body_format(&result, BodyFormat::Text).unwrap(),
+            "café".as_bytes()
+        );
+    }
+
+    #[test]
+    fn write_last_body_format_binary() {
+        let result = hurl_result();
+        let stdout =
+            String::from_utf8(write_body_format(&result, BodyFormat::Binary).unwrap()).unwrap();
+        assert_eq!(
+            stdout,
+            "00000000  7b 22 73 61 79 22 3a 20  22 48 65 6c 6c 6f 20 57  |{\"say\": \"Hello W|\n\
+             00000010  6f 72 6c 64 21 22 7d                              |orld!\"}|\n\
+             00000017\n"
+        );
+    }
+
+    #[test]
+    fn write_last_body_format_json() {
+        let result = hurl_result();
+        let stdout =
+            String::from_utf8(write_body_format(&result, BodyFormat::Json).unwrap()).unwrap();
+        assert_eq!(stdout, "{\n  \"say\": \"Hello World!\"\n}");
+
+        let mut result = hurl_result();
+        result.entries[2].calls[0].response.body =
+            Bytes::from_static(b"{\"b\":[1, {}, []], \"a\":\"x,{\\\"y\"}");
+        let stdout =
+            String::from_utf8(write_body_format(&result, BodyFormat::Json).unwrap()).unwrap();
+        assert_eq!(
+            stdout,
+            "{\n  \"b\": [\n    1,\n    {},\n    []\n  ],\n  \"a\": \"x,{\\\"y\"\n}"
+        );
+
+        let mut result = hurl_result();
+        result.entries[2].calls[0].response.body = Bytes::from_static(b"Hello");
+        assert!(write_body_format(&result, BodyFormat::Json).is_err());
+    }
 }
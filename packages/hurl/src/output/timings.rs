@@ -0,0 +1,89 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::runner::HurlResult;
+
+/// Returns a table of the timings of each entry of `hurl_result`.
+///
+/// Each row displays the entry index, the method, the URL and the status of the entry last call,
+/// and the entry duration in milliseconds. Columns are aligned, numbers being right-aligned.
+pub fn get_timings_table(hurl_result: &HurlResult) -> String {
+    let header = ["Entry", "Method", "URL", "Status", "Time (ms)"].map(String::from);
+    let mut rows = vec![header];
+    for entry in hurl_result.entries.iter() {
+        let (method, url, status) = match entry.calls.last() {
+            Some(call) => (
+                call.request.method.clone(),
+                call.request.url.clone(),
+                call.response.status.to_string(),
+            ),
+            None => ("-".to_string(), "-".to_string(), "-".to_string()),
+        };
+        rows.push([
+            entry.entry_index.to_string(),
+            method,
+            url,
+            status,
+            entry.time_in_ms.to_string(),
+        ]);
+    }
+
+    let mut widths = [0; 5];
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in rows.iter() {
+        let [index, method, url, status, time] = row;
+        let line = format!(
+            "{index:>w0$}  {method:<w1$}  {url:<w2$}  {status:>w3$}  {time:>w4$}",
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+        table.push_str(&line);
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::raw::tests::hurl_result;
+
+    #[test]
+    fn timings_table() {
+        let mut result = hurl_result();
+        result.entries[0].time_in_ms = 12;
+        result.entries[1].time_in_ms = 3;
+        result.entries[2].time_in_ms = 1024;
+
+        assert_eq!(
+            get_timings_table(&result),
+            "Entry  Method  URL              Status  Time (ms)\n    \
+                 1  GET     https://foo.com     200         12\n    \
+                 2  GET     https://bar.com     200          3\n    \
+                 3  GET     https://baz.com     204       1024\n"
+        );
+    }
+}
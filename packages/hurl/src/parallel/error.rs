@@ -17,6 +17,7 @@
  */
 
 /// Error triggered when running a [`crate::parallel::job::Job`].
+#[derive(Debug)]
 pub enum JobError {
     IO(String),
     Parsing,
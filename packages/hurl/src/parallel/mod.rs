@@ -21,4 +21,5 @@ pub mod job;
 mod message;
 mod progress;
 pub mod runner;
+pub mod stats;
 mod worker;
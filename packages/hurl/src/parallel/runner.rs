@@ -17,11 +17,14 @@
  */
 use std::sync::mpsc;
 use std::sync::mpsc::Receiver;
+use std::sync::Arc;
 
+use crate::http::HostLimiter;
 use crate::parallel::error::JobError;
 use crate::parallel::job::{Job, JobResult};
 use crate::parallel::message::WorkerMessage;
 use crate::parallel::progress::{Mode, ParProgress};
+use crate::parallel::stats::Stats;
 use crate::parallel::worker::{Worker, WorkerId};
 use crate::util::term::{Stderr, WriteMode};
 
@@ -45,6 +48,23 @@ pub struct ParallelRunner {
     rx: Receiver<WorkerMessage>,
     /// Progress reporter to display the advancement of the parallel runs.
     progress: ParProgress,
+    /// Stop launching new jobs after the first failed job.
+    fail_fast: bool,
+}
+
+/// Options of a [`ParallelRunner`].
+#[derive(Clone, Debug, Default)]
+pub struct ParallelRunnerOptions {
+    /// Run in "test" mode, reporting the success or failure of each file on standard error.
+    pub test: bool,
+    /// Display a progress bar designed for parallel run progression, in "test" mode.
+    pub progress_bar: bool,
+    /// Use color in standard error.
+    pub color: bool,
+    /// Limit of concurrent requests to the same host, across all the workers.
+    pub max_concurrent_per_host: Option<usize>,
+    /// Stop launching new jobs after the first failed job, running jobs being completed.
+    pub fail_fast: bool,
 }
 
 /// Represents a worker's state.
@@ -62,43 +82,42 @@ pub enum WorkerState {
 const MAX_RUNNING_DISPLAYED: usize = 8;
 
 impl ParallelRunner {
-    /// Creates a new parallel runner, with `worker_count` worker.
-    ///
-    /// If `test` mode is `true` the runner is run in "test" mode, reporting the success or failure
-    /// of each file on standard error. Additionally to the test mode, a `progress_bar` designed for
-    /// parallel run progression can be use.
-    ///
-    /// `color` determines if color if used in standard error.
-    pub fn new(workers_count: usize, test: bool, progress_bar: bool, color: bool) -> Self {
+    /// Creates a new parallel runner, with `worker_count` worker, configured with `options`.
+    pub fn new(workers_count: usize, options: &ParallelRunnerOptions) -> Self {
         // Create the channel to communicate from workers to the parallel runner (worker are running
         // on theirs own thread, while parallel runner is running in the main thread).
         let (tx, rx) = mpsc::channel();
 
-        // Create the workers:
+        // Create the workers, sharing the same host limiter:
+        let host_limiter = options
+            .max_concurrent_per_host
+            .map(|max| Arc::new(HostLimiter::new(max)));
         let workers = (0..workers_count)
             .map(|i| {
-                let worker = Worker::new(WorkerId::from(i), &tx);
+                let worker = Worker::new(WorkerId::from(i), &tx, host_limiter.clone());
                 let state = WorkerState::Idle;
                 (worker, state)
             })
             .collect::<Vec<_>>();
 
-        let mode = Mode::new(test, progress_bar);
-        let progress = ParProgress::new(MAX_RUNNING_DISPLAYED, mode, color);
+        let mode = Mode::new(options.test, options.progress_bar);
+        let progress = ParProgress::new(MAX_RUNNING_DISPLAYED, mode, options.color);
 
         ParallelRunner {
             workers,
             rx,
             progress,
+            fail_fast: options.fail_fast,
         }
     }
 
-    /// Runs a list of [`Job`] in parallel and returns the results.
+    /// Runs a list of [`Job`] in parallel and returns the results, with the [`Stats`] of all the
+    /// jobs.
     ///
     /// Results are returned ordered by the sequence number, and not their execution order. So, the
     /// order of the `jobs` is the same as the order of the `jobs` results, independently of the
     /// worker's count.
-    pub fn run(&mut self, jobs: &[Job]) -> Result<Vec<JobResult>, JobError> {
+    pub fn run(&mut self, jobs: &[Job]) -> Result<(Vec<JobResult>, Stats), JobError> {
         // The parallel runner runs on the main thread. It's responsible for displaying standard
         // output and standard error. Workers are buffering their output and error in memory, and
         // delegate the display to the runners.
@@ -107,6 +126,8 @@ impl ParallelRunner {
         // Create the jobs queue (last item is the first job to run):
         let jobs_count = jobs.len();
         let mut jobs = jobs.iter().rev().collect::<Vec<_>>();
+        // Number of jobs results to wait for, less than `jobs_count` if the queue is cancelled.
+        let mut expected_count = jobs_count;
 
         // Initiate the runner, fill our workers
         for (worker, _) in &self.workers {
@@ -117,6 +138,8 @@ impl ParallelRunner {
 
         // Start the message pump:
         let mut results = vec![];
+        // Stats are only updated here, on the main thread, when a job is completed.
+        let mut stats = Stats::new();
         for msg in self.rx.iter() {
             match msg {
                 // If we any error (either a [`WorkerMessage::IOError`] or a [`WorkerMessage::ParsingError`]
@@ -163,6 +186,13 @@ impl ParallelRunner {
 
                     self.progress.print_completed(&msg.result, &mut stderr);
 
+                    // With fail fast, a failed job cancels the jobs not yet launched.
+                    if self.fail_fast && !msg.result.hurl_result.success {
+                        expected_count -= jobs.len();
+                        jobs.clear();
+                    }
+
+                    stats.add(&msg.result.hurl_result);
                     results.push(msg.result);
 
                     // We run the next job to process:
@@ -181,7 +211,7 @@ impl ParallelRunner {
                             );
 
                             // If we have received all the job results, we can stop the run.
-                            if results.len() == jobs_count {
+                            if results.len() == expected_count {
                                 break;
                             }
                         }
@@ -193,6 +223,6 @@ impl ParallelRunner {
         // All jobs have been executed, we sort results by sequence number to get the same order
         // as the input jobs list.
         results.sort_unstable_by_key(|result| result.job.seq);
-        Ok(results)
+        Ok((results, stats))
     }
 }
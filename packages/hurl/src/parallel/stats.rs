@@ -0,0 +1,53 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::BTreeMap;
+
+use crate::runner::HurlResult;
+
+/// Cumulative statistics of the HTTP exchanges of a list of runs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    /// Total number of HTTP requests (each step of a redirection counts as a request).
+    pub requests: usize,
+    /// Total number of request body bytes sent.
+    pub bytes_sent: usize,
+    /// Total number of response body bytes received.
+    pub bytes_received: usize,
+    /// Total time of the runs in milliseconds (runs in parallel are summed).
+    pub time_in_ms: u128,
+    /// Number of responses for each status code.
+    pub status_counts: BTreeMap<u32, usize>,
+}
+
+impl Stats {
+    /// Creates empty statistics.
+    pub fn new() -> Self {
+        Stats::default()
+    }
+
+    /// Adds the HTTP exchanges of this `hurl_result` to these statistics.
+    pub fn add(&mut self, hurl_result: &HurlResult) {
+        for call in hurl_result.entries.iter().flat_map(|e| &e.calls) {
+            self.requests += 1;
+            self.bytes_sent += call.request.body.len();
+            self.bytes_received += call.response.body.len();
+            *self.status_counts.entry(call.response.status).or_insert(0) += 1;
+        }
+        self.time_in_ms += hurl_result.time_in_ms;
+    }
+}
@@ -17,10 +17,12 @@
  */
 use std::fmt;
 use std::sync::mpsc::Sender;
+use std::sync::Arc;
 use std::thread;
 
 use hurl_core::parser;
 
+use crate::http::{Client, HostLimiter};
 use crate::parallel::job::{Job, JobResult};
 use crate::parallel::message::{
     CompletedMsg, IOErrorMsg, ParsingErrorMsg, RunningMsg, WorkerMessage,
@@ -36,6 +38,8 @@ pub struct Worker {
     worker_id: WorkerId,
     /// The transmit end of the message, allowing to pass message to the parallel runner, on main thread.
     tx: Sender<WorkerMessage>,
+    /// The limiter of concurrent requests per host, shared by all the workers.
+    host_limiter: Option<Arc<HostLimiter>>,
 }
 
 /// Identifier of a worker.
@@ -55,11 +59,17 @@ impl fmt::Display for WorkerId {
 }
 
 impl Worker {
-    /// Creates a new worker, with id `worker_id` and sender `tx`.
-    pub fn new(worker_id: WorkerId, tx: &Sender<WorkerMessage>) -> Self {
+    /// Creates a new worker, with id `worker_id` and sender `tx`, optionally sharing a
+    /// `host_limiter` with other workers.
+    pub fn new(
+        worker_id: WorkerId,
+        tx: &Sender<WorkerMessage>,
+        host_limiter: Option<Arc<HostLimiter>>,
+    ) -> Self {
         Worker {
             worker_id,
             tx: tx.clone(),
+            host_limiter,
         }
     }
 
@@ -71,6 +81,7 @@ impl Worker {
         let tx = self.tx.clone();
         let worker_id = self.worker_id;
         let job = job.clone();
+        let host_limiter = self.host_limiter.clone();
 
         thread::spawn(move || {
             // In parallel execution, standard output and standard error messages are buffered
@@ -95,19 +106,26 @@ impl Worker {
             };
 
             // Try to parse the content
-            let hurl_file = parser::parse_hurl_file(&content);
+            let hurl_file = parser::parse_hurl_file_with_recovery(&content);
             let hurl_file = match hurl_file {
                 Ok(h) => h,
-                Err(e) => {
-                    logger.error_parsing_rich(&content, &e);
+                Err(errors) => {
+                    for e in &errors {
+                        logger.error_parsing_rich(&content, e);
+                    }
                     let msg = ParsingErrorMsg::new(worker_id, &job, &logger.stderr);
                     return tx.send(WorkerMessage::ParsingError(msg));
                 }
             };
 
             // Now, we have a syntactically correct HurlFile instance, we can run it.
+            let mut http_client = match host_limiter {
+                Some(host_limiter) => Client::with_host_limiter(host_limiter),
+                None => Client::new(),
+            };
             let result = runner::run_entries(
                 &hurl_file.entries,
+                &mut http_client,
                 &content,
                 &job.runner_options,
                 &job.variables,
@@ -0,0 +1,217 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::report::html::timeline::nice::NiceScale;
+use crate::report::html::timeline::svg;
+use crate::report::html::timeline::svg::Attribute::{
+    Class, Fill, FontFamily, FontSize, Height, Stroke, ViewBox, Width,
+};
+use crate::report::html::timeline::svg::Element;
+use crate::report::html::timeline::unit::{Pixel, Px};
+use crate::report::html::Testcase;
+use crate::runner::EntryResult;
+
+/// Maximum number of buckets of the histogram.
+const MAX_BUCKETS: usize = 10;
+
+/// A bucket of the entries duration histogram: `count` entries have a duration in
+/// milliseconds in the [`start`, `end`) interval.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Bucket {
+    start: u128,
+    end: u128,
+    count: usize,
+}
+
+impl Testcase {
+    /// Returns the SVG string of the histogram of these `entries` durations.
+    pub fn get_histogram_svg(&self, entries: &[EntryResult]) -> String {
+        let times = entries.iter().map(|e| e.time_in_ms).collect::<Vec<_>>();
+        let buckets = get_buckets(&times, MAX_BUCKETS);
+        if buckets.is_empty() {
+            return String::new();
+        }
+        new_histogram(&buckets).to_string()
+    }
+}
+
+/// Splits `times` (durations in milliseconds) in at most `max_buckets` buckets of equal "nice" width.
+fn get_buckets(times: &[u128], max_buckets: usize) -> Vec<Bucket> {
+    let Some(max) = times.iter().max() else {
+        return vec![];
+    };
+    let width = if *max == 0 {
+        1
+    } else {
+        let nice_scale = NiceScale::new(0.0, *max as f64, max_buckets);
+        (nice_scale.get_tick_spacing().ceil() as u128).max(1)
+    };
+    let count = (max / width + 1) as usize;
+    let mut buckets = (0..count)
+        .map(|i| Bucket {
+            start: i as u128 * width,
+            end: (i as u128 + 1) * width,
+            count: 0,
+        })
+        .collect::<Vec<_>>();
+    for time in times {
+        buckets[(time / width) as usize].count += 1;
+    }
+    buckets
+}
+
+/// Returns the SVG of the histogram of these `buckets`.
+fn new_histogram(buckets: &[Bucket]) -> Element {
+    let margin_top = 30.px();
+    let margin_bottom = 30.px();
+    let width = 600.px();
+    let height = 200.px();
+    let bar_width = Pixel(width.0 / buckets.len() as f64);
+    let bars_height = height - margin_top - margin_bottom;
+    let max_count = buckets.iter().map(|b| b.count).max().unwrap_or(0).max(1);
+
+    let mut root = svg::new_svg();
+    root.add_attr(ViewBox(0.0, 0.0, width.0, height.0));
+    root.add_attr(Width(width.0.to_string()));
+    root.add_attr(Height(height.0.to_string()));
+
+    let mut bars = svg::new_group();
+    let mut labels = svg::new_group();
+    labels.add_attr(Class("histogram-legend".to_string()));
+    labels.add_attr(FontSize("13px".to_string()));
+    labels.add_attr(FontFamily("sans-serif".to_string()));
+    labels.add_attr(Fill("#777".to_string()));
+
+    let bottom = height - margin_bottom;
+    for (i, bucket) in buckets.iter().enumerate() {
+        let x = bar_width * i as f64;
+        let bar_height = bars_height * (bucket.count as f64 / max_count as f64);
+        if bucket.count > 0 {
+            let y = bottom - bar_height;
+            let elt = svg::new_rect(x.0 + 1.0, y.0, bar_width.0 - 2.0, bar_height.0, "#36a9f4");
+            bars.add_child(elt);
+            let elt = svg::new_text(x.0 + 4.0, y.0 - 6.0, &bucket.count.to_string());
+            labels.add_child(elt);
+        }
+        let elt = svg::new_text(x.0 + 4.0, height.0 - 10.0, &format!("{} ms", bucket.start));
+        labels.add_child(elt);
+    }
+    root.add_child(bars);
+
+    let mut axis = svg::new_line(0.0, bottom.0, width.0, bottom.0);
+    axis.add_attr(Class("histogram-axis".to_string()));
+    axis.add_attr(Stroke("#ccc".to_string()));
+    root.add_child(axis);
+    root.add_child(labels);
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_of_times() {
+        let times = [5, 12, 18, 35, 90];
+        let buckets = get_buckets(&times, 10);
+        let counts = buckets.iter().map(|b| b.count).collect::<Vec<_>>();
+        assert_eq!(counts, vec![1, 2, 0, 1, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(
+            buckets[1],
+            Bucket {
+                start: 10,
+                end: 20,
+                count: 2
+            }
+        );
+
+        assert_eq!(
+            get_buckets(&[0, 0], 10),
+            vec![Bucket {
+                start: 0,
+                end: 1,
+                count: 2
+            }]
+        );
+        assert!(get_buckets(&[], 10).is_empty());
+    }
+
+    #[test]
+    fn histogram_svg() {
+        let buckets = [
+            Bucket {
+                start: 0,
+                end: 100,
+                count: 2,
+            },
+            Bucket {
+                start: 100,
+                end: 200,
+                count: 0,
+            },
+            Bucket {
+                start: 200,
+                end: 300,
+                count: 1,
+            },
+        ];
+        let elt = new_histogram(&buckets);
+        assert_eq!(
+            elt.to_string(),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 600 200\" width=\"600\" height=\"200\">\
+                <g>\
+                    <rect x=\"1\" y=\"30\" width=\"198\" height=\"140\" fill=\"#36a9f4\" />\
+                    <rect x=\"401\" y=\"100\" width=\"198\" height=\"70\" fill=\"#36a9f4\" />\
+                </g>\
+                <line x1=\"0\" y1=\"170\" x2=\"600\" y2=\"170\" class=\"histogram-axis\" stroke=\"#ccc\" />\
+                <g class=\"histogram-legend\" font-size=\"13px\" font-family=\"sans-serif\" fill=\"#777\">\
+                    <text x=\"4\" y=\"24\">2</text>\
+                    <text x=\"4\" y=\"190\">0 ms</text>\
+                    <text x=\"204\" y=\"190\">100 ms</text>\
+                    <text x=\"404\" y=\"94\">1</text>\
+                    <text x=\"404\" y=\"190\">200 ms</text>\
+                </g>\
+            </svg>"
+        );
+    }
+
+    #[test]
+    fn testcase_histogram_svg() {
+        let testcase = Testcase {
+            id: "08aad14a-8d10-4ecc-892e-a72703c5b494".to_string(),
+            filename: "tests/hello.hurl".to_string(),
+            success: true,
+            time_in_ms: 160,
+            errors: vec![],
+            timestamp: 0,
+        };
+        let entries = [5, 12, 18, 35, 90]
+            .iter()
+            .map(|time_in_ms| EntryResult {
+                time_in_ms: *time_in_ms,
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        let svg = testcase.get_histogram_svg(&entries);
+        assert_eq!(svg.matches("<rect ").count(), 4);
+        assert!(svg.contains("<text x=\"64\" y=\"24\">2</text>"));
+        assert!(svg.contains("90 ms"));
+
+        assert_eq!(testcase.get_histogram_svg(&[]), "");
+    }
+}
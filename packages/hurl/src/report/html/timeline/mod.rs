@@ -24,6 +24,7 @@ use crate::report::html::Testcase;
 use crate::runner::EntryResult;
 
 mod calls;
+mod histogram;
 mod nice;
 mod svg;
 mod unit;
@@ -73,10 +74,12 @@ impl Testcase {
         let nav_css = include_str!("../resources/nav.css");
         let calls_svg = self.get_calls_svg(&calls, &call_ctxs);
         let waterfall_svg = self.get_waterfall_svg(&calls, &call_ctxs);
+        let histogram_svg = self.get_histogram_svg(entries);
         format!(
             include_str!("../resources/timeline.html"),
             calls = calls_svg,
             filename = self.filename,
+            histogram = histogram_svg,
             nav = nav,
             nav_css = nav_css,
             timeline_css = timeline_css,
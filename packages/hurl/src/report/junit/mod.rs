@@ -56,19 +56,21 @@
 //!
 mod testcase;
 mod xml;
-use std::fs::File;
 use std::path::Path;
 
 pub use testcase::Testcase;
 
+use crate::report;
 use crate::report::junit::xml::{Element, XmlDocument};
 use crate::report::Error;
-
+/// Creates a JUnit from a list of `testcases`.
+///
+/// The report is gzip compressed if `filename` has a `.gz` extension.
 /// Creates a JUnit from a list of `testcases`.
 pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), Error> {
     // If there is an existing JUnit report, we parses it to insert a new testsuite.
     let mut root = if filename.exists() {
-        let file = match File::open(filename) {
+        let file = match report::read_file(filename) {
             Ok(s) => s,
             Err(why) => {
                 return Err(Error {
@@ -80,7 +82,7 @@ pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), Error
                 });
             }
         };
-        let doc = XmlDocument::parse(file).unwrap();
+        let doc = XmlDocument::parse(&file[..]).unwrap();
         doc.root.unwrap()
     } else {
         Element::new("testsuites")
@@ -90,18 +92,18 @@ pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), Error
     root = root.add_child(testsuite);
 
     let doc = XmlDocument::new(root);
-    let file = match File::create(filename) {
-        Ok(f) => f,
+    let bytes = match doc.write(Vec::new()) {
+        Ok(bytes) => bytes,
         Err(e) => {
             return Err(Error {
-                message: format!("Failed to produce JUnit report: {e:?}"),
+                message: format!("Failed to produce Junit report: {e:?}"),
             });
         }
     };
-    match doc.write(file) {
+    match report::write_file(filename, &bytes) {
         Ok(_) => Ok(()),
         Err(e) => Err(Error {
-            message: format!("Failed to produce Junit report: {e:?}"),
+            message: format!("Failed to produce JUnit report: {e:?}"),
         }),
     }
 }
@@ -170,6 +172,7 @@ mod tests {
                 )],
                 time_in_ms: 0,
                 compressed: false,
+                curl_cmd: String::new(),
             }],
             time_in_ms: 230,
             success: true,
@@ -193,6 +196,7 @@ mod tests {
                 )],
                 time_in_ms: 0,
                 compressed: false,
+                curl_cmd: String::new(),
             }],
             time_in_ms: 230,
             success: true,
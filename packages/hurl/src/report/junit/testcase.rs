@@ -26,6 +26,7 @@ pub struct Testcase {
     time_in_ms: u128,
     failures: Vec<String>,
     errors: Vec<String>,
+    warnings: Vec<String>,
 }
 
 impl Testcase {
@@ -45,12 +46,20 @@ impl Testcase {
                 errors.push(message);
             };
         }
+        let warnings = hurl_result
+            .warnings()
+            .iter()
+            .map(|(warning, entry_src_info)| {
+                logger::error_string(&name, content, warning, Some(*entry_src_info), false)
+            })
+            .collect();
         Testcase {
             id,
             name,
             time_in_ms,
             failures,
             errors,
+            warnings,
         }
     }
 
@@ -70,6 +79,12 @@ impl Testcase {
         for error in self.errors.iter() {
             element = element.add_child(Element::new("error").text(error));
         }
+
+        // Soft asserts failures are not failures: they're reported on the test standard error.
+        if !self.warnings.is_empty() {
+            let warnings = self.warnings.join("\n\n");
+            element = element.add_child(Element::new("system-err").text(&warnings));
+        }
         element
     }
 
@@ -88,7 +103,7 @@ mod test {
 
     use crate::report::junit::testcase::Testcase;
     use crate::report::junit::xml::XmlDocument;
-    use crate::runner::{EntryResult, Error, HurlResult, Input, RunnerError};
+    use crate::runner::{AssertResult, EntryResult, Error, HurlResult, Input, RunnerError};
 
     #[test]
     fn test_create_testcase_success() {
@@ -132,6 +147,7 @@ HTTP/1.0 200
                 )],
                 time_in_ms: 0,
                 compressed: false,
+                curl_cmd: String::new(),
             }],
             time_in_ms: 230,
             success: true,
@@ -171,6 +187,7 @@ HTTP/1.0 200
                 )],
                 time_in_ms: 0,
                 compressed: false,
+                curl_cmd: String::new(),
             }],
             time_in_ms: 230,
             success: true,
@@ -189,4 +206,58 @@ HTTP/1.0 200
    |</error></testcase>"#
         );
     }
+
+    #[test]
+    fn test_create_testcase_soft_assert() {
+        let content = r#"GET http://localhost:8000/hello
+HTTP 200
+[SoftAsserts]
+status == 201
+"#;
+        let filename = Input::new("test.hurl");
+        let source_info = SourceInfo::new(Pos::new(4, 0), Pos::new(4, 0));
+        let hurl_result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 31)),
+                calls: vec![],
+                captures: vec![],
+                asserts: vec![AssertResult::Soft {
+                    actual: Ok(None),
+                    source_info,
+                    predicate_result: Some(Err(Error::new(
+                        source_info,
+                        RunnerError::AssertFailure {
+                            actual: "integer <200>".to_string(),
+                            expected: "integer <201>".to_string(),
+                            type_mismatch: false,
+                        },
+                        true,
+                    ))),
+                }],
+                errors: vec![],
+                time_in_ms: 0,
+                compressed: false,
+                curl_cmd: String::new(),
+            }],
+            time_in_ms: 230,
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        let element = Testcase::from(&hurl_result, content, &filename).to_xml();
+        let doc = XmlDocument::new(element);
+        assert_eq!(
+            doc.to_string().unwrap(),
+            r#"<?xml version="1.0" encoding="utf-8"?><testcase id="test.hurl" name="test.hurl" time="0.230"><system-err>Assert failure
+  --> test.hurl:4:0
+   |
+   | GET http://localhost:8000/hello
+   | ...
+ 4 | status == 201
+   |   actual:   integer &lt;200>
+   |   expected: integer &lt;201>
+   |</system-err></testcase>"#
+        );
+    }
 }
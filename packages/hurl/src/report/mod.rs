@@ -20,6 +20,12 @@
 //! a single unit.
 
 use std::fmt;
+use std::fs;
+use std::io;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use libflate::gzip;
 
 pub mod html;
 pub mod junit;
@@ -43,3 +49,58 @@ impl From<std::io::Error> for Error {
         }
     }
 }
+
+/// Returns `true` if the report file `path` is gzip compressed (i.e. has a `.gz` extension).
+fn is_gzip(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "gz")
+}
+
+/// Reads the content of the report file `path`, uncompressing it if it's a gzip file.
+pub(crate) fn read_file(path: &Path) -> Result<Vec<u8>, io::Error> {
+    let bytes = fs::read(path)?;
+    if !is_gzip(path) {
+        return Ok(bytes);
+    }
+    let mut decoder = gzip::Decoder::new(&bytes[..])?;
+    let mut data = Vec::new();
+    decoder.read_to_end(&mut data)?;
+    Ok(data)
+}
+
+/// Writes `bytes` to the report file `path`, compressing them if it's a gzip file.
+pub(crate) fn write_file(path: &Path, bytes: &[u8]) -> Result<(), io::Error> {
+    if !is_gzip(path) {
+        return fs::write(path, bytes);
+    }
+    let mut encoder = gzip::Encoder::new(Vec::new())?;
+    encoder.write_all(bytes)?;
+    let data = encoder.finish().into_result()?;
+    fs::write(path, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_gzip_file() {
+        let dir = std::env::temp_dir().join(format!("hurl-report-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("report.tap.gz");
+        let report = "TAP version 13\n1..1\nok 1 - test.hurl\n";
+
+        write_file(&path, report.as_bytes()).unwrap();
+
+        // The file is a valid gzip file...
+        let bytes = fs::read(&path).unwrap();
+        assert_eq!(bytes[..2], [0x1f, 0x8b]);
+        let mut decoder = gzip::Decoder::new(&bytes[..]).unwrap();
+        let mut data = String::new();
+        decoder.read_to_string(&mut data).unwrap();
+        assert_eq!(data, report);
+
+        // ... that can be read back.
+        assert_eq!(read_file(&path).unwrap(), report.as_bytes());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -16,17 +16,18 @@
  *
  */
 
-use std::fs::File;
-use std::io::Write;
 use std::path::Path;
 
 use super::Testcase;
+use crate::report;
 use crate::report::Error;
 
 // https://testanything.org/tap-version-13-specification.html
 const TAP_REPORT_VERSION_MARKER: &str = "TAP version 13";
 
 /// Creates/Append a Tap report from a list of `testcases`
+///
+/// The report is gzip compressed if `filename` has a `.gz` extension.
 pub fn write_report(filename: &Path, new_testcases: &[Testcase]) -> Result<(), Error> {
     let mut testcases = vec![];
 
@@ -42,14 +43,6 @@ pub fn write_report(filename: &Path, new_testcases: &[Testcase]) -> Result<(), E
 
 /// Creates a Tap from a list of `testcases`.
 fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Error> {
-    let mut file = match File::create(filename) {
-        Ok(f) => f,
-        Err(e) => {
-            return Err(Error {
-                message: format!("Failed to produce TAP report: {e:?}"),
-            });
-        }
-    };
     let start = 1;
     let end = testcases.len();
 
@@ -62,7 +55,7 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Error>
         let description = &testcase.description;
         s.push_str(format!("{state} {number} - {description}\n").as_str());
     }
-    match file.write_all(s.as_bytes()) {
+    match report::write_file(filename, s.as_bytes()) {
         Ok(_) => Ok(()),
         Err(e) => Err(Error {
             message: format!("Failed to write TAP report: {e:?}"),
@@ -73,8 +66,17 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Error>
 /// Parse Tap report file
 fn parse_tap_file(filename: &Path) -> Result<Vec<Testcase>, Error> {
     if filename.exists() {
-        let s = match std::fs::read_to_string(filename) {
-            Ok(s) => s,
+        let s = match report::read_file(filename).map(String::from_utf8) {
+            Ok(Ok(s)) => s,
+            Ok(Err(why)) => {
+                return Err(Error {
+                    message: format!(
+                        "Issue reading {} to string to {:?}",
+                        filename.display(),
+                        why
+                    ),
+                });
+            }
             Err(why) => {
                 return Err(Error {
                     message: format!(
@@ -16,18 +16,98 @@
  *
  */
 use std::cmp::min;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
 use std::path::Path;
 use std::thread;
+use std::{fs, io};
 
 use crate::cli::options::CliOptions;
 use crate::cli::CliError;
 use crate::{cli, HurlRun};
 
+use hurl::capture::CaptureProxy;
+use hurl::http::{Cassette, RecordingClient, ReplayClient};
+use hurl::linter;
+use hurl::linter::{Diagnostic, Severity};
+use hurl::output::OutputOptions;
 use hurl::parallel::job::{Job, JobResult};
-use hurl::parallel::runner::ParallelRunner;
-use hurl::runner::Input;
-use hurl::util::term::{Stdout, WriteMode};
+use hurl::parallel::runner::{ParallelRunner, ParallelRunnerOptions};
+use hurl::runner::{Input, Output, ResponseQuery, Value};
+use hurl::util::logger::{BaseLogger, Logger};
+use hurl::util::term::{Stderr, Stdout, WriteMode};
 use hurl::{output, runner};
+use hurl_core::ast::HurlFile;
+use hurl_core::error::Error;
+use hurl_core::parser;
+
+/// Runs the setup Hurl file `setup`, given a current directory and command-line options (see
+/// [`crate::cli::options::CliOptions`]). This function returns the variables to use for the
+/// following runs: the command-line variables, overridden by the setup captures.
+pub fn run_setup(
+    setup: &Input,
+    current_dir: &Path,
+    options: &CliOptions,
+) -> Result<HashMap<String, Value>, CliError> {
+    let run = run_hook(setup, current_dir, options, &options.variables)?;
+    if !run.hurl_result.success {
+        return Err(CliError::Runtime(format!("Setup file {setup} has failed")));
+    }
+
+    let mut variables = options.variables.clone();
+    variables.extend(run.hurl_result.captured_variables());
+    Ok(variables)
+}
+
+/// Runs the teardown Hurl file `teardown`, given a current directory and command-line options
+/// (see [`crate::cli::options::CliOptions`]), after the Hurl files `runs`.
+///
+/// The teardown file can use the command-line variables, overridden by the captures of the runs
+/// (so created resources can be deleted for instance).
+pub fn run_teardown(
+    teardown: &Input,
+    current_dir: &Path,
+    options: &CliOptions,
+    runs: &[HurlRun],
+) -> Result<HurlRun, CliError> {
+    let mut variables = options.variables.clone();
+    for run in runs {
+        variables.extend(run.hurl_result.captured_variables());
+    }
+    run_hook(teardown, current_dir, options, &variables)
+}
+
+/// Runs a setup or teardown Hurl file `input` with `variables`.
+fn run_hook(
+    input: &Input,
+    current_dir: &Path,
+    options: &CliOptions,
+    variables: &HashMap<String, Value>,
+) -> Result<HurlRun, CliError> {
+    let content = match input.read_to_string() {
+        Ok(c) => c,
+        Err(error) => {
+            let error = CliError::IO(format!("Issue reading from {input}: {error}"));
+            return Err(error);
+        }
+    };
+    // The external asserts are only applied to the Hurl files to test, not to the hooks.
+    let mut options = options.clone();
+    options.assert_file = None;
+    let runner_options = options.to_runner_options(input, current_dir);
+    let logger_options = options.to_logger_options(input, 0, 1);
+
+    let hurl_result = runner::run(&content, &runner_options, variables, &logger_options);
+    let Ok(hurl_result) = hurl_result else {
+        return Err(CliError::Parsing);
+    };
+    Ok(HurlRun {
+        content,
+        filename: input.clone(),
+        hurl_result,
+    })
+}
 
 /// Runs Hurl `files` sequentially, given a current directory and command-line options (see
 /// [`crate::cli::options::CliOptions`]). This function returns a list of [`HurlRun`] results or
@@ -39,6 +119,23 @@ pub fn run_seq(
 ) -> Result<Vec<HurlRun>, CliError> {
     let mut runs = vec![];
 
+    // When recording or replaying HTTP exchanges, the same cassette is used for all the files.
+    let mut cassette = match (&options.record, &options.replay) {
+        (_, Some(path)) => match Cassette::read(path) {
+            Ok(c) => Some(c),
+            Err(error) => {
+                let path = path.display();
+                let error = CliError::IO(format!("Issue reading cassette {path}: {error}"));
+                return Err(error);
+            }
+        },
+        (Some(_), None) => Some(Cassette::default()),
+        (None, None) => None,
+    };
+
+    // Entries indices of the bodies written with `--output-split` continue from one file to the next.
+    let mut output_split_offset = 0;
+
     for (current, filename) in files.iter().enumerate() {
         let content = filename.read_to_string();
         let content = match content {
@@ -56,8 +153,34 @@ pub fn run_seq(
         // Run our Hurl file now, we can only fail if there is a parsing error.
         // The parsing error is displayed in the `execute` call, that's why we gobble the error
         // string.
-        let Ok(hurl_result) = runner::run(&content, &runner_options, variables, &logger_options)
-        else {
+        let hurl_result = match cassette.take() {
+            Some(c) if options.replay.is_some() => {
+                let mut client = ReplayClient::new(c);
+                let result = runner::run_with_client(
+                    &content,
+                    &mut client,
+                    &runner_options,
+                    variables,
+                    &logger_options,
+                );
+                cassette = Some(client.into_cassette());
+                result
+            }
+            Some(c) => {
+                let mut client = RecordingClient::new(c);
+                let result = runner::run_with_client(
+                    &content,
+                    &mut client,
+                    &runner_options,
+                    variables,
+                    &logger_options,
+                );
+                cassette = Some(client.into_cassette());
+                result
+            }
+            None => runner::run(&content, &runner_options, variables, &logger_options),
+        };
+        let Ok(hurl_result) = hurl_result else {
             return Err(CliError::Parsing);
         };
 
@@ -66,16 +189,24 @@ pub fn run_seq(
         // We can output the result, either the last raw body response or a structured JSON
         // representation of the full Hurl result.
         // In sequential run, we use an immediate (non-buffered) standard output.
+        // The last body response is output on success, or only on failure with
+        // `--body-only-on-failure`.
         let mut stdout = Stdout::new(WriteMode::Immediate);
-        let output_body = success
+        let output_body = success != options.body_only_on_failure
             && !options.interactive
             && matches!(options.output_type, cli::OutputType::ResponseBody);
         if output_body {
-            let include_headers = options.include;
+            let output_options = OutputOptions {
+                append_newline: options.output_append_newline,
+                body_format: options.body_format,
+                color: options.color,
+                include_headers: options.include,
+                include_request: options.include_request,
+                sort_headers: options.sort_headers,
+            };
             let result = output::write_last_body(
                 &hurl_result,
-                include_headers,
-                options.color,
+                &output_options,
                 options.output.as_ref(),
                 &mut stdout,
             );
@@ -83,6 +214,19 @@ pub fn run_seq(
                 return Err(CliError::Runtime(e.to_string()));
             }
         }
+        if let Some(dir) = &options.output_split {
+            let result = output::write_entries_bodies(
+                &hurl_result,
+                options.body_format,
+                dir,
+                output_split_offset,
+                &mut stdout,
+            );
+            match result {
+                Ok(offset) => output_split_offset = offset,
+                Err(e) => return Err(CliError::Runtime(e.to_string())),
+            }
+        }
         if matches!(options.output_type, cli::OutputType::Json) {
             let result = output::write_json(
                 &hurl_result,
@@ -102,11 +246,204 @@ pub fn run_seq(
             hurl_result,
         };
         runs.push(run);
+
+        // With fail fast, the remaining files are not run after a failed file.
+        if options.fail_fast && !success {
+            break;
+        }
+    }
+
+    if let (Some(path), Some(cassette)) = (&options.record, &cassette) {
+        if let Err(error) = cassette.write(path) {
+            let path = path.display();
+            let error = CliError::IO(format!("Issue writing cassette {path}: {error}"));
+            return Err(error);
+        }
     }
 
     Ok(runs)
 }
 
+/// Checks Hurl `files` without running them, given command-line options
+/// (see [`crate::cli::options::CliOptions`]). Syntax errors, and lint warnings if `--lint` is
+/// used, are displayed on the standard error, or as JSON diagnostics on the standard output if
+/// `--diagnostics-json` is used. This function returns an error if a file can't be read or parsed.
+pub fn check(files: &[Input], options: &CliOptions) -> Result<(), CliError> {
+    let mut parsing_error = false;
+    let mut diagnostics = vec![];
+    for (current, filename) in files.iter().enumerate() {
+        let content = filename.read_to_string();
+        let content = match content {
+            Ok(c) => c,
+            Err(error) => {
+                let error = CliError::IO(format!("Issue reading from {filename}: {error}"));
+                return Err(error);
+            }
+        };
+        let logger_options = options.to_logger_options(filename, current, files.len());
+        let mut logger = Logger::new(&logger_options, Stderr::new(WriteMode::Immediate));
+
+        match parser::parse_hurl_file_with_recovery(&content) {
+            Ok(hurl_file) => {
+                if options.lint {
+                    for warning in linter::check_hurl_file(&hurl_file, &content) {
+                        if options.diagnostics_json {
+                            let filename = filename.to_string();
+                            let d = Diagnostic::new(&filename, Severity::Warning, &warning);
+                            diagnostics.push(d);
+                        } else {
+                            logger.warning_rich(&content, &warning);
+                        }
+                    }
+                }
+            }
+            Err(errors) => {
+                for e in &errors {
+                    if options.diagnostics_json {
+                        let filename = filename.to_string();
+                        diagnostics.push(Diagnostic::new(&filename, Severity::Error, e));
+                    } else {
+                        logger.error_parsing_rich(&content, e);
+                    }
+                }
+                parsing_error = true;
+            }
+        }
+    }
+    if options.diagnostics_json {
+        let diagnostics = diagnostics.iter().map(|d| d.to_json()).collect();
+        let json = serde_json::Value::Array(diagnostics).to_string();
+        let mut stdout = Stdout::new(WriteMode::Immediate);
+        if let Err(e) = stdout.write_all(format!("{json}\n").as_bytes()) {
+            return Err(CliError::Runtime(e.to_string()));
+        }
+    }
+    if parsing_error {
+        return Err(CliError::Parsing);
+    }
+    Ok(())
+}
+
+/// Lists the entries of Hurl `files` without running them, given command-line options
+/// (see [`crate::cli::options::CliOptions`]). The listing is written on the standard output, with
+/// the name of each file when there are more than one file. This function returns an error if a
+/// file can't be read or parsed.
+pub fn list_entries(files: &[Input], options: &CliOptions) -> Result<(), CliError> {
+    let mut listing = String::new();
+    for (current, filename) in files.iter().enumerate() {
+        let content = filename.read_to_string();
+        let content = match content {
+            Ok(c) => c,
+            Err(error) => {
+                let error = CliError::IO(format!("Issue reading from {filename}: {error}"));
+                return Err(error);
+            }
+        };
+        let hurl_file = match parser::parse_hurl_file(&content) {
+            Ok(h) => h,
+            Err(e) => {
+                let logger_options = options.to_logger_options(filename, current, files.len());
+                let mut logger = Logger::new(&logger_options, Stderr::new(WriteMode::Immediate));
+                logger.error_parsing_rich(&content, &e);
+                return Err(CliError::Parsing);
+            }
+        };
+        if files.len() > 1 {
+            listing.push_str(&format!("{filename}:\n"));
+        }
+        listing.push_str(&get_entries_listing(&hurl_file));
+    }
+    let mut stdout = Stdout::new(WriteMode::Immediate);
+    if let Err(e) = stdout.write_all(listing.as_bytes()) {
+        return Err(CliError::Runtime(e.to_string()));
+    }
+    Ok(())
+}
+
+/// Returns the listing of the entries of `hurl_file`, one line per entry with its index, its
+/// method, its templated URL and its number of explicit asserts.
+fn get_entries_listing(hurl_file: &HurlFile) -> String {
+    let mut listing = String::new();
+    for (index, entry) in hurl_file.entries.iter().enumerate() {
+        let asserts = entry
+            .response
+            .as_ref()
+            .map_or(0, |r| r.asserts().len() + r.soft_asserts().len());
+        let request = &entry.request;
+        let line = format!(
+            "{}: {} {} ({asserts} asserts)\n",
+            index + 1,
+            request.method,
+            request.url
+        );
+        listing.push_str(&line);
+    }
+    listing
+}
+
+/// Runs a local proxy on `addr` recording the HTTP traffic as Hurl entries, given command-line
+/// options (see [`crate::cli::options::CliOptions`]). Entries are written to the output file if
+/// `--output` is used, or to the standard output, connection errors being reported with `logger`.
+/// This function runs until an error occurs.
+pub fn capture(addr: &str, options: &CliOptions, logger: &BaseLogger) -> Result<(), CliError> {
+    let mut proxy = match CaptureProxy::bind(addr) {
+        Ok(p) => p,
+        Err(error) => {
+            let error = CliError::Runtime(format!("Issue starting proxy on {addr}: {error}"));
+            return Err(error);
+        }
+    };
+    let result = match &options.output {
+        Some(Output::File(path)) => match File::create(path) {
+            Ok(mut file) => proxy.run(&mut file, logger),
+            Err(error) => {
+                let path = path.display();
+                let error = CliError::IO(format!("Issue writing to {path}: {error}"));
+                return Err(error);
+            }
+        },
+        _ => proxy.run(&mut io::stdout(), logger),
+    };
+    result.map_err(|e| CliError::Runtime(e.to_string()))
+}
+
+/// Evaluates `query` on a stored response body, given command-line options (see
+/// [`crate::cli::options::CliOptions`]). The response body is read from the `--input` file, or from
+/// the standard input, and the query result is written to the standard output. No HTTP request is
+/// sent in this mode.
+pub fn query(query: &ResponseQuery, options: &CliOptions) -> Result<(), CliError> {
+    let body = match &options.input {
+        Some(filename) => match fs::read(filename) {
+            Ok(body) => body,
+            Err(error) => {
+                let error = CliError::IO(format!("Issue reading from {filename}: {error}"));
+                return Err(error);
+            }
+        },
+        None => {
+            let mut body = vec![];
+            if let Err(error) = io::stdin().read_to_end(&mut body) {
+                let error = CliError::IO(format!("Issue reading from standard input: {error}"));
+                return Err(error);
+            }
+            body
+        }
+    };
+    let value = match query.eval(&body, options.timezone) {
+        Ok(Some(value)) => value,
+        Ok(None) => return Err(CliError::Runtime("No query result".to_string())),
+        Err(e) => {
+            let message = format!("{}: {}", e.description(), e.fixme());
+            return Err(CliError::Runtime(message));
+        }
+    };
+    let mut stdout = Stdout::new(WriteMode::Immediate);
+    if let Err(e) = stdout.write_all(format!("{value}\n").as_bytes()) {
+        return Err(CliError::Runtime(e.to_string()));
+    }
+    Ok(())
+}
+
 /// Runs Hurl `files` in parallel, given a current directory and command-line options (see
 /// [`crate::cli::options::CliOptions`]). This function returns a list of [`HurlRun`] results or
 /// an error.
@@ -131,13 +468,15 @@ pub fn run_par(
         })
         .collect::<Vec<_>>();
 
-    let mut runner = ParallelRunner::new(
-        workers_count,
-        options.test,
-        options.progress_bar,
-        options.color,
-    );
-    let results = runner.run(&jobs)?;
+    let parallel_options = ParallelRunnerOptions {
+        test: options.test,
+        progress_bar: options.progress_bar,
+        color: options.color,
+        max_concurrent_per_host: options.max_concurrent_per_host,
+        fail_fast: options.fail_fast,
+    };
+    let mut runner = ParallelRunner::new(workers_count, &parallel_options);
+    let (results, _) = runner.run(&jobs)?;
     let results = results.into_iter().map(HurlRun::from).collect();
     Ok(results)
 }
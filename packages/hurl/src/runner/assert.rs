@@ -17,17 +17,61 @@
  */
 use std::collections::HashMap;
 
+use chrono::{FixedOffset, Offset, Utc};
 use hurl_core::ast::*;
+use hurl_core::error::Error as CoreError;
 
 use crate::http;
+use crate::http::HeaderMatch;
 use crate::runner::error::{Error, RunnerError};
-use crate::runner::filter::eval_filters;
+use crate::runner::filter::{eval_filters, FilterContext};
 use crate::runner::predicate::eval_predicate;
 use crate::runner::query::eval_query;
 use crate::runner::result::AssertResult;
-use crate::runner::Value;
+use crate::runner::{RunnerOptions, Value};
 use crate::util::path::ContextDir;
 
+/// Options used to evaluate the asserts of a response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssertOptions {
+    /// Stops evaluating the asserts of a response at the first failed one (soft asserts are
+    /// always all evaluated).
+    pub abort_on_first_failure: bool,
+    /// How header names are matched.
+    pub header_match: HeaderMatch,
+    /// Normalizes numbers of an implicit JSON body assert before comparison.
+    pub normalize_json_numbers: bool,
+    /// Replaces `\r\n` line endings by `\n` in the actual and expected strings before comparison.
+    pub normalize_newlines: bool,
+    /// The timezone offset in which dates are parsed and formatted by filters.
+    pub timezone: FixedOffset,
+}
+
+impl From<&RunnerOptions> for AssertOptions {
+    /// Creates the assert options of an entry, given the `runner_options`.
+    fn from(runner_options: &RunnerOptions) -> Self {
+        AssertOptions {
+            abort_on_first_failure: runner_options.abort_on_first_assert_failure_within_entry,
+            header_match: runner_options.header_match,
+            normalize_json_numbers: runner_options.normalize_json_numbers,
+            normalize_newlines: runner_options.normalize_newlines,
+            timezone: runner_options.timezone,
+        }
+    }
+}
+
+impl Default for AssertOptions {
+    fn default() -> Self {
+        AssertOptions {
+            abort_on_first_failure: false,
+            header_match: HeaderMatch::default(),
+            normalize_json_numbers: false,
+            normalize_newlines: false,
+            timezone: Utc.fix(),
+        }
+    }
+}
+
 impl AssertResult {
     /// Evaluates an assert and returns `None` if assert is succeeded or an `Error` if failed.
     pub fn error(&self) -> Option<Error> {
@@ -89,6 +133,18 @@ impl AssertResult {
                     Ok(actual) => {
                         if actual == expected {
                             None
+                        } else if let (Value::Bytes(actual), Value::Bytes(expected)) =
+                            (actual, expected)
+                        {
+                            // As for explicit asserts, column error is set to 0 to display the
+                            // multi-line diff instead of "^^^".
+                            let line = source_info.start.line;
+                            let source_info = SourceInfo::new(Pos::new(line, 0), Pos::new(line, 0));
+                            let inner = RunnerError::AssertBytesFailure {
+                                actual: actual.clone(),
+                                expected: expected.clone(),
+                            };
+                            Some(Error::new(source_info, inner, false))
                         } else {
                             let actual = actual.to_string();
                             let expected = expected.to_string();
@@ -98,14 +154,48 @@ impl AssertResult {
                     }
                 },
             },
-            AssertResult::Explicit { actual: Err(e), .. } => Some(e.clone()),
+            AssertResult::Explicit { actual: Err(e), .. }
+            | AssertResult::Soft { actual: Err(e), .. } => Some(e.clone()),
             AssertResult::Explicit {
                 predicate_result: Some(Err(e)),
                 ..
+            }
+            | AssertResult::Soft {
+                predicate_result: Some(Err(e)),
+                ..
             } => Some(e.clone()),
             _ => None,
         }
     }
+
+    /// Returns `true` if this assert is a soft assert, whose failure doesn't make the entry fail.
+    pub fn is_soft(&self) -> bool {
+        matches!(self, AssertResult::Soft { .. })
+    }
+
+    /// Returns the value returned by the query of this assert, as displayed with `--explain`.
+    pub fn actual_display(&self) -> String {
+        match self {
+            AssertResult::Version { actual, .. } => format!("string <{actual}>"),
+            AssertResult::Status { actual, .. } => format!("int <{actual}>"),
+            AssertResult::Header { actual, .. } => match actual {
+                Ok(s) => format!("string <{s}>"),
+                Err(e) => e.description(),
+            },
+            AssertResult::Body { actual, .. } => match actual {
+                Ok(v) => v.display(),
+                Err(e) => e.description(),
+            },
+            AssertResult::Explicit { actual, .. } | AssertResult::Soft { actual, .. } => {
+                match actual {
+                    Ok(Some(v)) => v.display(),
+                    Ok(None) => "none".to_string(),
+                    Err(e) => e.description(),
+                }
+            }
+        }
+    }
+
     pub fn line(&self) -> usize {
         match self {
             AssertResult::Version { source_info, .. } => source_info.start.line,
@@ -113,19 +203,49 @@ impl AssertResult {
             AssertResult::Header { source_info, .. } => source_info.start.line,
             AssertResult::Body { source_info, .. } => source_info.start.line,
             AssertResult::Explicit { source_info, .. } => source_info.start.line,
+            AssertResult::Soft { source_info, .. } => source_info.start.line,
         }
     }
 }
 
-/// Evaluates an explicit `assert`, given a set of `variables`, a HTTP response and a context
-/// directory `context_dir`.
+/// Evaluates a soft `assert`, given a set of `variables`, a HTTP response, assert `options` and a
+/// context directory `context_dir`.
+pub fn eval_soft_assert(
+    assert: &Assert,
+    variables: &HashMap<String, Value>,
+    http_response: &http::Response,
+    options: &AssertOptions,
+    context_dir: &ContextDir,
+) -> AssertResult {
+    match eval_explicit_assert(assert, variables, http_response, options, context_dir) {
+        AssertResult::Explicit {
+            actual,
+            source_info,
+            predicate_result,
+        } => AssertResult::Soft {
+            actual,
+            source_info,
+            predicate_result,
+        },
+        assert_result => assert_result,
+    }
+}
+
+/// Evaluates an explicit `assert`, given a set of `variables`, a HTTP response, assert `options`
+/// and a context directory `context_dir`.
 pub fn eval_explicit_assert(
     assert: &Assert,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    options: &AssertOptions,
     context_dir: &ContextDir,
 ) -> AssertResult {
-    let query_result = eval_query(&assert.query, variables, http_response);
+    let query_result = eval_query(
+        &assert.query,
+        variables,
+        http_response,
+        options.header_match,
+    );
 
     let actual = if assert.filters.is_empty() {
         query_result
@@ -143,7 +263,15 @@ pub fn eval_explicit_assert(
             }),
             Some(value) => {
                 let filters = assert.filters.iter().map(|(_, f)| f.clone()).collect();
-                match eval_filters(&filters, &value, variables, true) {
+                match eval_filters(
+                    &filters,
+                    &value,
+                    variables,
+                    &FilterContext {
+                        in_assert: true,
+                        timezone: options.timezone,
+                    },
+                ) {
                     Ok(value) => Ok(value),
                     Err(e) => Err(e),
                 }
@@ -160,6 +288,7 @@ pub fn eval_explicit_assert(
             &assert.predicate,
             variables,
             actual,
+            options.normalize_newlines,
             context_dir,
         )),
     };
@@ -234,7 +363,8 @@ pub mod tests {
                 &assert_count_user(),
                 &variables,
                 &xml_three_users_http_response(),
-                &context_dir
+                &AssertOptions::default(),
+                &context_dir,
             ),
             AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(3)))),
@@ -243,4 +373,29 @@ pub mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_actual_display() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let assert = AssertResult::Status {
+            actual: 200,
+            expected: 200,
+            source_info,
+        };
+        assert_eq!(assert.actual_display(), "int <200>");
+
+        let assert = AssertResult::Explicit {
+            actual: Ok(Some(Value::String("Bob".to_string()))),
+            source_info,
+            predicate_result: Some(Ok(())),
+        };
+        assert_eq!(assert.actual_display(), "string <Bob>");
+
+        let assert = AssertResult::Explicit {
+            actual: Ok(None),
+            source_info,
+            predicate_result: None,
+        };
+        assert_eq!(assert.actual_display(), "none");
+    }
 }
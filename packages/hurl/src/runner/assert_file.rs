@@ -0,0 +1,94 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+
+use hurl_core::ast::Assert;
+use hurl_core::parser;
+
+use crate::http;
+use crate::runner::assert;
+use crate::runner::assert::AssertOptions;
+use crate::runner::result::AssertResult;
+use crate::runner::Value;
+use crate::util::path::ContextDir;
+
+/// A list of asserts read from an external file, evaluated on the last response of a run.
+///
+/// Contrary to the asserts of an entry, these asserts are not part of the Hurl file source, so we
+/// keep the assert file `filename` and `content` to report errors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssertFile {
+    pub(crate) filename: String,
+    pub(crate) content: String,
+    pub(crate) asserts: Vec<Assert>,
+}
+
+impl AssertFile {
+    /// Creates a new assert file named `filename`, parsing its `content` made of assert lines.
+    pub fn new(filename: &str, content: &str) -> Result<AssertFile, parser::Error> {
+        let asserts = parser::parse_asserts(content)?;
+        Ok(AssertFile {
+            filename: filename.to_string(),
+            content: content.to_string(),
+            asserts,
+        })
+    }
+
+    /// Evaluates the asserts of this file against `http_response`.
+    pub(crate) fn eval(
+        &self,
+        variables: &HashMap<String, Value>,
+        http_response: &http::Response,
+        options: &AssertOptions,
+        context_dir: &ContextDir,
+    ) -> Vec<AssertResult> {
+        self.asserts
+            .iter()
+            .map(|a| {
+                assert::eval_explicit_assert(a, variables, http_response, options, context_dir)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{hello_http_response, HeaderMatch};
+
+    #[test]
+    fn eval_assert_file() {
+        let content = "status == 200\nbody contains \"Bye\"\n";
+        let assert_file = AssertFile::new("checks.asserts", content).unwrap();
+        let asserts = assert_file.eval(
+            &HashMap::new(),
+            &hello_http_response(),
+            &AssertOptions {
+                header_match: HeaderMatch::Exact,
+                ..AssertOptions::default()
+            },
+            &ContextDir::default(),
+        );
+        assert_eq!(asserts.len(), 2);
+        assert!(asserts[0].error().is_none());
+        let error = asserts[1].error().unwrap();
+        assert_eq!(error.source_info.start.line, 2);
+
+        assert!(AssertFile::new("checks.asserts", "status ==\n").is_err());
+    }
+}
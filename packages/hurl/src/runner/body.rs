@@ -24,7 +24,7 @@ use crate::http;
 use crate::runner::error::{Error, RunnerError};
 use crate::runner::json::eval_json_value;
 use crate::runner::multiline::eval_multiline;
-use crate::runner::template::eval_template;
+use crate::runner::template::{eval_expression, eval_template};
 use crate::runner::value::Value;
 use crate::util::path::ContextDir;
 
@@ -43,6 +43,10 @@ pub fn eval_bytes(
 ) -> Result<http::Body, Error> {
     match bytes {
         Bytes::OnelineString(value) => {
+            // A string made of a single bytes variable is sent as is, without any encoding.
+            if let Some(bytes) = eval_bytes_variable(value, variables)? {
+                return Ok(http::Body::Binary(bytes));
+            }
             let value = eval_template(value, variables)?;
             Ok(http::Body::Text(value))
         }
@@ -65,6 +69,21 @@ pub fn eval_bytes(
     }
 }
 
+/// Returns the value of the variable of this `template` if the template is only made of a
+/// bytes variable, `None` otherwise.
+fn eval_bytes_variable(
+    template: &Template,
+    variables: &HashMap<String, Value>,
+) -> Result<Option<Vec<u8>>, Error> {
+    let [TemplateElement::Expression(expr)] = template.elements.as_slice() else {
+        return Ok(None);
+    };
+    match eval_expression(expr, variables)? {
+        Value::Bytes(bytes) => Ok(Some(bytes)),
+        _ => Ok(None),
+    }
+}
+
 pub fn eval_file(
     filename: &Template,
     variables: &HashMap<String, Value>,
@@ -127,6 +146,45 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_body_bytes_variable() {
+        // `{{data}}`
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let bytes = Bytes::OnelineString(Template {
+            delimiter: Some('`'),
+            source_info: SourceInfo::new(Pos::new(1, 2), Pos::new(1, 10)),
+            elements: vec![TemplateElement::Expression(Expr {
+                space0: whitespace.clone(),
+                variable: Variable {
+                    name: "data".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 4), Pos::new(1, 8)),
+                },
+                space1: whitespace,
+            })],
+        });
+
+        let current_dir = std::env::current_dir().unwrap();
+        let context_dir = ContextDir::new(current_dir.as_path(), Path::new(""));
+
+        // Bytes are sent unchanged, even if they're not valid UTF-8.
+        let mut variables = HashMap::new();
+        variables.insert("data".to_string(), Value::Bytes(vec![0xff, 0x00, 0x2a]));
+        assert_eq!(
+            eval_bytes(&bytes, &variables, &context_dir).unwrap(),
+            http::Body::Binary(vec![0xff, 0x00, 0x2a])
+        );
+
+        // Other variables are rendered as text.
+        variables.insert("data".to_string(), Value::String("Hello".to_string()));
+        assert_eq!(
+            eval_bytes(&bytes, &variables, &context_dir).unwrap(),
+            http::Body::Text("Hello".to_string())
+        );
+    }
+
     #[test]
     pub fn test_body_file_error() {
         // file, data.bin;
@@ -17,25 +17,29 @@
  */
 use std::collections::HashMap;
 
+use chrono::FixedOffset;
 use hurl_core::ast::*;
 
 use crate::http;
+use crate::http::HeaderMatch;
 use crate::runner::error::{Error, RunnerError};
-use crate::runner::filter::eval_filters;
+use crate::runner::filter::{eval_filters, FilterContext};
 use crate::runner::query::eval_query;
 use crate::runner::result::CaptureResult;
 use crate::runner::template::eval_template;
 use crate::runner::Value;
 
 /// Evaluates a `capture` with `variables` map and `http_response`, returns a
-/// [`CaptureResult`] on success or an [`Error`] .
+/// [`CaptureResult`] on success or an [`Error`] . Header names are matched with `header_match`.
 pub fn eval_capture(
     capture: &Capture,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    header_match: HeaderMatch,
+    timezone: FixedOffset,
 ) -> Result<CaptureResult, Error> {
     let name = eval_template(&capture.name, variables)?;
-    let value = eval_query(&capture.query, variables, http_response)?;
+    let value = eval_query(&capture.query, variables, http_response, header_match)?;
     let value = match value {
         None => {
             return Err(Error::new(
@@ -46,13 +50,21 @@ pub fn eval_capture(
         }
         Some(value) => {
             let filters = capture.filters.iter().map(|(_, f)| f.clone()).collect();
-            match eval_filters(&filters, &value, variables, false)? {
+            match eval_filters(
+                &filters,
+                &value,
+                variables,
+                &FilterContext {
+                    in_assert: false,
+                    timezone,
+                },
+            )? {
                 None => {
-                    return Err(Error::new(
-                        capture.query.source_info,
-                        RunnerError::NoQueryResult,
-                        false,
-                    ));
+                    // The error points to the last filter, which has returned no value.
+                    let source_info = filters
+                        .last()
+                        .map_or(capture.query.source_info, |f| f.source_info);
+                    return Err(Error::new(source_info, RunnerError::NoQueryResult, false));
                 }
                 Some(v) => v,
             }
@@ -67,6 +79,7 @@ pub fn eval_capture(
 
 #[cfg(test)]
 pub mod tests {
+    use chrono::{Offset, Utc};
     use hurl_core::ast::{Pos, SourceInfo};
 
     use self::super::super::query;
@@ -165,9 +178,15 @@ pub mod tests {
             },
         };
 
-        let error = eval_capture(&capture, &variables, &http::xml_three_users_http_response())
-            .err()
-            .unwrap();
+        let error = eval_capture(
+            &capture,
+            &variables,
+            &http::xml_three_users_http_response(),
+            HeaderMatch::default(),
+            Utc.fix(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 7 });
         assert_eq!(error.inner, RunnerError::QueryInvalidXpathEval);
     }
@@ -225,6 +244,8 @@ pub mod tests {
                 &user_count_capture(),
                 &variables,
                 &http::xml_three_users_http_response(),
+                HeaderMatch::default(),
+                Utc.fix(),
             )
             .unwrap(),
             CaptureResult {
@@ -234,11 +255,83 @@ pub mod tests {
         );
 
         assert_eq!(
-            eval_capture(&duration_capture(), &variables, &http::json_http_response()).unwrap(),
+            eval_capture(
+                &duration_capture(),
+                &variables,
+                &http::json_http_response(),
+                HeaderMatch::default(),
+                Utc.fix(),
+            )
+            .unwrap(),
             CaptureResult {
                 name: "duration".to_string(),
                 value: Value::Number(Number::from(1.5)),
             }
         );
     }
+
+    #[test]
+    fn test_capture_filter_no_result() {
+        // body jsonpath "$.unknown"
+        let variables = HashMap::new();
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let capture = Capture {
+            line_terminators: vec![],
+            space0: whitespace.clone(),
+            name: Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "unknown".to_string(),
+                    encoded: "unknown".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8)),
+            },
+            space1: whitespace.clone(),
+            space2: whitespace.clone(),
+            query: Query {
+                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 14)),
+                value: QueryValue::Body,
+            },
+            filters: vec![(
+                whitespace.clone(),
+                Filter {
+                    source_info: SourceInfo::new(Pos::new(1, 15), Pos::new(1, 35)),
+                    value: FilterValue::JsonPath {
+                        space0: whitespace.clone(),
+                        expr: Template {
+                            delimiter: Some('"'),
+                            elements: vec![TemplateElement::String {
+                                value: "$.unknown".to_string(),
+                                encoded: "$.unknown".to_string(),
+                            }],
+                            source_info: SourceInfo::new(Pos::new(1, 24), Pos::new(1, 35)),
+                        },
+                    },
+                },
+            )],
+            line_terminator0: LineTerminator {
+                space0: whitespace.clone(),
+                comment: None,
+                newline: whitespace,
+            },
+        };
+
+        let error = eval_capture(
+            &capture,
+            &variables,
+            &http::json_http_response(),
+            HeaderMatch::default(),
+            Utc.fix(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 15), Pos::new(1, 35))
+        );
+        assert_eq!(error.inner, RunnerError::NoQueryResult);
+    }
 }
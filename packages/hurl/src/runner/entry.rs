@@ -19,14 +19,16 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use hurl_core::ast::*;
+use uuid::Uuid;
 
 use crate::http;
-use crate::http::ClientOptions;
-use crate::runner::error::Error;
+use crate::http::{ClientOptions, HttpClient, DEFAULT_ACCEPT_ENCODING};
+use crate::runner::assert::AssertOptions;
+use crate::runner::error::{Error, RunnerError};
 use crate::runner::result::{AssertResult, EntryResult};
 use crate::runner::runner_options::RunnerOptions;
 use crate::runner::value::Value;
-use crate::runner::{request, response, CaptureResult};
+use crate::runner::{pagination, request, response, CaptureResult};
 use crate::util::logger::{Logger, Verbosity};
 
 /// Runs an `entry` with `http_client` and returns one [`EntryResult`].
@@ -35,18 +37,21 @@ use crate::util::logger::{Logger, Verbosity};
 /// been executed. If `http_client` has been configured to follow redirection, the `calls` list contains
 /// every step of the redirection for the first to the last.
 /// `variables` are used to render values at runtime, and can be updated by captures.
+/// `idempotency_key` is sent, if any, in the [`RunnerOptions::idempotency_key_header`] header of
+/// mutating requests.
 pub fn run(
     entry: &Entry,
     entry_index: usize,
-    http_client: &mut http::Client,
+    http_client: &mut dyn HttpClient,
     variables: &mut HashMap<String, Value>,
     runner_options: &RunnerOptions,
+    idempotency_key: Option<&str>,
     logger: &mut Logger,
 ) -> EntryResult {
-    let compressed = runner_options.compressed;
+    let compressed = get_accept_encoding(runner_options).is_some();
     let source_info = entry.source_info();
     let context_dir = &runner_options.context_dir;
-    let http_request = match request::eval_request(&entry.request, variables, context_dir) {
+    let mut http_request = match request::eval_request(&entry.request, variables, context_dir) {
         Ok(r) => r,
         Err(error) => {
             return EntryResult {
@@ -58,7 +63,26 @@ pub fn run(
             };
         }
     };
-    let client_options = ClientOptions::from(runner_options, logger.verbosity);
+    if let (Some(name), Some(key)) = (&runner_options.idempotency_key_header, idempotency_key) {
+        if is_mutating(&http_request.method) && !http_request.headers.contains_key(name) {
+            http_request.headers.push(http::Header::new(name, key));
+        }
+    }
+    if runner_options.trace_context {
+        add_trace_context(&mut http_request, runner_options.trace_state.as_deref());
+    }
+    let mut client_options = ClientOptions::from(runner_options, logger.verbosity);
+    // Bodies of paginated responses are aggregated, and bodies written to an output file, they
+    // must be fully read.
+    if runner_options.partial_body
+        && !runner_options.ignore_asserts
+        && runner_options.paginate.is_none()
+        && runner_options.output.is_none()
+    {
+        if let Some(response_spec) = &entry.response {
+            client_options.body_contains = response::eval_body_contains(response_spec, variables);
+        }
+    }
 
     // Experimental features with cookie storage
     use std::str::FromStr;
@@ -73,16 +97,14 @@ pub fn run(
         http_client.clear_cookie_storage(&client_options);
     }
 
-    log_request(
-        http_client,
-        &http_request,
-        runner_options,
-        &client_options,
-        logger,
-    );
+    let output = &runner_options.output;
+    let curl_cmd =
+        http_client.curl_command_line(&http_request, context_dir, output.as_ref(), &client_options);
+    log_request(http_client, &http_request, &curl_cmd, logger);
 
     // Run the HTTP requests (optionally follow redirection)
-    let calls = match http_client.execute_with_redirect(&http_request, &client_options, logger) {
+    let mut calls = match http_client.execute_with_redirect(&http_request, &client_options, logger)
+    {
         Ok(calls) => calls,
         Err(http_error) => {
             let start = entry.request.url.source_info.start;
@@ -94,14 +116,44 @@ pub fn run(
                 source_info,
                 errors: vec![error],
                 compressed,
+                curl_cmd,
                 ..Default::default()
             };
         }
     };
 
-    // We runs capture and asserts on the last HTTP request/response chains.
-    let call = calls.last().unwrap();
-    let http_response = &call.response;
+    // Follow the pagination links, if any: captures and asserts are then run on the aggregated
+    // pages response.
+    let pages_response = match &runner_options.paginate {
+        None => None,
+        Some(rel) => match pagination::paginate(
+            http_client,
+            &http_request,
+            rel,
+            runner_options.max_pages,
+            &mut calls,
+            &client_options,
+            logger,
+        ) {
+            Ok(response) => response,
+            Err(http_error) => {
+                let start = entry.request.url.source_info.start;
+                let end = entry.request.url.source_info.end;
+                let error_source_info = SourceInfo::new(start, end);
+                let error = Error::new(error_source_info, http_error.into(), false);
+                return EntryResult {
+                    entry_index,
+                    source_info,
+                    calls,
+                    errors: vec![error],
+                    compressed,
+                    curl_cmd,
+                    ..Default::default()
+                };
+            }
+        },
+    };
+
     // `time_in_ms` represent the network time of calls, not including assert processing.
     let time_in_ms = calls
         .iter()
@@ -109,6 +161,41 @@ pub fn run(
         .sum::<Duration>()
         .as_millis();
 
+    // Any response in a failing status class makes the entry fail, regardless of the asserts.
+    let status_errors = calls
+        .iter()
+        .map(|call| call.response.status)
+        .filter(|status| runner_options.fail_on.contains(&(status / 100)))
+        .map(|status| {
+            let start = entry.request.url.source_info.start;
+            let end = entry.request.url.source_info.end;
+            let error_source_info = SourceInfo::new(start, end);
+            Error::new(
+                error_source_info,
+                RunnerError::FailOnStatus { status },
+                false,
+            )
+        })
+        .collect::<Vec<_>>();
+    if !status_errors.is_empty() {
+        return EntryResult {
+            entry_index,
+            source_info,
+            calls,
+            errors: status_errors,
+            time_in_ms,
+            compressed,
+            curl_cmd,
+            ..Default::default()
+        };
+    }
+
+    // We runs capture and asserts on the last HTTP request/response chains.
+    let http_response = match &pages_response {
+        Some(response) => response,
+        None => &calls.last().unwrap().response,
+    };
+
     // We proceed asserts and captures in this order:
     // 1. first, check implicit assert on status and version. If KO, test is failed
     // 2. then, we compute captures, we might need them in asserts
@@ -132,6 +219,7 @@ pub fn run(
                     errors,
                     time_in_ms,
                     compressed,
+                    curl_cmd,
                 };
             }
         }
@@ -140,7 +228,13 @@ pub fn run(
     let captures = match &entry.response {
         None => vec![],
         Some(response_spec) => {
-            match response::eval_captures(response_spec, http_response, variables) {
+            match response::eval_captures(
+                response_spec,
+                http_response,
+                variables,
+                runner_options.header_match,
+                runner_options.timezone,
+            ) {
                 Ok(captures) => captures,
                 Err(e) => {
                     return EntryResult {
@@ -152,6 +246,7 @@ pub fn run(
                         errors: vec![e],
                         time_in_ms,
                         compressed,
+                        curl_cmd,
                     };
                 }
             }
@@ -163,9 +258,23 @@ pub fn run(
     // Compute asserts
     if !runner_options.ignore_asserts {
         if let Some(response_spec) = &entry.response {
-            let mut other_asserts =
-                response::eval_asserts(response_spec, variables, http_response, context_dir);
+            let assert_options = AssertOptions::from(runner_options);
+            let mut other_asserts = response::eval_asserts(
+                response_spec,
+                variables,
+                http_response,
+                &assert_options,
+                context_dir,
+            );
             asserts.append(&mut other_asserts);
+            let mut soft_asserts = response::eval_soft_asserts(
+                response_spec,
+                variables,
+                http_response,
+                &assert_options,
+                context_dir,
+            );
+            asserts.append(&mut soft_asserts);
         }
     };
 
@@ -180,13 +289,17 @@ pub fn run(
         errors,
         time_in_ms,
         compressed,
+        curl_cmd,
     }
 }
 
 /// Converts a list of [`AssertResult`] to a list of [`Error`].
+///
+/// Soft asserts failures are not errors, and are ignored.
 fn asserts_to_errors(asserts: &[AssertResult]) -> Vec<Error> {
     asserts
         .iter()
+        .filter(|assert| !assert.is_soft())
         .filter_map(|assert| assert.error())
         .map(
             |Error {
@@ -196,14 +309,69 @@ fn asserts_to_errors(asserts: &[AssertResult]) -> Vec<Error> {
         .collect()
 }
 
+/// Returns the value of the `Accept-Encoding` header to send, given these `runner_options`, or
+/// `None` if no header is sent. Responses are decompressed only if a header is sent.
+fn get_accept_encoding(runner_options: &RunnerOptions) -> Option<String> {
+    match runner_options.accept_encoding.as_deref() {
+        Some("none") => None,
+        Some(encodings) => Some(encodings.to_string()),
+        None if runner_options.compressed => Some(DEFAULT_ACCEPT_ENCODING.to_string()),
+        None => None,
+    }
+}
+
+/// Returns `true` if a request with this `method` can modify the server state, and would need an
+/// idempotency key to be safely retried.
+fn is_mutating(method: &http::Method) -> bool {
+    matches!(
+        method.0.to_uppercase().as_str(),
+        "POST" | "PUT" | "PATCH" | "DELETE"
+    )
+}
+
+/// W3C Trace Context header identifying the request in a trace.
+const TRACEPARENT: &str = "traceparent";
+
+/// W3C Trace Context header conveying vendor specific trace information.
+const TRACESTATE: &str = "tracestate";
+
+/// Adds W3C Trace Context headers to `request`, unless already defined: a new `traceparent` and
+/// an optional `trace_state` `tracestate`.
+fn add_trace_context(request: &mut http::RequestSpec, trace_state: Option<&str>) {
+    if !request.headers.contains_key(TRACEPARENT) {
+        request
+            .headers
+            .push(http::Header::new(TRACEPARENT, &new_traceparent()));
+    }
+    if let Some(trace_state) = trace_state {
+        if !request.headers.contains_key(TRACESTATE) {
+            request
+                .headers
+                .push(http::Header::new(TRACESTATE, trace_state));
+        }
+    }
+}
+
+/// Returns a new `traceparent` header value, with random trace id and parent id, sampled.
+///
+/// See <https://www.w3.org/TR/trace-context/#traceparent-header>
+fn new_traceparent() -> String {
+    let trace_id = Uuid::new_v4().simple().to_string();
+    let parent_id = &Uuid::new_v4().simple().to_string()[..16];
+    format!("00-{trace_id}-{parent_id}-01")
+}
+
 impl ClientOptions {
     fn from(runner_options: &RunnerOptions, verbosity: Option<Verbosity>) -> Self {
         ClientOptions {
+            accept_encoding: get_accept_encoding(runner_options),
             aws_sigv4: runner_options.aws_sigv4.clone(),
+            body_contains: vec![],
+            buffer_size: runner_options.buffer_size,
             cacert_file: runner_options.cacert_file.clone(),
             client_cert_file: runner_options.client_cert_file.clone(),
             client_key_file: runner_options.client_key_file.clone(),
-            compressed: runner_options.compressed,
+            compressed: get_accept_encoding(runner_options).is_some(),
             connect_timeout: runner_options.connect_timeout,
             connects_to: runner_options.connects_to.clone(),
             cookie_input_file: runner_options.cookie_input_file.clone(),
@@ -211,16 +379,21 @@ impl ClientOptions {
             follow_location_trusted: runner_options.follow_location_trusted,
             http_version: runner_options.http_version,
             ip_resolve: runner_options.ip_resolve,
+            max_header_size: runner_options.max_header_size,
             max_redirect: runner_options.max_redirect,
             netrc: runner_options.netrc,
             netrc_file: runner_options.netrc_file.clone(),
             netrc_optional: runner_options.netrc_optional,
             path_as_is: runner_options.path_as_is,
             proxy: runner_options.proxy.clone(),
+            proxy_headers: runner_options.proxy_headers.clone(),
+            proxy_tunnel: runner_options.proxy_tunnel,
             no_proxy: runner_options.no_proxy.clone(),
             insecure: runner_options.insecure,
+            insecure_hosts: runner_options.insecure_hosts.clone(),
             resolves: runner_options.resolves.clone(),
             retry: runner_options.retry,
+            sni: runner_options.sni.clone(),
             ssl_no_revoke: runner_options.ssl_no_revoke,
             timeout: runner_options.timeout,
             unix_socket: runner_options.unix_socket.clone(),
@@ -231,16 +404,16 @@ impl ClientOptions {
                 Some(Verbosity::VeryVerbose) => Some(http::Verbosity::VeryVerbose),
                 _ => None,
             },
+            verify_content_length: runner_options.verify_content_length,
         }
     }
 }
 
-/// Logs this HTTP `request`.
+/// Logs this HTTP `request`, with its equivalent `curl_cmd` command line.
 fn log_request(
-    http_client: &mut http::Client,
+    http_client: &mut dyn HttpClient,
     request: &http::RequestSpec,
-    runner_options: &RunnerOptions,
-    client_options: &ClientOptions,
+    curl_cmd: &str,
     logger: &mut Logger,
 ) {
     logger.debug("");
@@ -281,11 +454,7 @@ fn log_request(
     }
     logger.debug("");
     logger.debug("Request can be run with the following curl command:");
-    let context_dir = &runner_options.context_dir;
-    let output = &runner_options.output;
-    let curl_command =
-        http_client.curl_command_line(request, context_dir, output.as_ref(), client_options);
-    logger.debug(&curl_command);
+    logger.debug(curl_cmd);
     logger.debug("");
 }
 
@@ -299,3 +468,48 @@ fn log_captures(captures: &[CaptureResult], logger: &mut Logger) {
         logger.capture(&c.name, &c.value);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::RunnerOptionsBuilder;
+
+    #[test]
+    fn test_new_traceparent() {
+        let re = regex::Regex::new("^00-[0-9a-f]{32}-[0-9a-f]{16}-01$").unwrap();
+        let traceparent = new_traceparent();
+        assert!(re.is_match(&traceparent));
+        assert_ne!(traceparent, new_traceparent());
+    }
+
+    #[test]
+    fn test_add_trace_context() {
+        let mut request = http::RequestSpec::default();
+        add_trace_context(&mut request, Some("hurl=1"));
+        assert!(request.headers.contains_key("traceparent"));
+        assert_eq!(request.headers.get("tracestate").unwrap().value, "hurl=1");
+
+        let mut request = http::RequestSpec::default();
+        request
+            .headers
+            .push(http::Header::new("Traceparent", "00-foo-bar-01"));
+        add_trace_context(&mut request, None);
+        assert_eq!(request.headers.get_all("traceparent").len(), 1);
+        assert_eq!(
+            request.headers.get("traceparent").unwrap().value,
+            "00-foo-bar-01"
+        );
+        assert!(!request.headers.contains_key("tracestate"));
+    }
+
+    #[test]
+    fn test_client_options_buffer_size() {
+        let runner_options = RunnerOptionsBuilder::new().build();
+        let client_options = ClientOptions::from(&runner_options, None);
+        assert_eq!(client_options.buffer_size, None);
+
+        let runner_options = RunnerOptionsBuilder::new().buffer_size(Some(1024)).build();
+        let client_options = ClientOptions::from(&runner_options, None);
+        assert_eq!(client_options.buffer_size, Some(1024));
+    }
+}
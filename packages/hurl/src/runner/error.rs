@@ -18,14 +18,16 @@
 use std::path::PathBuf;
 
 use hurl_core::ast::SourceInfo;
+use serde::{Deserialize, Serialize};
 
 use crate::http::{HttpError, RequestedHttpVersion};
+use crate::util::hexdump::hexdump_diff;
 
 /// Represents a single instance of a runtime error, usually triggered by running a
 /// [`hurl_core::ast::Entry`]. Running a Hurl content (see [`crate::runner::run`]) returns a list of
 /// result for each entry. Each entry result can contain a list of [`Error`]. The runtime error variant
 /// is defined in [`RunnerError`]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Error {
     pub source_info: SourceInfo,
     pub inner: RunnerError,
@@ -42,12 +44,16 @@ impl Error {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RunnerError {
     AssertBodyValueError {
         actual: String,
         expected: String,
     },
+    AssertBytesFailure {
+        actual: Vec<u8>,
+        expected: Vec<u8>,
+    },
     AssertFailure {
         actual: String,
         expected: String,
@@ -62,8 +68,17 @@ pub enum RunnerError {
     AssertVersion {
         actual: String,
     },
+    /// The received body length `actual` doesn't match the `Content-Length` header value `expected`.
+    ContentLengthMismatch {
+        expected: String,
+        actual: u64,
+    },
     CouldNotParseResponse,
     CouldNotUncompressResponse(String),
+    /// The response `status` is in one of the failing status classes of `--fail-on`.
+    FailOnStatus {
+        status: u32,
+    },
     /// I/O read error on `path`.
     FileReadAccess {
         path: PathBuf,
@@ -77,6 +92,10 @@ pub enum RunnerError {
     FilterInvalidEncoding(String),
     FilterInvalidInput(String),
     FilterMissingInput,
+    /// The cumulative size of the response headers exceeds `max_header_size` bytes.
+    HeaderSizeExceeded {
+        max_header_size: u64,
+    },
     HttpConnection(String),
     InvalidJson {
         value: String,
@@ -87,6 +106,7 @@ pub enum RunnerError {
     InvalidDecoding {
         charset: String,
     },
+    InvalidMethod(String),
     InvalidRegex,
     InvalidUrl(String),
     InvalidUrlPrefix(String),
@@ -95,6 +115,9 @@ pub enum RunnerError {
     QueryInvalidJsonpathExpression {
         value: String,
     },
+    QueryInvalidJsonpointerExpression {
+        value: String,
+    },
     QueryInvalidXpathEval,
     QueryInvalidXml,
     QueryInvalidJson,
@@ -106,6 +129,8 @@ pub enum RunnerError {
         value: String,
         expecting: String,
     },
+    /// Hurl has been built without TLS support, and this URL can't be requested.
+    TlsNotSupported(String),
     TooManyRedirect,
     UnsupportedContentEncoding(String),
     UnsupportedHttpVersion(RequestedHttpVersion),
@@ -128,22 +153,27 @@ impl hurl_core::error::Error for Error {
     fn description(&self) -> String {
         match &self.inner {
             RunnerError::AssertBodyValueError { .. } => "Assert body value".to_string(),
+            RunnerError::AssertBytesFailure { .. } => "Assert failure".to_string(),
             RunnerError::AssertFailure { .. } => "Assert failure".to_string(),
             RunnerError::AssertHeaderValueError { .. } => "Assert header value".to_string(),
             RunnerError::AssertStatus { .. } => "Assert status code".to_string(),
             RunnerError::AssertVersion { .. } => "Assert HTTP version".to_string(),
+            RunnerError::ContentLengthMismatch { .. } => "Content-Length mismatch".to_string(),
             RunnerError::CouldNotParseResponse => "HTTP connection".to_string(),
             RunnerError::CouldNotUncompressResponse(..) => "Decompression error".to_string(),
+            RunnerError::FailOnStatus { .. } => "HTTP status".to_string(),
             RunnerError::FileReadAccess { .. } => "File read access".to_string(),
             RunnerError::FileWriteAccess { .. } => "File write access".to_string(),
             RunnerError::FilterDecode { .. } => "Filter error".to_string(),
             RunnerError::FilterInvalidEncoding { .. } => "Filter error".to_string(),
             RunnerError::FilterInvalidInput { .. } => "Filter error".to_string(),
             RunnerError::FilterMissingInput => "Filter error".to_string(),
+            RunnerError::HeaderSizeExceeded { .. } => "HTTP connection".to_string(),
             RunnerError::HttpConnection { .. } => "HTTP connection".to_string(),
             RunnerError::InvalidCharset { .. } => "Invalid charset".to_string(),
             RunnerError::InvalidDecoding { .. } => "Invalid decoding".to_string(),
             RunnerError::InvalidJson { .. } => "Invalid JSON".to_string(),
+            RunnerError::InvalidMethod(..) => "Invalid method".to_string(),
             RunnerError::InvalidRegex => "Invalid regex".to_string(),
             RunnerError::InvalidUrl(..) => "Invalid URL".to_string(),
             RunnerError::InvalidUrlPrefix(..) => "Invalid URL".to_string(),
@@ -151,10 +181,14 @@ impl hurl_core::error::Error for Error {
             RunnerError::QueryHeaderNotFound => "Header not found".to_string(),
             RunnerError::QueryInvalidJson => "Invalid JSON".to_string(),
             RunnerError::QueryInvalidJsonpathExpression { .. } => "Invalid JSONPath".to_string(),
+            RunnerError::QueryInvalidJsonpointerExpression { .. } => {
+                "Invalid JSON Pointer".to_string()
+            }
             RunnerError::QueryInvalidXml => "Invalid XML".to_string(),
             RunnerError::QueryInvalidXpathEval => "Invalid XPath expression".to_string(),
             RunnerError::TemplateVariableInvalidType { .. } => "Invalid variable type".to_string(),
             RunnerError::TemplateVariableNotDefined { .. } => "Undefined variable".to_string(),
+            RunnerError::TlsNotSupported(..) => "TLS not supported".to_string(),
             RunnerError::TooManyRedirect => "HTTP connection".to_string(),
             RunnerError::UnauthorizedFileAccess { .. } => "Unauthorized file access".to_string(),
             RunnerError::UnrenderableVariable { .. } => "Unrenderable variable".to_string(),
@@ -168,6 +202,7 @@ impl hurl_core::error::Error for Error {
             RunnerError::AssertBodyValueError { actual, .. } => {
                 format!("actual value is <{actual}>")
             }
+            RunnerError::AssertBytesFailure { actual, expected } => hexdump_diff(actual, expected),
             RunnerError::AssertFailure {
                 actual,
                 expected,
@@ -186,10 +221,16 @@ impl hurl_core::error::Error for Error {
             }
             RunnerError::AssertStatus { actual, .. } => format!("actual value is <{actual}>"),
             RunnerError::AssertVersion { actual, .. } => format!("actual value is <{actual}>"),
+            RunnerError::ContentLengthMismatch { expected, actual } => {
+                format!("received {actual} bytes of body, Content-Length header is <{expected}>")
+            }
             RunnerError::CouldNotParseResponse => "could not parse response".to_string(),
             RunnerError::CouldNotUncompressResponse(algorithm) => {
                 format!("could not uncompress response with {algorithm}")
             }
+            RunnerError::FailOnStatus { status } => {
+                format!("response status <{status}> is in a failing status class")
+            }
             RunnerError::FileReadAccess { path } => {
                 format!("file {} can not be read", path.to_string_lossy())
             }
@@ -206,6 +247,9 @@ impl hurl_core::error::Error for Error {
                 format!("invalid filter input: {message}")
             }
             RunnerError::FilterMissingInput => "missing value to apply filter".to_string(),
+            RunnerError::HeaderSizeExceeded { max_header_size } => {
+                format!("response headers exceed the maximum size of {max_header_size} bytes")
+            }
             RunnerError::HttpConnection(message) => message.to_string(),
             RunnerError::InvalidCharset { charset } => {
                 format!("the charset '{charset}' is not valid")
@@ -216,6 +260,9 @@ impl hurl_core::error::Error for Error {
             RunnerError::InvalidJson { value } => {
                 format!("actual value is <{value}>")
             }
+            RunnerError::InvalidMethod(method) => {
+                format!("method <{method}> is not valid, it must be made of uppercase letters")
+            }
             RunnerError::InvalidRegex => "regex expression is not valid".to_string(),
             RunnerError::InvalidUrl(url) => format!("invalid URL <{url}>"),
             RunnerError::InvalidUrlPrefix(url) => {
@@ -229,6 +276,9 @@ impl hurl_core::error::Error for Error {
             RunnerError::QueryInvalidJsonpathExpression { value } => {
                 format!("the JSONPath expression '{value}' is not valid")
             }
+            RunnerError::QueryInvalidJsonpointerExpression { value } => {
+                format!("the JSON Pointer expression '{value}' is not valid")
+            }
             RunnerError::QueryInvalidXml => "the HTTP response is not a valid XML".to_string(),
             RunnerError::QueryInvalidXpathEval => "the XPath expression is not valid".to_string(),
             RunnerError::TemplateVariableInvalidType {
@@ -239,6 +289,9 @@ impl hurl_core::error::Error for Error {
             RunnerError::TemplateVariableNotDefined { name } => {
                 format!("you must set the variable {name}")
             }
+            RunnerError::TlsNotSupported(url) => {
+                format!("TLS support not compiled in, URL <{url}> can not be requested")
+            }
             RunnerError::TooManyRedirect => "too many redirect".to_string(),
             RunnerError::UnauthorizedFileAccess { path } => {
                 format!(
@@ -263,10 +316,16 @@ impl From<HttpError> for RunnerError {
     /// Converts a HttpError to a RunnerError.
     fn from(item: HttpError) -> Self {
         match item {
+            HttpError::ContentLengthMismatch { expected, actual } => {
+                RunnerError::ContentLengthMismatch { expected, actual }
+            }
             HttpError::CouldNotParseResponse => RunnerError::CouldNotParseResponse,
             HttpError::CouldNotUncompressResponse { description } => {
                 RunnerError::CouldNotUncompressResponse(description)
             }
+            HttpError::HeaderSizeExceeded { max_header_size } => {
+                RunnerError::HeaderSizeExceeded { max_header_size }
+            }
             HttpError::InvalidCharset { charset } => RunnerError::InvalidCharset { charset },
             HttpError::InvalidDecoding { charset } => RunnerError::InvalidDecoding { charset },
             HttpError::InvalidUrl(url) => RunnerError::InvalidUrl(url),
@@ -280,9 +339,13 @@ impl From<HttpError> for RunnerError {
             } => RunnerError::HttpConnection(format!(
                 "Option {option} requires libcurl version {minimum_version} or higher"
             )),
+            HttpError::NoRecordedResponse { method, url } => RunnerError::HttpConnection(format!(
+                "no recorded response for {method} {url} in the cassette"
+            )),
             HttpError::StatuslineIsMissing => {
                 RunnerError::HttpConnection("status line is missing".to_string())
             }
+            HttpError::TlsNotSupported(url) => RunnerError::TlsNotSupported(url),
             HttpError::TooManyRedirect => RunnerError::TooManyRedirect,
             HttpError::UnsupportedContentEncoding { description } => {
                 RunnerError::UnsupportedContentEncoding(description)
@@ -18,6 +18,7 @@
 
 use std::collections::HashMap;
 
+use chrono::{FixedOffset, Offset, Utc};
 use hurl_core::ast::{Filter, FilterValue};
 
 use crate::runner::filter::days_after_now::eval_days_after_now;
@@ -27,6 +28,8 @@ use crate::runner::filter::format::eval_format;
 use crate::runner::filter::html_escape::eval_html_escape;
 use crate::runner::filter::html_unescape::eval_html_unescape;
 use crate::runner::filter::jsonpath::eval_jsonpath;
+use crate::runner::filter::jsonpointer::eval_jsonpointer;
+use crate::runner::filter::line_count::eval_line_count;
 use crate::runner::filter::nth::eval_nth;
 use crate::runner::filter::regex::eval_regex;
 use crate::runner::filter::replace::eval_replace;
@@ -41,35 +44,70 @@ use crate::runner::{Error, RunnerError, Value};
 
 use super::count::eval_count;
 
-/// Apply successive `filter` to an input `value`.
-/// Specify whether they are executed  `in_assert` or not.
+/// The context in which filters are evaluated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FilterContext {
+    /// Whether the filters are executed in an assert or not.
+    pub in_assert: bool,
+    /// The timezone offset in which dates are parsed and formatted.
+    pub timezone: FixedOffset,
+}
+
+impl Default for FilterContext {
+    fn default() -> Self {
+        FilterContext {
+            in_assert: false,
+            timezone: Utc.fix(),
+        }
+    }
+}
+
+/// Apply successive `filter` to an input `value`, in a given `context`.
 pub fn eval_filters(
     filters: &Vec<Filter>,
     value: &Value,
     variables: &HashMap<String, Value>,
-    in_assert: bool,
+    context: &FilterContext,
 ) -> Result<Option<Value>, Error> {
     let mut value = Some(value.clone());
     for filter in filters {
         value = if let Some(value) = value {
-            eval_filter(filter, &value, variables, in_assert)?
+            eval_filter_in_context(filter, &value, variables, context)?
         } else {
             return Err(Error::new(
                 filter.source_info,
                 RunnerError::FilterMissingInput,
-                in_assert,
+                context.in_assert,
             ));
         }
     }
     Ok(value)
 }
 
+/// Apply a `filter` to an input `value`, in the default context (dates in UTC).
+#[cfg(test)]
 pub fn eval_filter(
     filter: &Filter,
     value: &Value,
     variables: &HashMap<String, Value>,
     in_assert: bool,
 ) -> Result<Option<Value>, Error> {
+    let context = FilterContext {
+        in_assert,
+        ..FilterContext::default()
+    };
+    eval_filter_in_context(filter, value, variables, &context)
+}
+
+/// Apply a `filter` to an input `value`, in a given `context`.
+pub fn eval_filter_in_context(
+    filter: &Filter,
+    value: &Value,
+    variables: &HashMap<String, Value>,
+    context: &FilterContext,
+) -> Result<Option<Value>, Error> {
+    let in_assert = context.in_assert;
+    let timezone = context.timezone;
     match &filter.value {
         FilterValue::Count => eval_count(value, filter.source_info, in_assert),
         FilterValue::DaysAfterNow => eval_days_after_now(value, filter.source_info, in_assert),
@@ -77,14 +115,23 @@ pub fn eval_filter(
         FilterValue::Decode { encoding, .. } => {
             eval_decode(value, encoding, variables, filter.source_info, in_assert)
         }
-        FilterValue::Format { fmt, .. } => {
-            eval_format(value, fmt, variables, filter.source_info, in_assert)
-        }
+        FilterValue::Format { fmt, .. } => eval_format(
+            value,
+            fmt,
+            variables,
+            filter.source_info,
+            in_assert,
+            timezone,
+        ),
         FilterValue::HtmlEscape => eval_html_escape(value, filter.source_info, in_assert),
         FilterValue::HtmlUnescape => eval_html_unescape(value, filter.source_info, in_assert),
         FilterValue::JsonPath { expr, .. } => {
             eval_jsonpath(value, expr, variables, filter.source_info, in_assert)
         }
+        FilterValue::JsonPointer { expr, .. } => {
+            eval_jsonpointer(value, expr, variables, filter.source_info, in_assert)
+        }
+        FilterValue::LineCount => eval_line_count(value, filter.source_info, in_assert),
         FilterValue::Regex {
             value: regex_value, ..
         } => eval_regex(value, regex_value, variables, filter.source_info, in_assert),
@@ -104,9 +151,14 @@ pub fn eval_filter(
         FilterValue::Split { sep, .. } => {
             eval_split(value, variables, filter.source_info, in_assert, sep)
         }
-        FilterValue::ToDate { fmt, .. } => {
-            eval_to_date(value, fmt, variables, filter.source_info, in_assert)
-        }
+        FilterValue::ToDate { fmt, .. } => eval_to_date(
+            value,
+            fmt,
+            variables,
+            filter.source_info,
+            in_assert,
+            timezone,
+        ),
         FilterValue::ToInt => eval_to_int(value, filter.source_info, in_assert),
         FilterValue::UrlDecode => eval_url_decode(value, filter.source_info, in_assert),
         FilterValue::UrlEncode => eval_url_encode(value, filter.source_info, in_assert),
@@ -117,7 +169,7 @@ pub fn eval_filter(
 }
 #[cfg(test)]
 pub mod tests {
-    use crate::runner::filter::eval::eval_filters;
+    use crate::runner::filter::eval::{eval_filters, FilterContext};
     use crate::runner::{Number, Value};
     use hurl_core::ast::{Filter, FilterValue, Pos, SourceInfo};
     use std::collections::HashMap;
@@ -138,7 +190,7 @@ pub mod tests {
                     Value::Number(Number::Integer(2)),
                 ]),
                 &variables,
-                false,
+                &FilterContext::default(),
             )
             .unwrap()
             .unwrap(),
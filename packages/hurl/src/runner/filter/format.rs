@@ -17,22 +17,26 @@
  */
 use std::collections::HashMap;
 
+use chrono::FixedOffset;
 use hurl_core::ast::{SourceInfo, Template};
 
 use crate::runner::template::eval_template;
 use crate::runner::{Error, RunnerError, Value};
 
+/// Formats a date `value` with the format `fmt`, the date being displayed in the `timezone` offset.
 pub fn eval_format(
     value: &Value,
     fmt: &Template,
     variables: &HashMap<String, Value>,
     source_info: SourceInfo,
     assert: bool,
+    timezone: FixedOffset,
 ) -> Result<Option<Value>, Error> {
     let fmt = eval_template(fmt, variables)?;
 
     match value {
         Value::Date(value) => {
+            let value = value.with_timezone(&timezone);
             let formatted = format!("{}", value.format(fmt.as_str()));
             Ok(Some(Value::String(formatted)))
         }
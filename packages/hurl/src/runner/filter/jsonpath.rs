@@ -83,7 +83,7 @@ pub mod tests {
     use std::collections::HashMap;
 
     use crate::runner::filter::eval::eval_filter;
-    use crate::runner::Value;
+    use crate::runner::{RunnerError, Value};
 
     #[test]
     pub fn eval_filter_jsonpath() {
@@ -118,4 +118,44 @@ pub mod tests {
             Value::String("Hello".to_string())
         );
     }
+
+    #[test]
+    pub fn eval_filter_jsonpath_invalid_expression() {
+        // jsonpath "$.[x"
+        let variables = HashMap::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 19), Pos::new(1, 34)),
+            value: FilterValue::JsonPath {
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$.[x".to_string(),
+                        encoded: "$.[x".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 28), Pos::new(1, 34)),
+                },
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 27), Pos::new(1, 28)),
+                },
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::String(r#"{"message":"Hello"}"#.to_string()),
+            &variables,
+            true,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 28), Pos::new(1, 34))
+        );
+        assert_eq!(
+            error.inner,
+            RunnerError::QueryInvalidJsonpathExpression {
+                value: "$.[x".to_string()
+            }
+        );
+    }
 }
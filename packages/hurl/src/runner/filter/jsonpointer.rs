@@ -0,0 +1,262 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::template::eval_template;
+use crate::runner::{Error, RunnerError, Value};
+
+pub fn eval_jsonpointer(
+    value: &Value,
+    expr: &Template,
+    variables: &HashMap<String, Value>,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, Error> {
+    match value {
+        Value::String(json) => eval_jsonpointer_string(json, expr, variables, source_info),
+        v => {
+            let inner = RunnerError::FilterInvalidInput(v._type());
+            Err(Error::new(source_info, inner, assert))
+        }
+    }
+}
+
+pub fn eval_jsonpointer_string(
+    json: &str,
+    expr: &Template,
+    variables: &HashMap<String, Value>,
+    source_info: SourceInfo,
+) -> Result<Option<Value>, Error> {
+    let value = eval_template(expr, variables)?;
+    let expr_source_info = &expr.source_info;
+    let tokens = match parse(value.as_str()) {
+        Some(tokens) => tokens,
+        None => {
+            let inner = RunnerError::QueryInvalidJsonpointerExpression { value };
+            return Err(Error::new(*expr_source_info, inner, false));
+        }
+    };
+    let value = match serde_json::from_str(json) {
+        Err(_) => {
+            return Err(Error::new(
+                source_info,
+                RunnerError::QueryInvalidJson,
+                false,
+            ));
+        }
+        Ok(v) => v,
+    };
+    Ok(resolve(&value, &tokens).map(Value::from_json))
+}
+
+/// Parses a JSON Pointer `pointer` (see <https://www.rfc-editor.org/rfc/rfc6901>) and returns its
+/// unescaped reference tokens, or `None` if `pointer` is not valid.
+///
+/// The empty pointer `""` references the whole document, and `~1` and `~0` are unescaped
+/// to `/` and `~` respectively.
+fn parse(pointer: &str) -> Option<Vec<String>> {
+    if pointer.is_empty() {
+        return Some(vec![]);
+    }
+    let pointer = pointer.strip_prefix('/')?;
+    pointer.split('/').map(unescape).collect()
+}
+
+/// Unescapes a reference `token`, returns `None` if `~` is not followed by `0` or `1`.
+fn unescape(token: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(token.len());
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        if c == '~' {
+            match chars.next() {
+                Some('0') => unescaped.push('~'),
+                Some('1') => unescaped.push('/'),
+                _ => return None,
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Some(unescaped)
+}
+
+/// Returns the value of `json` referenced by the reference `tokens`, if any.
+fn resolve<'a>(json: &'a serde_json::Value, tokens: &[String]) -> Option<&'a serde_json::Value> {
+    tokens
+        .iter()
+        .try_fold(json, |current, token| match current {
+            serde_json::Value::Object(map) => map.get(token),
+            serde_json::Value::Array(array) => {
+                // Array indices are decimal numbers without leading zeros.
+                if token.is_empty()
+                    || (token.len() > 1 && token.starts_with('0'))
+                    || !token.bytes().all(|b| b.is_ascii_digit())
+                {
+                    return None;
+                }
+                array.get(token.parse::<usize>().ok()?)
+            }
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{
+        Filter, FilterValue, Pos, SourceInfo, Template, TemplateElement, Whitespace,
+    };
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerError, Value};
+
+    fn new_jsonpointer_filter(expr: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonPointer {
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: expr.to_string(),
+                        encoded: expr.to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 20)),
+                },
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 14)),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(""), Some(vec![]));
+        assert_eq!(parse("/"), Some(vec![String::new()]));
+        assert_eq!(
+            parse("/data/0/id"),
+            Some(vec!["data".to_string(), "0".to_string(), "id".to_string()])
+        );
+        assert_eq!(
+            parse("/a~1b/m~0n/~01"),
+            Some(vec!["a/b".to_string(), "m~n".to_string(), "~1".to_string()])
+        );
+        assert_eq!(parse("data"), None);
+        assert_eq!(parse("/a~2b"), None);
+        assert_eq!(parse("/a~"), None);
+    }
+
+    #[test]
+    fn test_resolve() {
+        // Example from <https://www.rfc-editor.org/rfc/rfc6901#section-5>
+        let json = serde_json::json!({
+            "foo": ["bar", "baz"],
+            "": 0,
+            "a/b": 1,
+            "c%d": 2,
+            "e^f": 3,
+            "g|h": 4,
+            "i\\j": 5,
+            "k\"l": 6,
+            " ": 7,
+            "m~n": 8
+        });
+        let eval = |pointer: &str| resolve(&json, &parse(pointer).unwrap()).cloned();
+        assert_eq!(eval(""), Some(json.clone()));
+        assert_eq!(eval("/foo"), Some(serde_json::json!(["bar", "baz"])));
+        assert_eq!(eval("/foo/0"), Some(serde_json::json!("bar")));
+        assert_eq!(eval("/"), Some(serde_json::json!(0)));
+        assert_eq!(eval("/a~1b"), Some(serde_json::json!(1)));
+        assert_eq!(eval("/c%d"), Some(serde_json::json!(2)));
+        assert_eq!(eval("/e^f"), Some(serde_json::json!(3)));
+        assert_eq!(eval("/g|h"), Some(serde_json::json!(4)));
+        assert_eq!(eval("/i\\j"), Some(serde_json::json!(5)));
+        assert_eq!(eval("/k\"l"), Some(serde_json::json!(6)));
+        assert_eq!(eval("/ "), Some(serde_json::json!(7)));
+        assert_eq!(eval("/m~0n"), Some(serde_json::json!(8)));
+
+        assert_eq!(eval("/foo/2"), None);
+        assert_eq!(eval("/foo/01"), None);
+        assert_eq!(eval("/foo/-"), None);
+        assert_eq!(eval("/foo/0/bar"), None);
+        assert_eq!(eval("/unknown"), None);
+    }
+
+    #[test]
+    pub fn eval_filter_jsonpointer() {
+        let variables = HashMap::new();
+        let json = r#"{"data": [{"id": 1, "tags": {"a/b": "x", "m~n": "y"}}]}"#;
+
+        let filter = new_jsonpointer_filter("/data/0/id");
+        assert_eq!(
+            eval_filter(&filter, &Value::String(json.to_string()), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(1))
+        );
+
+        let filter = new_jsonpointer_filter("/data/0/tags/a~1b");
+        assert_eq!(
+            eval_filter(&filter, &Value::String(json.to_string()), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String("x".to_string())
+        );
+
+        let filter = new_jsonpointer_filter("/data/0/tags/m~0n");
+        assert_eq!(
+            eval_filter(&filter, &Value::String(json.to_string()), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String("y".to_string())
+        );
+
+        let filter = new_jsonpointer_filter("/data/1");
+        assert_eq!(
+            eval_filter(&filter, &Value::String(json.to_string()), &variables, false).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_jsonpointer_invalid_expression() {
+        let variables = HashMap::new();
+        let filter = new_jsonpointer_filter("data");
+        let error = eval_filter(
+            &filter,
+            &Value::String(r#"{"data":1}"#.to_string()),
+            &variables,
+            true,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 14), Pos::new(1, 20))
+        );
+        assert_eq!(
+            error.inner,
+            RunnerError::QueryInvalidJsonpointerExpression {
+                value: "data".to_string()
+            }
+        );
+    }
+}
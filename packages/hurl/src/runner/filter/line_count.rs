@@ -0,0 +1,94 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Error, Number, RunnerError, Value};
+
+/// Counts the lines of a text value. A trailing newline terminates the last line and doesn't
+/// start a new one: `"a\nb"` and `"a\nb\n"` both have 2 lines. Both `\n` and `\r\n` are
+/// line terminators, and an empty string has no line.
+pub fn eval_line_count(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, Error> {
+    match value {
+        Value::String(value) => {
+            let count = value.lines().count();
+            Ok(Some(Value::Number(Number::Integer(count as i64))))
+        }
+        v => {
+            let inner = RunnerError::FilterInvalidInput(v._type());
+            Err(Error::new(source_info, inner, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::runner::filter::eval::eval_filter;
+    use hurl_core::ast::{Filter, FilterValue, Pos, SourceInfo};
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    pub fn eval_filter_line_count() {
+        let variables = HashMap::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+            value: FilterValue::LineCount,
+        };
+
+        let tests = [
+            ("", 0),
+            ("\n", 1),
+            ("line1", 1),
+            ("line1\nline2\nline3", 3),
+            ("line1\nline2\nline3\n", 3),
+            ("line1\r\nline2\r\n", 2),
+            ("line1\n\nline3\n\n", 4),
+        ];
+        for (input, count) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::Number(Number::Integer(*count))
+            );
+        }
+
+        let error = eval_filter(&filter, &Value::Bytes(vec![1, 2]), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10))
+        );
+        assert_eq!(
+            error.inner,
+            RunnerError::FilterInvalidInput("bytes".to_string())
+        );
+    }
+}
@@ -16,8 +16,9 @@
  *
  */
 
-pub use eval::eval_filters;
+pub use eval::{eval_filters, FilterContext};
 pub use jsonpath::eval_jsonpath_string;
+pub use jsonpointer::eval_jsonpointer_string;
 pub use xpath::eval_xpath_string;
 
 mod count;
@@ -29,6 +30,8 @@ mod format;
 mod html_escape;
 mod html_unescape;
 mod jsonpath;
+mod jsonpointer;
+mod line_count;
 mod nth;
 mod regex;
 mod replace;
@@ -17,32 +17,43 @@
  */
 use std::collections::HashMap;
 
-use chrono::NaiveDateTime;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
 
 use hurl_core::ast::{SourceInfo, Template};
 
 use crate::runner::template::eval_template;
 use crate::runner::{Error, RunnerError, Value};
 
+/// Parses a string `value` to a date with the format `fmt`.
+///
+/// If `fmt` parses an offset (with `%z` or `%+` for instance), the date is in this offset.
+/// Otherwise, the parsed date has no timezone information and is interpreted as a date in the
+/// `timezone` offset.
 pub fn eval_to_date(
     value: &Value,
     fmt: &Template,
     variables: &HashMap<String, Value>,
     source_info: SourceInfo,
     assert: bool,
+    timezone: FixedOffset,
 ) -> Result<Option<Value>, Error> {
     let fmt = eval_template(fmt, variables)?;
 
     match value {
-        Value::String(v) => match NaiveDateTime::parse_from_str(v, fmt.as_str()) {
-            Ok(v) => Ok(Some(Value::Date(
-                v.and_local_timezone(chrono::Utc).unwrap(),
-            ))),
-            Err(_) => {
-                let inner = RunnerError::FilterInvalidInput(value.display());
-                Err(Error::new(source_info, inner, assert))
+        Value::String(v) => {
+            if let Ok(v) = DateTime::parse_from_str(v, fmt.as_str()) {
+                return Ok(Some(Value::Date(v.with_timezone(&Utc))));
             }
-        },
+            match NaiveDateTime::parse_from_str(v, fmt.as_str()) {
+                Ok(v) => Ok(Some(Value::Date(
+                    v.and_local_timezone(timezone).unwrap().with_timezone(&Utc),
+                ))),
+                Err(_) => {
+                    let inner = RunnerError::FilterInvalidInput(value.display());
+                    Err(Error::new(source_info, inner, assert))
+                }
+            }
+        }
         v => {
             let inner = RunnerError::FilterInvalidInput(v.display());
             Err(Error::new(source_info, inner, assert))
@@ -53,9 +64,9 @@ pub fn eval_to_date(
 #[cfg(test)]
 pub mod tests {
 
-    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::filter::eval::{eval_filter, eval_filter_in_context, FilterContext};
     use crate::runner::Value;
-    use chrono::{DateTime, NaiveDate, Utc};
+    use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
     use hurl_core::ast::{
         Filter, FilterValue, Pos, SourceInfo, Template, TemplateElement, Whitespace,
     };
@@ -135,4 +146,111 @@ pub mod tests {
             Value::Date(datetime_utc)
         );
     }
+
+    #[test]
+    pub fn eval_filter_to_date_timezone() {
+        let variables = HashMap::new();
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ToDate {
+                fmt: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "%Y-%m-%d %H:%M:%S".to_string(),
+                        encoded: "%Y-%m-%d %H:%M:%S".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        let value = Value::String("2024-01-02 10:00:00".to_string());
+
+        let naive_datetime_utc = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let datetime_utc = DateTime::<Utc>::from_naive_utc_and_offset(naive_datetime_utc, Utc);
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::Date(datetime_utc)
+        );
+
+        // The same local date in +02:00 is two hours earlier in UTC.
+        let naive_datetime_utc = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let datetime_utc = DateTime::<Utc>::from_naive_utc_and_offset(naive_datetime_utc, Utc);
+        let context = FilterContext {
+            in_assert: false,
+            timezone: FixedOffset::east_opt(2 * 3600).unwrap(),
+        };
+        assert_eq!(
+            eval_filter_in_context(&filter, &value, &variables, &context)
+                .unwrap()
+                .unwrap(),
+            Value::Date(datetime_utc)
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_to_date_with_offset_ignores_timezone() {
+        let variables = HashMap::new();
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ToDate {
+                fmt: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "%Y-%m-%d %H:%M:%S %z".to_string(),
+                        encoded: "%Y-%m-%d %H:%M:%S %z".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        let context = FilterContext {
+            in_assert: false,
+            timezone: FixedOffset::east_opt(2 * 3600).unwrap(),
+        };
+
+        // The explicit offset of the date wins over the timezone.
+        let naive_datetime_utc = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let datetime_utc = DateTime::<Utc>::from_naive_utc_and_offset(naive_datetime_utc, Utc);
+        let value = Value::String("2024-01-02 10:00:00 +0000".to_string());
+        assert_eq!(
+            eval_filter_in_context(&filter, &value, &variables, &context)
+                .unwrap()
+                .unwrap(),
+            Value::Date(datetime_utc)
+        );
+
+        let naive_datetime_utc = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .and_hms_opt(15, 0, 0)
+            .unwrap();
+        let datetime_utc = DateTime::<Utc>::from_naive_utc_and_offset(naive_datetime_utc, Utc);
+        let value = Value::String("2024-01-02 10:00:00 -0500".to_string());
+        assert_eq!(
+            eval_filter_in_context(&filter, &value, &variables, &context)
+                .unwrap()
+                .unwrap(),
+            Value::Date(datetime_utc)
+        );
+    }
 }
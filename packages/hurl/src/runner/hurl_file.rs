@@ -24,12 +24,16 @@ use hurl_core::ast::VersionValue::VersionAnyLegacy;
 use hurl_core::ast::{Body, Bytes, Entry, MultilineString, Request, Response, Retry};
 use hurl_core::error::Error;
 use hurl_core::parser;
+use uuid::Uuid;
 
-use crate::http::{Call, Client};
+use crate::http::{Call, Client, HttpClient};
+use crate::runner::assert::AssertOptions;
 use crate::runner::event::EventListener;
 use crate::runner::progress::{Mode, SeqProgress};
 use crate::runner::runner_options::RunnerOptions;
-use crate::runner::{entry, options, EntryResult, HurlResult, Value};
+use crate::runner::{
+    entry, options, order, AssertFile, EntryResult, HurlResult, RunnerError, Value,
+};
 use crate::util::logger::{ErrorFormat, Logger, LoggerOptions};
 use crate::util::term::{Stderr, Stdout, WriteMode};
 
@@ -80,6 +84,27 @@ pub fn run(
     runner_options: &RunnerOptions,
     variables: &HashMap<String, Value>,
     logger_options: &LoggerOptions,
+) -> Result<HurlResult, String> {
+    let mut http_client = Client::new();
+    run_with_client(
+        content,
+        &mut http_client,
+        runner_options,
+        variables,
+        logger_options,
+    )
+}
+
+/// Runs a Hurl `content` with `http_client` and returns a [`HurlResult`] upon completion.
+///
+/// Contrary to [`run`] which uses the libcurl HTTP client, requests are executed by `http_client`:
+/// it can be used to run a Hurl file against an alternative HTTP engine or an in-memory backend.
+pub fn run_with_client(
+    content: &str,
+    http_client: &mut dyn HttpClient,
+    runner_options: &RunnerOptions,
+    variables: &HashMap<String, Value>,
+    logger_options: &LoggerOptions,
 ) -> Result<HurlResult, String> {
     // In this method, we run Hurl content sequentially. Standard output and standard error messages
     // are written immediately (in parallel mode, we'll use buffered standard output and error).
@@ -109,18 +134,21 @@ pub fn run(
     progress.print_test_start(&mut logger.stderr);
 
     // Try to parse the content
-    let hurl_file = parser::parse_hurl_file(content);
+    let hurl_file = parser::parse_hurl_file_with_recovery(content);
     let hurl_file = match hurl_file {
         Ok(h) => h,
-        Err(e) => {
-            logger.error_parsing_rich(content, &e);
-            return Err(e.description());
+        Err(errors) => {
+            for e in &errors {
+                logger.error_parsing_rich(content, e);
+            }
+            return Err(errors[0].description());
         }
     };
 
     // Now, we have a syntactically correct HurlFile instance, we can run it.
     let result = run_entries(
         &hurl_file.entries,
+        http_client,
         content,
         runner_options,
         variables,
@@ -138,14 +166,16 @@ pub fn run(
     Ok(result)
 }
 
-/// Runs a list of `entries` and returns a [`HurlResult`] upon completion.
+/// Runs a list of `entries` with `http_client` and returns a [`HurlResult`] upon completion.
 ///
 /// `content` is the original source content, used to construct `entries`. It is used to construct
 /// rich error messages with annotated source code.
 /// New entry run events are reported to `progress` and are usually used to display a progress bar
 /// in test mode.
+#[allow(clippy::too_many_arguments)]
 pub fn run_entries(
     entries: &[Entry],
+    http_client: &mut dyn HttpClient,
     content: &str,
     runner_options: &RunnerOptions,
     variables: &HashMap<String, Value>,
@@ -153,12 +183,25 @@ pub fn run_entries(
     listener: &dyn EventListener,
     logger: &mut Logger,
 ) -> HurlResult {
-    let mut http_client = Client::new();
     let mut entries_result = vec![];
     let mut variables = variables.clone();
-    let mut entry_index = runner_options.from_entry.unwrap_or(1);
+    let from_entry = runner_options.from_entry.unwrap_or(1);
     let n = runner_options.to_entry.unwrap_or(entries.len());
+    let order = if runner_options.randomize_order {
+        order::shuffle_entries(entries, content, from_entry, n, runner_options.seed)
+    } else {
+        (from_entry..=n).collect()
+    };
+    // `position` is the position of the entry to execute in `order`.
+    let mut position = 0;
     let mut retry_count = 1;
+    let mut retry_connect_count = 1;
+    // The idempotency key of the current entry, kept between retries of this entry.
+    let mut idempotency_key = None;
+    // The variables to set from a given entry, not yet set: they're set once, before running
+    // their entry (or the first entry after it if their entry is not run).
+    let mut set_at = runner_options.set_at.clone();
+    set_at.sort_by_key(|(index, _, _)| *index);
     let default_verbosity = logger.verbosity;
     let start = Instant::now();
     let timestamp = Utc::now().timestamp();
@@ -166,15 +209,24 @@ pub fn run_entries(
     log_run_info(entries, runner_options, &variables, logger);
 
     // Main loop processing each entry.
-    // The `entry_index` is not always incremented of each loop tick: an entry can be retried upon
+    // The `position` is not always incremented of each loop tick: an entry can be retried upon
     // errors for instance. Each entry is executed with options that are computed from the global
     // runner options and the "overridden" request options.
     loop {
-        if entry_index > n {
+        if position >= order.len() {
             break;
         }
+        let entry_index = order[position];
         let entry = &entries[entry_index - 1];
 
+        set_at.retain(|(index, name, value)| {
+            if *index > entry_index {
+                return true;
+            }
+            variables.insert(name.clone(), value.clone());
+            false
+        });
+
         if let Some(pre_entry) = runner_options.pre_entry {
             let exit = pre_entry(entry.clone());
             if exit {
@@ -198,7 +250,11 @@ pub fn run_entries(
 
         warn_deprecated(entry, logger);
 
-        listener.on_running(entry_index - 1, n, &mut logger.stderr);
+        listener.on_running(from_entry - 1 + position, n, &mut logger.stderr);
+
+        if runner_options.idempotency_key_header.is_some() && idempotency_key.is_none() {
+            idempotency_key = Some(Uuid::new_v4().to_string());
+        }
 
         // The real execution of the entry happens here, with the overridden entry options.
         let options = options::get_entry_options(entry, runner_options, &mut variables, logger);
@@ -213,7 +269,8 @@ pub fn run_entries(
                 if options.skip {
                     logger.debug("");
                     logger.debug_important(&format!("Entry {entry_index} has been skipped"));
-                    entry_index += 1;
+                    position += 1;
+                    idempotency_key = None;
                     continue;
                 }
 
@@ -230,9 +287,10 @@ pub fn run_entries(
                 entry::run(
                     entry,
                     entry_index,
-                    &mut http_client,
+                    http_client,
                     &mut variables,
                     options,
+                    idempotency_key.as_deref(),
                     logger,
                 )
             }
@@ -240,10 +298,26 @@ pub fn run_entries(
 
         // Check if we need to retry.
         let has_error = !entry_result.errors.is_empty();
-        let (retry_opts, retry_interval) = match &options {
-            Ok(options) => (options.retry, options.retry_interval),
-            Err(_) => (runner_options.retry, runner_options.retry_interval),
+        let (retry_opts, retry_connect_opts, retry_interval) = match &options {
+            Ok(options) => (options.retry, options.retry_connect, options.retry_interval),
+            Err(_) => (
+                runner_options.retry,
+                runner_options.retry_connect,
+                runner_options.retry_interval,
+            ),
         };
+        // Connection errors can be retried independently of the other errors (asserts, captures
+        // etc...) with `retry_connect`.
+        let has_connect_error = entry_result
+            .errors
+            .iter()
+            .any(|e| matches!(e.inner, RunnerError::HttpConnection(_)));
+        let retry_connect = has_connect_error
+            && match retry_connect_opts {
+                Retry::None => false,
+                Retry::Finite(r) => retry_connect_count <= r,
+                Retry::Infinite => true,
+            };
         // The retry threshold can only reached with a finite positive number of retries
         let retry_max_reached = if let Retry::Finite(r) = retry_opts {
             retry_count > r
@@ -254,15 +328,25 @@ pub fn run_entries(
         // error so any potential error is the last thing displayed to the user.
         // If `retry_max_reached` is not true (for instance `retry`is true, or there is no error
         // we first log the error and a potential warning about retrying.
-        if retry_max_reached {
+        if retry_max_reached && !retry_connect {
             logger.debug_important("Retry max count reached, no more retry");
             logger.debug("");
         }
 
         // We logs eventual errors, only if we're not retrying the current entry...
-        let retry = !matches!(retry_opts, Retry::None) && !retry_max_reached && has_error;
+        let retry = retry_connect
+            || (!matches!(retry_opts, Retry::None) && !retry_max_reached && has_error);
+        if !retry && runner_options.explain {
+            log_explain(&entry_result, content, logger);
+        }
         if has_error {
             log_errors(&entry_result, content, retry, logger);
+            if !retry && runner_options.print_curl_on_failure {
+                log_curl_command(&entry_result, logger);
+            }
+        }
+        if !retry {
+            log_warnings(&entry_result, content, logger);
         }
 
         // When --output is overridden on a request level, we output the HTTP response only if the
@@ -293,11 +377,20 @@ pub fn run_entries(
 
         if retry {
             let delay = retry_interval.as_millis();
+            let count = if retry_connect {
+                retry_connect_count
+            } else {
+                retry_count
+            };
             logger.debug("");
             logger.debug_important(&format!(
-                "Retry entry {entry_index} (x{retry_count} pause {delay} ms)"
+                "Retry entry {entry_index} (x{count} pause {delay} ms)"
             ));
-            retry_count += 1;
+            if retry_connect {
+                retry_connect_count += 1;
+            } else {
+                retry_count += 1;
+            }
             // If we retry the entry, we do not want to display a 'blank' progress bar during the
             // sleep delay. During the pause, we artificially show the previously erased progress
             // line.
@@ -316,8 +409,25 @@ pub fn run_entries(
         }
 
         // We pass to the next entry
-        entry_index += 1;
+        position += 1;
         retry_count = 1;
+        retry_connect_count = 1;
+        idempotency_key = None;
+    }
+
+    // Asserts of an external assert file are evaluated on the last response of the run.
+    if let Some(assert_file) = &runner_options.assert_file {
+        if !runner_options.ignore_asserts {
+            if let Some(entry_result) = entries_result.last_mut() {
+                eval_assert_file(
+                    assert_file,
+                    entry_result,
+                    runner_options,
+                    &variables,
+                    logger,
+                );
+            }
+        }
     }
 
     let time_in_ms = start.elapsed().as_millis();
@@ -332,6 +442,37 @@ pub fn run_entries(
     }
 }
 
+/// Evaluates the asserts of `assert_file` on the last response of `entry_result`.
+///
+/// Asserts results are appended to `entry_result` asserts, and errors are logged against the assert
+/// file content. As reports display the entry errors against the Hurl file content, the errors
+/// added to `entry_result` are located on the entry line.
+fn eval_assert_file(
+    assert_file: &AssertFile,
+    entry_result: &mut EntryResult,
+    runner_options: &RunnerOptions,
+    variables: &HashMap<String, Value>,
+    logger: &mut Logger,
+) {
+    let Some(Call { response, .. }) = entry_result.calls.last() else {
+        return;
+    };
+    let mut asserts = assert_file.eval(
+        variables,
+        response,
+        &AssertOptions::from(runner_options),
+        &runner_options.context_dir,
+    );
+    let line = entry_result.source_info.start.line;
+    for mut error in asserts.iter().filter_map(|a| a.error()) {
+        logger.error_file_rich(&assert_file.filename, &assert_file.content, &error);
+        error.source_info.start.line = line;
+        error.source_info.end.line = line;
+        entry_result.errors.push(error);
+    }
+    entry_result.asserts.append(&mut asserts);
+}
+
 /// Returns `true` if all the entries results are successful, `false` otherwise.
 ///
 /// For a given list of entry results, only the last one on the same index is checked.
@@ -421,6 +562,25 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
 
     let mut non_default_options = vec![];
 
+    if options.abort_on_first_assert_failure_within_entry
+        != default_options.abort_on_first_assert_failure_within_entry
+    {
+        non_default_options.push((
+            "abort on first assert failure within entry",
+            options
+                .abort_on_first_assert_failure_within_entry
+                .to_string(),
+        ));
+    }
+
+    if let Some(accept_encoding) = &options.accept_encoding {
+        non_default_options.push(("accept encoding", accept_encoding.clone()));
+    }
+
+    if let Some(assert_file) = &options.assert_file {
+        non_default_options.push(("assert file", assert_file.filename.clone()));
+    }
+
     if options.continue_on_error != default_options.continue_on_error {
         non_default_options.push(("continue_on_error", options.continue_on_error.to_string()));
     }
@@ -433,30 +593,101 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
         non_default_options.push(("delay", format!("{}ms", options.delay.as_millis() as u64)));
     }
 
+    if options.explain != default_options.explain {
+        non_default_options.push(("explain", options.explain.to_string()));
+    }
+
     if options.follow_location != default_options.follow_location {
         non_default_options.push(("follow redirect", options.follow_location.to_string()));
     }
 
+    if options.header_match != default_options.header_match {
+        non_default_options.push(("header match", format!("{:?}", options.header_match)));
+    }
+
+    if let Some(name) = &options.idempotency_key_header {
+        non_default_options.push(("idempotency key header", name.to_string()));
+    }
+
     if options.insecure != default_options.insecure {
         non_default_options.push(("insecure", options.insecure.to_string()));
     }
 
+    for host in options.insecure_hosts.iter() {
+        non_default_options.push(("insecure host", host.to_string()));
+    }
+
+    if let Some(n) = options.max_header_size {
+        non_default_options.push(("max header size", n.to_string()));
+    }
+
     if options.max_redirect != default_options.max_redirect {
         if let Some(n) = options.max_redirect {
             non_default_options.push(("max redirect", n.to_string()));
         }
     }
 
+    if options.normalize_json_numbers {
+        non_default_options.push(("normalize JSON numbers", "true".to_string()));
+    }
+
+    if options.normalize_newlines {
+        non_default_options.push(("normalize newlines", "true".to_string()));
+    }
+
+    if let Some(rel) = &options.paginate {
+        non_default_options.push((
+            "paginate",
+            format!("{rel} (max {} pages)", options.max_pages),
+        ));
+    }
+
+    if options.print_curl_on_failure {
+        non_default_options.push(("print curl on failure", "true".to_string()));
+    }
+
     if options.proxy != default_options.proxy {
         if let Some(proxy) = &options.proxy {
             non_default_options.push(("proxy", proxy.to_string()));
         }
     }
 
+    for header in options.proxy_headers.iter() {
+        non_default_options.push(("proxy header", header.to_string()));
+    }
+
+    if options.proxy_tunnel != default_options.proxy_tunnel {
+        non_default_options.push(("proxy tunnel", options.proxy_tunnel.to_string()));
+    }
+
+    if options.randomize_order != default_options.randomize_order {
+        non_default_options.push(("randomize order", format!("seed {}", options.seed)));
+    }
+
     if options.retry != default_options.retry {
         non_default_options.push(("retry", options.retry.to_string()));
     }
 
+    if options.retry_connect != default_options.retry_connect {
+        non_default_options.push(("retry connect", options.retry_connect.to_string()));
+    }
+
+    for (index, name, value) in options.set_at.iter() {
+        non_default_options.push(("set at", format!("{index}:{name}={value}")));
+    }
+
+    if options.timezone != default_options.timezone {
+        non_default_options.push(("timezone", options.timezone.to_string()));
+    }
+
+    if options.trace_context != default_options.trace_context {
+        non_default_options.push(("trace context", options.trace_context.to_string()));
+    }
+
+    if let Some(state) = &options.trace_state {
+        non_default_options.push(("trace state", state.to_string()));
+    }
+
     if options.unix_socket != default_options.unix_socket {
         if let Some(unix_socket) = &options.unix_socket {
             non_default_options.push(("unix socket", unix_socket.to_string()));
@@ -516,6 +747,46 @@ fn log_errors(entry_result: &EntryResult, content: &str, retry: bool, logger: &m
         .for_each(|error| logger.error_runtime_rich(content, error, entry_result.source_info));
 }
 
+/// Logs the curl command line equivalent to the request of the failing `entry_result`.
+fn log_curl_command(entry_result: &EntryResult, logger: &mut Logger) {
+    if entry_result.curl_cmd.is_empty() {
+        return;
+    }
+    logger.info(&format!(
+        "Entry {} can be run with the following curl command:",
+        entry_result.entry_index
+    ));
+    logger.info(&entry_result.curl_cmd);
+    logger.info("");
+}
+
+/// Logs the result of each assert of `entry_result`, whether it has succeeded or not.
+fn log_explain(entry_result: &EntryResult, content: &str, logger: &mut Logger) {
+    if entry_result.asserts.is_empty() {
+        return;
+    }
+    let lines = content.lines().collect::<Vec<_>>();
+    logger.info(&format!("Asserts of entry {}:", entry_result.entry_index));
+    for assert in entry_result.asserts.iter() {
+        let line = assert.line();
+        let source = lines.get(line - 1).map_or("", |l| l.trim());
+        let actual = assert.actual_display();
+        logger.explain_assert(line, source, &actual, assert.error().is_none());
+    }
+}
+
+/// Logs the soft asserts failures of `entry_result` as warnings.
+fn log_warnings(entry_result: &EntryResult, content: &str, logger: &mut Logger) {
+    entry_result
+        .asserts
+        .iter()
+        .filter(|assert| assert.is_soft())
+        .filter_map(|assert| assert.error())
+        .for_each(|warning| {
+            logger.warning_runtime_rich(content, &warning, entry_result.source_info);
+        });
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
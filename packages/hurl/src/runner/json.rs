@@ -15,10 +15,12 @@
  * limitations under the License.
  *
  */
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 
 use hurl_core::ast::{JsonListElement, JsonObjectElement, JsonValue, Template, TemplateElement};
 use hurl_core::parser::{parse_json_boolean, parse_json_null, parse_json_number, Reader};
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
 
 use crate::runner::error::{Error, RunnerError};
 use crate::runner::template::render_expression;
@@ -174,6 +176,151 @@ fn encode_json_char(c: char) -> String {
     }
 }
 
+/// Returns the JSON text `json` with its numbers written in a canonical form.
+///
+/// Numbers with the same value, like `1.0`, `1`, `1e0` or `100e-2`, are written the same way, so
+/// two JSON texts can be compared as strings regardless of their numbers formatting. Integral
+/// numbers are written without fraction part or exponent, other numbers in their shortest decimal
+/// representation. Everything else in the JSON text (strings, whitespace, etc...) is unchanged.
+pub fn normalize_json_numbers(json: &str) -> String {
+    let mut normalized = String::with_capacity(json.len());
+    let mut chars = json.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            // Strings are copied as is, numbers-like content included.
+            '"' => {
+                normalized.push(c);
+                let mut escaped = false;
+                for (_, c) in chars.by_ref() {
+                    normalized.push(c);
+                    match c {
+                        '\\' if !escaped => escaped = true,
+                        '"' if !escaped => break,
+                        _ => escaped = false,
+                    }
+                }
+            }
+            '-' | '0'..='9' => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, c)) = chars.peek() {
+                    if !matches!(c, '0'..='9' | '.' | 'e' | 'E' | '+' | '-') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                normalized.push_str(&normalize_json_number(&json[start..end]));
+            }
+            c => normalized.push(c),
+        }
+    }
+    normalized
+}
+
+/// Parses the JSON text `json`, failing if an object has duplicate keys.
+///
+/// Contrary to parsing the text as a [`serde_json::Value`], where the last duplicate key wins,
+/// every key of each object is kept to detect duplicates. A duplicate key is reported as a data
+/// error (see [`serde_json::Error::is_data`]), with the key and the path of its object.
+pub fn check_duplicate_keys(json: &str) -> Result<(), serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    DuplicateKeysChecker { path: "$" }.deserialize(&mut deserializer)?;
+    deserializer.end()
+}
+
+/// A JSON visitor checking the uniqueness of object keys, for a value located at `path`.
+struct DuplicateKeysChecker<'a> {
+    path: &'a str,
+}
+
+impl<'de> DeserializeSeed<'de> for DuplicateKeysChecker<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for DuplicateKeysChecker<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let mut index = 0;
+        loop {
+            let path = format!("{}[{index}]", self.path);
+            if seq
+                .next_element_seed(DuplicateKeysChecker { path: &path })?
+                .is_none()
+            {
+                return Ok(());
+            }
+            index += 1;
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut keys = HashSet::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if keys.contains(&key) {
+                let message = format!("duplicate key <{key}> in object {}", self.path);
+                return Err(de::Error::custom(message));
+            }
+            let path = format!("{}.{key}", self.path);
+            map.next_value_seed(DuplicateKeysChecker { path: &path })?;
+            keys.insert(key);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the canonical form of a JSON `number`.
+fn normalize_json_number(number: &str) -> String {
+    // Integers without fraction or exponent are kept as is, to preserve their precision.
+    if !number.contains(['.', 'e', 'E']) {
+        return if number == "-0" {
+            "0".to_string()
+        } else {
+            number.to_string()
+        };
+    }
+    match number.parse::<f64>() {
+        // Integral values are written as integers while they're exactly representable.
+        Ok(value) if value.fract() == 0.0 && value.abs() < 9_007_199_254_740_992.0 => {
+            format!("{}", value as i64)
+        }
+        Ok(value) if value.is_finite() => format!("{value}"),
+        _ => number.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use hurl_core::ast::*;
@@ -467,6 +614,41 @@ mod tests {
         assert_eq!(encode_json_string("\\"), "\\\\");
     }
 
+    #[test]
+    fn test_normalize_json_numbers() {
+        assert_eq!(normalize_json_numbers("1e2"), normalize_json_numbers("100"));
+        assert_eq!(
+            normalize_json_numbers(r#"{"a": 1.0, "b": [1e2, -2.50, 0.1e1, -0.0]}"#),
+            r#"{"a": 1, "b": [100, -2.5, 1, 0]}"#
+        );
+        assert_eq!(
+            normalize_json_numbers(r#"{"id": 12345678901234567890, "n": 1.5E-3}"#),
+            r#"{"id": 12345678901234567890, "n": 0.0015}"#
+        );
+        // Strings are not modified.
+        assert_eq!(
+            normalize_json_numbers(r#"["1.0", "a\"2.0", 3.0]"#),
+            r#"["1.0", "a\"2.0", 3]"#
+        );
+    }
+
+    #[test]
+    fn test_check_duplicate_keys() {
+        assert!(check_duplicate_keys(r#"{"a": 1, "b": [{"a": 2}, {"a": 3}]}"#).is_ok());
+        assert!(check_duplicate_keys("[1, true, null, \"a\"]").is_ok());
+
+        let error =
+            check_duplicate_keys(r#"{"a": 1, "b": [{"c": 2}, {"c": 3, "c": 4}]}"#).unwrap_err();
+        assert!(error.is_data());
+        assert_eq!(
+            error.to_string(),
+            "duplicate key <c> in object $.b[1] at line 1 column 37"
+        );
+
+        let error = check_duplicate_keys(r#"{"a": 1,}"#).unwrap_err();
+        assert!(error.is_syntax());
+    }
+
     #[test]
     fn test_not_preserving_spaces() {
         let variables = HashMap::new();
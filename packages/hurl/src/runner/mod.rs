@@ -18,20 +18,26 @@
 
 //! A runner for Hurl files. If you want to execute an Hurl file, this is the right place.
 
+pub use self::assert_file::AssertFile;
 pub use self::error::{Error, RunnerError};
 #[doc(hidden)]
 pub use self::event::EventListener;
-pub use self::hurl_file::run;
 #[doc(hidden)]
 pub use self::hurl_file::run_entries;
+pub use self::hurl_file::{run, run_with_client};
 pub use self::input::Input;
 pub use self::number::Number;
+pub use self::order::sample_indices;
 pub use self::output::Output;
-pub use self::result::{AssertResult, CaptureResult, EntryResult, HurlResult};
+pub use self::response_query::ResponseQuery;
+pub use self::result::{
+    AssertResult, CaptureResult, EntryResult, HurlResult, HURL_RESULT_SCHEMA_VERSION,
+};
 pub use self::runner_options::{RunnerOptions, RunnerOptionsBuilder};
 pub use self::value::Value;
 
 mod assert;
+mod assert_file;
 mod body;
 mod capture;
 mod entry;
@@ -46,7 +52,9 @@ mod multiline;
 mod multipart;
 mod number;
 mod options;
+mod order;
 mod output;
+mod pagination;
 mod predicate;
 mod predicate_value;
 mod progress;
@@ -54,6 +62,7 @@ mod query;
 mod regex;
 mod request;
 mod response;
+mod response_query;
 mod result;
 mod runner_options;
 mod template;
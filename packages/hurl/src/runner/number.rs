@@ -18,10 +18,12 @@
 use std::cmp::Ordering;
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 /// System types used in Hurl.
 ///
 /// Values are used by queries, captures, asserts and predicates.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Number {
     Float(f64),
     Integer(i64),
@@ -69,6 +69,10 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         runner_options.compressed = value;
                     }
+                    OptionKind::ConnectTimeout(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        runner_options.connect_timeout = Duration::from_millis(value);
+                    }
                     OptionKind::ConnectTo(value) => {
                         let value = eval_template(value, variables)?;
                         runner_options.connects_to.push(value);
@@ -207,6 +211,10 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         runner_options.skip = value;
                     }
+                    OptionKind::Timeout(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        runner_options.timeout = Duration::from_millis(value);
+                    }
                     OptionKind::UnixSocket(value) => {
                         let value = eval_template(value, variables)?;
                         runner_options.unix_socket = Some(value);
@@ -491,4 +499,35 @@ mod tests {
             10
         );
     }
+
+    #[test]
+    fn test_get_entry_options_timeouts() {
+        use crate::util::logger::LoggerOptionsBuilder;
+        use crate::util::term::{Stderr, WriteMode};
+
+        let content = "GET http://localhost:8000/hello\n\
+            [Options]\n\
+            timeout: 1500\n\
+            connect-timeout: {{connect_timeout}}\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let runner_options = RunnerOptions::default();
+        let mut variables = HashMap::default();
+        variables.insert(
+            "connect_timeout".to_string(),
+            Value::Number(Number::Integer(200)),
+        );
+        let logger_options = LoggerOptionsBuilder::new().build();
+        let mut logger = Logger::new(&logger_options, Stderr::new(WriteMode::Immediate));
+
+        let options = get_entry_options(
+            &hurl_file.entries[0],
+            &runner_options,
+            &mut variables,
+            &mut logger,
+        )
+        .unwrap();
+        assert_eq!(options.timeout, Duration::from_millis(1500));
+        assert_eq!(options.connect_timeout, Duration::from_millis(200));
+        assert_eq!(runner_options.timeout, Duration::from_secs(300));
+    }
 }
@@ -0,0 +1,242 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashSet;
+
+use hurl_core::ast::{Entry, OptionKind, VariableDefinition};
+
+/// Returns the indices (1-based) of `entries` from `from_entry` to `to_entry` (included), in a
+/// random order given by `seed`.
+///
+/// Entries that depends on each other are kept in their original order: an entry that defines a
+/// variable (with a capture or a `variable` option) stays before (or after) any entry that uses or
+/// defines this variable. `content` is the source of the `entries`, used to find the variables used
+/// by each entry.
+pub fn shuffle_entries(
+    entries: &[Entry],
+    content: &str,
+    from_entry: usize,
+    to_entry: usize,
+    seed: u64,
+) -> Vec<usize> {
+    let indices = (from_entry..=to_entry).collect::<Vec<_>>();
+    let lines = content.lines().collect::<Vec<_>>();
+    let variables = indices
+        .iter()
+        .map(|&index| {
+            let start = entries[index - 1].source_info().start.line;
+            let end = entries
+                .get(index)
+                .map_or(lines.len() + 1, |next| next.source_info().start.line);
+            let source = lines[start - 1..end - 1].join("\n");
+            (
+                defined_variables(&entries[index - 1]),
+                used_variables(&source),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // `dependencies[i]` contains the entries (positions in `indices`) that must run before the
+    // entry at position `i`.
+    let dependencies = (0..indices.len())
+        .map(|j| {
+            let (defined_j, used_j) = &variables[j];
+            (0..j)
+                .filter(|&i| {
+                    let (defined_i, used_i) = &variables[i];
+                    !defined_i.is_disjoint(defined_j)
+                        || !defined_i.is_disjoint(used_j)
+                        || !used_i.is_disjoint(defined_j)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // We pick randomly the next entry among the entries whose dependencies have all been run.
+    let mut rng = Rng::new(seed);
+    let mut done = vec![false; indices.len()];
+    let mut order = Vec::with_capacity(indices.len());
+    while order.len() < indices.len() {
+        let ready = (0..indices.len())
+            .filter(|&i| !done[i] && dependencies[i].iter().all(|&d| done[d]))
+            .collect::<Vec<_>>();
+        let next = ready[rng.below(ready.len())];
+        done[next] = true;
+        order.push(indices[next]);
+    }
+    order
+}
+
+/// Returns the indices (0-based, in ascending order) of a random sample of `percent`% of `count`
+/// items, given by `seed`.
+///
+/// A given seed always selects the same items. At least one item is selected, unless `count` is
+/// zero.
+pub fn sample_indices(count: usize, percent: u32, seed: u64) -> Vec<usize> {
+    let percent = percent.min(100) as usize;
+    let size = (count * percent).div_ceil(100).max(1).min(count);
+    let mut rng = Rng::new(seed);
+    let mut indices = (0..count).collect::<Vec<_>>();
+    // Partial Fisher–Yates shuffle: the first `size` indices are a random sample.
+    for i in 0..size {
+        let j = i + rng.below(count - i);
+        indices.swap(i, j);
+    }
+    let mut sample = indices[..size].to_vec();
+    sample.sort_unstable();
+    sample
+}
+
+/// Returns the name of the variables defined by this `entry`, with captures or `variable` options.
+fn defined_variables(entry: &Entry) -> HashSet<String> {
+    let mut variables = HashSet::new();
+    for option in entry.request.options() {
+        if let OptionKind::Variable(VariableDefinition { name, .. }) = option.kind {
+            variables.insert(name);
+        }
+    }
+    if let Some(response) = &entry.response {
+        for capture in response.captures() {
+            variables.insert(capture.name.to_string());
+        }
+    }
+    variables
+}
+
+/// Returns the name of the variables used in this entry `source`, in `{{name}}` expressions.
+fn used_variables(source: &str) -> HashSet<String> {
+    let mut variables = HashSet::new();
+    for (_, expr) in source
+        .match_indices("{{")
+        .map(|(i, _)| source.split_at(i + 2))
+    {
+        let name = expr
+            .trim_start()
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+            .collect::<String>();
+        if !name.is_empty() {
+            variables.insert(name);
+        }
+    }
+    variables
+}
+
+/// A small pseudo-random number generator (SplitMix64): a given seed always produces the same
+/// sequence, so that a random order can be reproduced.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number between 0 (included) and `n` (excluded).
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser;
+
+    use super::*;
+
+    const CONTENT: &str = r#"GET http://localhost:8000/a
+HTTP 200
+
+GET http://localhost:8000/b
+HTTP 200
+
+GET http://localhost:8000/login
+HTTP 200
+[Captures]
+token: header "X-Token"
+
+GET http://localhost:8000/c
+HTTP 200
+
+GET http://localhost:8000/protected
+Authorization: Bearer {{token}}
+HTTP 200
+
+GET http://localhost:8000/d
+HTTP 200
+"#;
+
+    #[test]
+    fn test_used_variables() {
+        assert_eq!(
+            used_variables("GET {{ host}}/{{path}}\nX-Foo: \\{{ {{bar}}"),
+            HashSet::from(["host".to_string(), "path".to_string(), "bar".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_sample_indices() {
+        // A given seed always selects the same items.
+        let sample = sample_indices(100, 10, 42);
+        assert_eq!(sample.len(), 10);
+        assert_eq!(sample, sample_indices(100, 10, 42));
+        assert_ne!(sample, sample_indices(100, 10, 43));
+        assert!(sample.windows(2).all(|w| w[0] < w[1]));
+        assert!(sample.iter().all(|&i| i < 100));
+
+        assert_eq!(sample_indices(25, 10, 1).len(), 3);
+        assert_eq!(sample_indices(3, 10, 1).len(), 1);
+        assert_eq!(sample_indices(5, 100, 1), vec![0, 1, 2, 3, 4]);
+        assert!(sample_indices(0, 10, 1).is_empty());
+    }
+
+    #[test]
+    fn test_shuffle_entries() {
+        let hurl_file = parser::parse_hurl_file(CONTENT).unwrap();
+        let entries = &hurl_file.entries;
+
+        // A given seed always gives the same order.
+        let order = shuffle_entries(entries, CONTENT, 1, 6, 42);
+        assert_eq!(order, shuffle_entries(entries, CONTENT, 1, 6, 42));
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![1, 2, 3, 4, 5, 6]);
+
+        // Independent entries are shuffled, but the capture and its use stay ordered.
+        let orders = (0..20)
+            .map(|seed| shuffle_entries(entries, CONTENT, 1, 6, seed))
+            .collect::<Vec<_>>();
+        assert!(orders.iter().any(|order| order != &vec![1, 2, 3, 4, 5, 6]));
+        for order in &orders {
+            let login = order.iter().position(|&i| i == 3).unwrap();
+            let protected = order.iter().position(|&i| i == 5).unwrap();
+            assert!(login < protected);
+        }
+
+        // Only the selected entries are shuffled.
+        let mut order = shuffle_entries(entries, CONTENT, 2, 4, 42);
+        order.sort();
+        assert_eq!(order, vec![2, 3, 4]);
+    }
+}
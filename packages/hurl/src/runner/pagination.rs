@@ -0,0 +1,135 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Follows pagination links of an HTTP response and aggregates the bodies of the pages.
+use std::collections::HashSet;
+
+use crate::http;
+use crate::http::{
+    Call, ClientOptions, HttpClient, HttpError, Method, RequestSpec, CONTENT_ENCODING,
+    CONTENT_LENGTH,
+};
+use crate::util::logger::Logger;
+
+/// Follows the links with relation type `rel` from the last response of `calls`, until there is
+/// no more link or `max_pages` pages (including the first one) have been fetched.
+///
+/// Each page is requested with a `GET` using the headers of the first request `request_spec`, and
+/// its calls are appended to `calls`. If more than one page has been fetched, returns a response
+/// built from the last page, whose body aggregates the bodies of all the pages (see
+/// [`aggregate_bodies`]).
+pub fn paginate(
+    http_client: &mut dyn HttpClient,
+    request_spec: &RequestSpec,
+    rel: &str,
+    max_pages: usize,
+    calls: &mut Vec<Call>,
+    client_options: &ClientOptions,
+    logger: &mut Logger,
+) -> Result<Option<http::Response>, HttpError> {
+    let mut pages = vec![calls.last().unwrap().response.clone()];
+    let mut visited = HashSet::new();
+    visited.insert(calls.last().unwrap().request.url.clone());
+
+    while pages.len() < max_pages {
+        let Some(url) = pages.last().unwrap().get_link_url(rel) else {
+            break;
+        };
+        // We stop the pagination on loops.
+        if !visited.insert(url.clone()) {
+            break;
+        }
+        logger.debug("");
+        logger.debug_important(&format!(
+            "Follow pagination link <{rel}> to {url} (page {})",
+            pages.len() + 1
+        ));
+        let page_request = RequestSpec {
+            method: Method("GET".to_string()),
+            url,
+            headers: request_spec.headers.clone(),
+            ..Default::default()
+        };
+        let mut page_calls =
+            http_client.execute_with_redirect(&page_request, client_options, logger)?;
+        pages.push(page_calls.last().unwrap().response.clone());
+        calls.append(&mut page_calls);
+    }
+
+    if pages.len() == 1 {
+        return Ok(None);
+    }
+    let bodies = pages
+        .iter()
+        .map(|page| page.uncompress_body())
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut response = pages.pop().unwrap();
+    // The aggregated body is not compressed anymore and has a new length.
+    response
+        .headers
+        .retain(|h| !h.name_eq(CONTENT_ENCODING) && !h.name_eq(CONTENT_LENGTH));
+    response.body = aggregate_bodies(&bodies).into();
+    Ok(Some(response))
+}
+
+/// Aggregates the `bodies` of several pages.
+///
+/// If every body is a JSON array, the items of the arrays are concatenated into a single JSON
+/// array. Otherwise, bodies are concatenated as is.
+fn aggregate_bodies(bodies: &[bytes::Bytes]) -> Vec<u8> {
+    let arrays = bodies
+        .iter()
+        .map(
+            |body| match serde_json::from_slice::<serde_json::Value>(body) {
+                Ok(serde_json::Value::Array(items)) => Some(items),
+                _ => None,
+            },
+        )
+        .collect::<Option<Vec<_>>>();
+    match arrays {
+        Some(arrays) => {
+            let items = arrays.into_iter().flatten().collect::<Vec<_>>();
+            serde_json::to_vec(&items).unwrap()
+        }
+        None => bodies.concat(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+
+    #[test]
+    fn test_aggregate_bodies() {
+        let bodies = vec![
+            Bytes::from_static(br#"[{"id": 1}, {"id": 2}]"#),
+            Bytes::from_static(br#"[{"id": 3}]"#),
+        ];
+        assert_eq!(
+            aggregate_bodies(&bodies),
+            br#"[{"id":1},{"id":2},{"id":3}]"#.to_vec()
+        );
+
+        let bodies = vec![
+            Bytes::from_static(br#"[{"id": 1}]"#),
+            Bytes::from_static(b"page 2"),
+        ];
+        assert_eq!(aggregate_bodies(&bodies), b"[{\"id\": 1}]page 2".to_vec());
+    }
+}
@@ -21,11 +21,13 @@ use std::collections::HashMap;
 use hurl_core::ast::*;
 use regex;
 
+use crate::runner::body::eval_file;
 use crate::runner::error::Error;
 use crate::runner::predicate_value::{eval_predicate_value, eval_predicate_value_template};
 use crate::runner::result::PredicateResult;
 use crate::runner::template::eval_template;
 use crate::runner::value::Value;
+use crate::runner::{json, xpath};
 use crate::runner::{Number, RunnerError};
 use crate::util::path::ContextDir;
 
@@ -51,12 +53,14 @@ pub fn eval_predicate(
     predicate: &Predicate,
     variables: &HashMap<String, Value>,
     value: &Option<Value>,
+    normalize_newlines: bool,
     context_dir: &ContextDir,
 ) -> PredicateResult {
     let assert_result = eval_predicate_func(
         &predicate.predicate_func,
         variables,
         value.as_ref(),
+        normalize_newlines,
         context_dir,
     )?;
 
@@ -83,6 +87,11 @@ pub fn eval_predicate(
         };
         Err(Error::new(source_info, inner, true))
     } else if !predicate.not && !assert_result.success {
+        if let Some(inner) =
+            eval_bytes_failure(&predicate.predicate_func, variables, value, context_dir)?
+        {
+            return Err(Error::new(source_info, inner, true));
+        }
         let inner = RunnerError::AssertFailure {
             actual: assert_result.actual,
             expected: assert_result.expected,
@@ -94,6 +103,35 @@ pub fn eval_predicate(
     }
 }
 
+/// Returns an assert failure displaying the diff of the actual and expected bytes, if this
+/// `predicate_func` is an equality between the bytes `value` and expected bytes.
+///
+/// Byte arrays are compared with a hexdump of their first difference, which is more readable than
+/// their whole hexadecimal values.
+fn eval_bytes_failure(
+    predicate_func: &PredicateFunc,
+    variables: &HashMap<String, Value>,
+    value: &Option<Value>,
+    context_dir: &ContextDir,
+) -> Result<Option<RunnerError>, Error> {
+    let (
+        PredicateFuncValue::Equal {
+            value: expected, ..
+        },
+        Some(actual @ Value::Bytes(bytes)),
+    ) = (&predicate_func.value, value)
+    else {
+        return Ok(None);
+    };
+    match eval_expected_value(expected, variables, actual, context_dir)? {
+        Value::Bytes(expected) => Ok(Some(RunnerError::AssertBytesFailure {
+            actual: bytes.clone(),
+            expected,
+        })),
+        _ => Ok(None),
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct AssertResult {
     pub success: bool,
@@ -239,16 +277,22 @@ fn expected_no_value(
         PredicateFuncValue::Exist => Ok("something".to_string()),
         PredicateFuncValue::IsEmpty => Ok("empty".to_string()),
         PredicateFuncValue::IsNumber => Ok("number".to_string()),
+        PredicateFuncValue::IsJson => Ok("valid JSON".to_string()),
+        PredicateFuncValue::IsXml => Ok("valid XML".to_string()),
+        PredicateFuncValue::IsHtml => Ok("valid HTML".to_string()),
+        PredicateFuncValue::HasNoDuplicateKeys => Ok("no duplicate keys".to_string()),
     }
 }
 
 /// Evaluates a `predicate_func` against an actual `value`.
 /// The `predicate_func` is a test with an expected value. The expected value can
-/// use a set of `variables`.
+/// use a set of `variables`. If `normalize_newlines` is true, `\r\n` line endings of the actual
+/// and expected strings are replaced by `\n` before comparison.
 fn eval_predicate_func(
     predicate_func: &PredicateFunc,
     variables: &HashMap<String, Value>,
     value: Option<&Value>,
+    normalize_newlines: bool,
     context_dir: &ContextDir,
 ) -> Result<AssertResult, Error> {
     let value = match value {
@@ -264,13 +308,21 @@ fn eval_predicate_func(
         }
     };
 
+    let normalized;
+    let value = if normalize_newlines {
+        normalized = value.clone().normalize_newlines();
+        &normalized
+    } else {
+        value
+    };
+
     match &predicate_func.value {
         PredicateFuncValue::Equal {
             value: expected, ..
-        } => eval_equal(expected, variables, value, context_dir),
+        } => eval_equal(expected, variables, value, normalize_newlines, context_dir),
         PredicateFuncValue::NotEqual {
             value: expected, ..
-        } => eval_not_equal(expected, variables, value, context_dir),
+        } => eval_not_equal(expected, variables, value, normalize_newlines, context_dir),
         PredicateFuncValue::GreaterThan {
             value: expected, ..
         } => eval_greater_than(expected, variables, value, context_dir),
@@ -285,13 +337,13 @@ fn eval_predicate_func(
         } => eval_less_than_or_equal(expected, variables, value, context_dir),
         PredicateFuncValue::StartWith {
             value: expected, ..
-        } => eval_start_with(expected, variables, value, context_dir),
+        } => eval_start_with(expected, variables, value, normalize_newlines, context_dir),
         PredicateFuncValue::EndWith {
             value: expected, ..
-        } => eval_end_with(expected, variables, value, context_dir),
+        } => eval_end_with(expected, variables, value, normalize_newlines, context_dir),
         PredicateFuncValue::Contain {
             value: expected, ..
-        } => eval_contain(expected, variables, value, context_dir),
+        } => eval_contain(expected, variables, value, normalize_newlines, context_dir),
         PredicateFuncValue::Include {
             value: expected, ..
         } => eval_include(expected, variables, value, context_dir),
@@ -308,6 +360,58 @@ fn eval_predicate_func(
         PredicateFuncValue::Exist => eval_exist(value),
         PredicateFuncValue::IsEmpty => eval_is_empty(value),
         PredicateFuncValue::IsNumber => eval_is_number(value),
+        PredicateFuncValue::IsJson => eval_is_json(value),
+        PredicateFuncValue::IsXml => eval_is_xml(value),
+        PredicateFuncValue::IsHtml => eval_is_html(value),
+        PredicateFuncValue::HasNoDuplicateKeys => eval_has_no_duplicate_keys(value),
+    }
+}
+
+/// Evaluates an `expected` value (using a `variables` set) to be compared with an `actual` value.
+///
+/// An expected file is read as bytes, except if the actual value is a string (the file content is
+/// then read as UTF-8 text) or a JSON value (the file content is then parsed as JSON).
+fn eval_expected_value(
+    expected: &PredicateValue,
+    variables: &HashMap<String, Value>,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<Value, Error> {
+    let PredicateValue::File(file) = expected else {
+        return eval_predicate_value(expected, variables, context_dir);
+    };
+    let bytes = eval_file(&file.filename, variables, context_dir)?;
+    match actual {
+        Value::String(_) => match String::from_utf8(bytes) {
+            Ok(s) => Ok(Value::String(s)),
+            Err(_) => {
+                let inner = RunnerError::InvalidDecoding {
+                    charset: "utf-8".to_string(),
+                };
+                Err(Error::new(file.filename.source_info, inner, false))
+            }
+        },
+        Value::Bool(_) | Value::List(_) | Value::Null | Value::Number(_) | Value::Object(_) => {
+            match serde_json::from_slice::<serde_json::Value>(&bytes) {
+                Ok(json) => Ok(Value::from_json(&json)),
+                Err(_) => {
+                    let inner = RunnerError::InvalidJson {
+                        value: String::from_utf8_lossy(&bytes).to_string(),
+                    };
+                    Err(Error::new(file.filename.source_info, inner, false))
+                }
+            }
+        }
+        _ => Ok(Value::Bytes(bytes)),
+    }
+}
+
+/// Returns the `expected` value with normalized line endings if `normalize_newlines` is true.
+fn normalize_expected(expected: Value, normalize_newlines: bool) -> Value {
+    if normalize_newlines {
+        expected.normalize_newlines()
+    } else {
+        expected
     }
 }
 
@@ -316,9 +420,11 @@ fn eval_equal(
     expected: &PredicateValue,
     variables: &HashMap<String, Value>,
     actual: &Value,
+    normalize_newlines: bool,
     context_dir: &ContextDir,
 ) -> Result<AssertResult, Error> {
-    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let expected = eval_expected_value(expected, variables, actual, context_dir)?;
+    let expected = normalize_expected(expected, normalize_newlines);
     Ok(assert_values_equal(actual, &expected))
 }
 
@@ -327,9 +433,11 @@ fn eval_not_equal(
     expected: &PredicateValue,
     variables: &HashMap<String, Value>,
     actual: &Value,
+    normalize_newlines: bool,
     context_dir: &ContextDir,
 ) -> Result<AssertResult, Error> {
-    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let expected = eval_expected_value(expected, variables, actual, context_dir)?;
+    let expected = normalize_expected(expected, normalize_newlines);
     Ok(assert_values_not_equal(actual, &expected))
 }
 
@@ -383,9 +491,11 @@ fn eval_start_with(
     expected: &PredicateValue,
     variables: &HashMap<String, Value>,
     actual: &Value,
+    normalize_newlines: bool,
     context_dir: &ContextDir,
 ) -> Result<AssertResult, Error> {
     let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let expected = normalize_expected(expected, normalize_newlines);
     let expected_display = format!("starts with {}", expected.display());
     let actual_display = actual.display();
     match (expected, actual) {
@@ -416,9 +526,11 @@ fn eval_end_with(
     expected: &PredicateValue,
     variables: &HashMap<String, Value>,
     actual: &Value,
+    normalize_newlines: bool,
     context_dir: &ContextDir,
 ) -> Result<AssertResult, Error> {
     let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let expected = normalize_expected(expected, normalize_newlines);
     let expected_display = format!("ends with {}", expected.display());
     let actual_display = actual.display();
     match (expected, actual) {
@@ -449,9 +561,11 @@ fn eval_contain(
     expected: &PredicateValue,
     variables: &HashMap<String, Value>,
     actual: &Value,
+    normalize_newlines: bool,
     context_dir: &ContextDir,
 ) -> Result<AssertResult, Error> {
     let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let expected = normalize_expected(expected, normalize_newlines);
     let expected_display = format!("contains {}", expected.display());
     let actual_display = actual.display();
     match (expected, actual) {
@@ -609,6 +723,100 @@ fn eval_is_iso_date(actual: &Value) -> Result<AssertResult, Error> {
     }
 }
 
+/// Evaluates if an `actual` value is a valid JSON document.
+fn eval_is_json(actual: &Value) -> Result<AssertResult, Error> {
+    eval_is_document(actual, "JSON", |text| {
+        serde_json::from_str::<serde_json::Value>(text)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    })
+}
+
+/// Evaluates if an `actual` value is a well-formed XML document.
+fn eval_is_xml(actual: &Value) -> Result<AssertResult, Error> {
+    eval_is_document(actual, "XML", |text| {
+        xpath::check_xml(text).map_err(|e| e.to_string())
+    })
+}
+
+/// Evaluates if an `actual` value is a valid HTML document.
+fn eval_is_html(actual: &Value) -> Result<AssertResult, Error> {
+    eval_is_document(actual, "HTML", |text| {
+        xpath::check_html(text).map_err(|e| e.to_string())
+    })
+}
+
+/// Evaluates if an `actual` value is a JSON document without duplicate keys in its objects.
+fn eval_has_no_duplicate_keys(actual: &Value) -> Result<AssertResult, Error> {
+    let expected = "no duplicate keys".to_string();
+    let text = match actual {
+        Value::String(value) => std::borrow::Cow::Borrowed(value.as_str()),
+        Value::Bytes(value) => String::from_utf8_lossy(value),
+        _ => {
+            return Ok(AssertResult {
+                success: false,
+                actual: actual.display(),
+                expected: "string or bytes".to_string(),
+                type_mismatch: true,
+            })
+        }
+    };
+    let (success, actual) = match json::check_duplicate_keys(&text) {
+        Ok(()) => (true, "no duplicate keys".to_string()),
+        Err(error) if error.is_data() => (false, error.to_string()),
+        Err(error) => (false, format!("invalid JSON: {error}")),
+    };
+    Ok(AssertResult {
+        success,
+        actual,
+        expected,
+        type_mismatch: false,
+    })
+}
+
+/// Evaluates if an `actual` string or bytes value can be parsed as a `kind` document with `check`.
+///
+/// When the parsing fails, the actual value describes the error and its location.
+fn eval_is_document(
+    actual: &Value,
+    kind: &str,
+    check: impl Fn(&str) -> Result<(), String>,
+) -> Result<AssertResult, Error> {
+    let expected = format!("valid {kind}");
+    let text = match actual {
+        Value::String(value) => value.as_str(),
+        Value::Bytes(value) => match std::str::from_utf8(value) {
+            Ok(value) => value,
+            Err(_) => {
+                return Ok(AssertResult {
+                    success: false,
+                    actual: format!("invalid {kind}: bytes are not valid UTF-8"),
+                    expected,
+                    type_mismatch: false,
+                })
+            }
+        },
+        _ => {
+            return Ok(AssertResult {
+                success: false,
+                actual: actual.display(),
+                expected: "string or bytes".to_string(),
+                type_mismatch: true,
+            })
+        }
+    };
+    let (success, actual) = match check(text) {
+        Ok(()) => (true, format!("valid {kind}")),
+        Err(error) => (false, format!("invalid {kind}: {error}")),
+    };
+    Ok(AssertResult {
+        success,
+        actual,
+        expected,
+        type_mismatch: false,
+    })
+}
+
 /// Evaluates if an `actual` value exists.
 fn eval_exist(actual: &Value) -> Result<AssertResult, Error> {
     let actual_display = actual.display();
@@ -722,6 +930,12 @@ fn assert_values_equal(actual: &Value, expected: &Value) -> AssertResult {
             expected: expected_display,
             type_mismatch: false,
         },
+        (Value::Object(value1), Value::Object(value2)) => AssertResult {
+            success: value1 == value2,
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: false,
+        },
         // FIXME: why case (UNIT UNIT) is not treated?
         (Value::Unit, _) => AssertResult {
             success: false,
@@ -779,6 +993,12 @@ fn assert_values_not_equal(actual: &Value, expected: &Value) -> AssertResult {
             expected: expected_display,
             type_mismatch: false,
         },
+        (Value::Object(value1), Value::Object(value2)) => AssertResult {
+            success: value1 != value2,
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: false,
+        },
         (Value::Unit, _) => AssertResult {
             success: false,
             actual: actual_display,
@@ -911,6 +1131,10 @@ fn assert_include(value: &Value, element: &Value) -> AssertResult {
 }
 
 fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    // An empty sequence is always contained (and `windows` panics with a zero size).
+    if needle.is_empty() {
+        return true;
+    }
     haystack
         .windows(needle.len())
         .any(|window| window == needle)
@@ -934,6 +1158,8 @@ mod tests {
         assert!(contains(&haystack, &[1]));
         assert!(contains(&haystack, &[1, 2]));
         assert!(!contains(&haystack, &[1, 3]));
+        assert!(contains(&haystack, &[]));
+        assert!(!contains(&[], &[1]));
     }
 
     #[test]
@@ -968,6 +1194,7 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Bool(true)),
+            false,
             &context_dir
         )
         .is_ok());
@@ -976,6 +1203,7 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Number(Number::Integer(10))),
+            false,
             &context_dir,
         )
         .unwrap_err();
@@ -996,6 +1224,7 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Number(Number::Integer(1))),
+            false,
             &context_dir
         )
         .is_ok());
@@ -1012,7 +1241,7 @@ mod tests {
         // value: true
         let expected = PredicateValue::Number(hurl_core::ast::Number::Integer(10));
         let value = Value::Bool(true);
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(!assert_result.success);
         // FIXME: should be type_mismatch = true here
         // assert!(assert_result.type_mismatch);
@@ -1032,7 +1261,7 @@ mod tests {
         // value: Unit
         let expected = PredicateValue::Number(hurl_core::ast::Number::Integer(10));
         let value = Value::Unit;
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(!assert_result.success);
         assert!(assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "unit");
@@ -1050,7 +1279,7 @@ mod tests {
         // value: 1
         let expected = PredicateValue::Number(hurl_core::ast::Number::Integer(10));
         let value = Value::Number(Number::Integer(1));
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(!assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "int <1>");
@@ -1060,7 +1289,7 @@ mod tests {
         // value: false
         let expected = PredicateValue::Bool(true);
         let value = Value::Bool(false);
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(!assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "bool <false>");
@@ -1073,7 +1302,7 @@ mod tests {
             encoded: "1.2".to_string(),
         }));
         let value = Value::Number(Number::Float(1.1));
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(!assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "float <1.1>");
@@ -1096,14 +1325,14 @@ mod tests {
 
         let value = Some(&Value::Unit);
         let assert_result =
-            eval_predicate_func(&pred_func, &variables, value, &context_dir).unwrap();
+            eval_predicate_func(&pred_func, &variables, value, false, &context_dir).unwrap();
         assert!(assert_result.success);
         assert_eq!(assert_result.actual.as_str(), "unit");
         assert_eq!(assert_result.expected.as_str(), "something");
 
         let value = None;
         let assert_result =
-            eval_predicate_func(&pred_func, &variables, value, &context_dir).unwrap();
+            eval_predicate_func(&pred_func, &variables, value, false, &context_dir).unwrap();
         assert!(!assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "none");
@@ -1121,7 +1350,7 @@ mod tests {
         // value: 1
         let expected = PredicateValue::Number(hurl_core::ast::Number::Integer(1));
         let value = Value::Number(Number::Integer(1));
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "int <1>");
@@ -1139,7 +1368,7 @@ mod tests {
         // value: false
         let expected = PredicateValue::Bool(false);
         let value = Value::Bool(false);
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "bool <false>");
@@ -1149,7 +1378,7 @@ mod tests {
         // value: false
         let expected = PredicateValue::Bool(true);
         let value = Value::Bool(false);
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(!assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "bool <false>");
@@ -1159,7 +1388,7 @@ mod tests {
         // value: true
         let expected = PredicateValue::Bool(true);
         let value = Value::Bool(true);
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "bool <true>");
@@ -1180,7 +1409,7 @@ mod tests {
             encoded: "1.1".to_string(),
         }));
         let value = Value::Number(Number::Float(1.1));
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "float <1.1>");
@@ -1198,7 +1427,7 @@ mod tests {
         // value: 1.0
         let expected = PredicateValue::Number(hurl_core::ast::Number::Integer(1));
         let value = Value::Number(Number::Float(1.0));
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "float <1.0>");
@@ -1216,13 +1445,56 @@ mod tests {
         // value: 2
         let expected = PredicateValue::Number(hurl_core::ast::Number::Integer(1));
         let value = Value::Number(Number::Integer(2));
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(!assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "int <2>");
         assert_eq!(assert_result.expected, "int <1>");
     }
 
+    #[test]
+    fn test_predicate_value_equals_bytes() {
+        let variables = HashMap::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        let value = Value::Bytes(b"Hello".to_vec());
+
+        // predicate: `== hex,48656c6c6f;`
+        // value: Hello
+        let expected = PredicateValue::Hex(Hex {
+            space0: whitespace(),
+            value: b"Hello".to_vec(),
+            encoded: "48656c6c6f".to_string(),
+            space1: whitespace(),
+        });
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        let assert_result =
+            eval_not_equal(&expected, &variables, &value, false, &context_dir).unwrap();
+        assert!(!assert_result.success);
+
+        // predicate: `== base64,SGVsbG8=;`
+        // value: Hello
+        let expected = PredicateValue::Base64(Base64 {
+            space0: whitespace(),
+            value: b"Hello".to_vec(),
+            encoded: "SGVsbG8=".to_string(),
+            space1: whitespace(),
+        });
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        // predicate: `== base64,SGVsbG8=;`
+        // value: Hello World!
+        let value = Value::Bytes(b"Hello World!".to_vec());
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
+        assert!(!assert_result.success);
+        assert_eq!(assert_result.expected, "byte array <48656c6c6f>");
+    }
+
     #[test]
     fn test_predicate_value_equals_string() {
         let variables = HashMap::new();
@@ -1255,7 +1527,7 @@ mod tests {
         // base_url is not defined
         let expected = PredicateValue::String(template.clone());
         let value = Value::String(String::from("http://localhost:8000"));
-        let error = eval_equal(&expected, &variables, &value, &context_dir).unwrap_err();
+        let error = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap_err();
         assert_eq!(
             error.inner,
             RunnerError::TemplateVariableNotDefined {
@@ -1275,7 +1547,7 @@ mod tests {
             String::from("base_url"),
             Value::String(String::from("http://localhost:8000")),
         );
-        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        let assert_result = eval_equal(&expected, &variables, &value, false, &context_dir).unwrap();
         assert!(assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "string <http://localhost:8000>");
@@ -1424,6 +1696,7 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Number(Number::Integer(1))),
+            false,
             &context_dir
         )
         .is_ok());
@@ -1453,6 +1726,7 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Number(Number::Integer(1))),
+            false,
             &context_dir,
         )
         .unwrap_err();
@@ -1510,7 +1784,7 @@ mod tests {
             },
         };
 
-        let error = eval_predicate(&predicate, &variables, &None, &context_dir)
+        let error = eval_predicate(&predicate, &variables, &None, false, &context_dir)
             .err()
             .unwrap();
         assert_eq!(
@@ -1537,7 +1811,7 @@ mod tests {
         };
 
         let variables = HashMap::new();
-        assert!(eval_predicate(&predicate, &variables, &None, &context_dir).is_ok());
+        assert!(eval_predicate(&predicate, &variables, &None, false, &context_dir).is_ok());
     }
 
     #[test]
@@ -1558,6 +1832,145 @@ mod tests {
         assert_eq!(assert_result.expected, "matches regex <a{3}>");
     }
 
+    #[test]
+    fn test_predicate_bytes_failure() {
+        let variables = HashMap::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let context_dir = ContextDir::new(current_dir.as_path(), Path::new(""));
+
+        // predicate: `== hex,48656c6c6f21;`
+        // value: Hello?
+        let predicate = |not: bool| Predicate {
+            not,
+            space0: whitespace(),
+            predicate_func: PredicateFunc {
+                source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 26)),
+                value: PredicateFuncValue::Equal {
+                    space0: whitespace(),
+                    value: PredicateValue::Hex(Hex {
+                        space0: whitespace(),
+                        value: b"Hello!".to_vec(),
+                        encoded: "48656c6c6f21".to_string(),
+                        space1: whitespace(),
+                    }),
+                    operator: true,
+                },
+            },
+        };
+        let value = Some(Value::Bytes(b"Hello?".to_vec()));
+
+        let error =
+            eval_predicate(&predicate(false), &variables, &value, false, &context_dir).unwrap_err();
+        assert_eq!(
+            error.inner,
+            RunnerError::AssertBytesFailure {
+                actual: b"Hello?".to_vec(),
+                expected: b"Hello!".to_vec(),
+            }
+        );
+        assert!(error.assert);
+        assert!(eval_predicate(&predicate(true), &variables, &value, false, &context_dir).is_ok());
+    }
+
+    #[test]
+    fn test_predicate_match_flags() {
+        let variables = HashMap::new();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let body = Value::String("Hello World!\nBye World!\n".to_string());
+        let string = |s: &str| {
+            PredicateValue::String(Template {
+                delimiter: Some('"'),
+                elements: vec![TemplateElement::String {
+                    value: s.to_string(),
+                    encoded: s.to_string(),
+                }],
+                source_info,
+            })
+        };
+        let regex = |s: &str| {
+            PredicateValue::Regex(Regex {
+                inner: regex::Regex::new(s).unwrap(),
+            })
+        };
+
+        // Case-insensitive
+        // predicate: `matches "(?i)hello world"` and `matches /(?i)^HELLO/`
+        for expected in [string("(?i)hello world"), regex("(?i)^HELLO")] {
+            let assert_result = eval_match(&expected, source_info, &variables, &body).unwrap();
+            assert!(assert_result.success);
+        }
+        let assert_result =
+            eval_match(&string("hello world"), source_info, &variables, &body).unwrap();
+        assert!(!assert_result.success);
+
+        // Multiline: `^` and `$` match at the start and end of each line.
+        // predicate: `matches /(?m)^Bye World!$/`
+        let assert_result =
+            eval_match(&regex("(?m)^Bye World!$"), source_info, &variables, &body).unwrap();
+        assert!(assert_result.success);
+        let assert_result =
+            eval_match(&regex("^Bye World!$"), source_info, &variables, &body).unwrap();
+        assert!(!assert_result.success);
+
+        // Dotall: `.` matches newlines.
+        // predicate: `matches /(?s)Hello.*Bye/`
+        let assert_result =
+            eval_match(&regex("(?s)Hello.*Bye"), source_info, &variables, &body).unwrap();
+        assert!(assert_result.success);
+        let assert_result =
+            eval_match(&regex("Hello.*Bye"), source_info, &variables, &body).unwrap();
+        assert!(!assert_result.success);
+    }
+
+    #[test]
+    fn test_predicate_contains_bytes() {
+        let variables = HashMap::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        let hex = |value: Vec<u8>| {
+            PredicateValue::Hex(Hex {
+                space0: whitespace(),
+                encoded: hex::encode(&value),
+                value,
+                space1: whitespace(),
+            })
+        };
+
+        // predicate: `contains hex,deadbeef;`
+        // value: hex,00deadbeef01;
+        let value = Value::Bytes(vec![0x00, 0xde, 0xad, 0xbe, 0xef, 0x01]);
+        let expected = hex(vec![0xde, 0xad, 0xbe, 0xef]);
+        let assert_result =
+            eval_contain(&expected, &variables, &value, false, &context_dir).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.expected, "contains byte array <deadbeef>");
+
+        // predicate: `contains hex,deadbeef;`
+        // value: hex,00deadbe;
+        let value = Value::Bytes(vec![0x00, 0xde, 0xad, 0xbe]);
+        let assert_result =
+            eval_contain(&expected, &variables, &value, false, &context_dir).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        // predicate: `contains hex,;`
+        let expected = hex(vec![]);
+        let assert_result =
+            eval_contain(&expected, &variables, &value, false, &context_dir).unwrap();
+        assert!(assert_result.success);
+
+        // predicate: `contains hex,deadbeef;`
+        // value: "deadbeef"
+        let value = Value::String("deadbeef".to_string());
+        let expected = hex(vec![0xde, 0xad, 0xbe, 0xef]);
+        let assert_result =
+            eval_contain(&expected, &variables, &value, false, &context_dir).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
     #[test]
     fn test_predicate_is_iso_date() {
         let value = Value::String("2020-03-09T22:18:26.625Z".to_string());
@@ -1603,6 +2016,109 @@ mod tests {
         assert_eq!(res.expected, "string");
     }
 
+    #[test]
+    fn test_predicate_is_json() {
+        let value = Value::String(r#"{"name": "Bob", "tags": [1, 2]}"#.to_string());
+        let res = eval_is_json(&value).unwrap();
+        assert!(res.success);
+        assert_eq!(res.actual, "valid JSON");
+        assert_eq!(res.expected, "valid JSON");
+
+        let value = Value::Bytes(b"[true, null]".to_vec());
+        let res = eval_is_json(&value).unwrap();
+        assert!(res.success);
+
+        let value = Value::String("{\n  \"name\": \"Bob\",\n}".to_string());
+        let res = eval_is_json(&value).unwrap();
+        assert!(!res.success);
+        assert!(!res.type_mismatch);
+        assert_eq!(
+            res.actual,
+            "invalid JSON: trailing comma at line 3 column 1"
+        );
+
+        let value = Value::Number(Number::Integer(1));
+        let res = eval_is_json(&value).unwrap();
+        assert!(!res.success);
+        assert!(res.type_mismatch);
+        assert_eq!(res.actual, "int <1>");
+        assert_eq!(res.expected, "string or bytes");
+    }
+
+    #[test]
+    fn test_predicate_is_xml() {
+        let value = Value::String(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<food>
+  <banana type="fruit" price="1.1"/>
+</food>
+"#
+            .to_string(),
+        );
+        let res = eval_is_xml(&value).unwrap();
+        assert!(res.success);
+        assert_eq!(res.actual, "valid XML");
+
+        let value = Value::String("<food>\n  <banana>\n</food>\n".to_string());
+        let res = eval_is_xml(&value).unwrap();
+        assert!(!res.success);
+        assert!(!res.type_mismatch);
+        assert_eq!(
+            res.actual,
+            "invalid XML: Opening and ending tag mismatch: banana line 2 and food at line 3 column 8"
+        );
+
+        let value = Value::String(String::new());
+        let res = eval_is_xml(&value).unwrap();
+        assert!(!res.success);
+    }
+
+    #[test]
+    fn test_predicate_is_html() {
+        let value = Value::String(
+            "<!DOCTYPE html><html><body><section><p>Hello</p></section></body></html>".to_string(),
+        );
+        let res = eval_is_html(&value).unwrap();
+        assert!(res.success);
+        assert_eq!(res.actual, "valid HTML");
+
+        let value = Value::String(String::new());
+        let res = eval_is_html(&value).unwrap();
+        assert!(!res.success);
+        assert!(!res.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_has_no_duplicate_keys() {
+        let value = Value::String(r#"{"name": "Bob", "tags": [{"id": 1}, {"id": 2}]}"#.to_string());
+        let res = eval_has_no_duplicate_keys(&value).unwrap();
+        assert!(res.success);
+        assert_eq!(res.actual, "no duplicate keys");
+        assert_eq!(res.expected, "no duplicate keys");
+
+        let value =
+            Value::Bytes(b"{\n  \"user\": {\"name\": \"Bob\", \"name\": \"Bill\"}\n}".to_vec());
+        let res = eval_has_no_duplicate_keys(&value).unwrap();
+        assert!(!res.success);
+        assert!(!res.type_mismatch);
+        assert_eq!(
+            res.actual,
+            "duplicate key <name> in object $.user at line 2 column 32"
+        );
+
+        let value = Value::String("{".to_string());
+        let res = eval_has_no_duplicate_keys(&value).unwrap();
+        assert!(!res.success);
+        assert_eq!(
+            res.actual,
+            "invalid JSON: EOF while parsing an object at line 1 column 1"
+        );
+
+        let value = Value::Number(Number::Integer(1));
+        let res = eval_has_no_duplicate_keys(&value).unwrap();
+        assert!(res.type_mismatch);
+    }
+
     #[test]
     fn test_predicate_is_number() {
         let value = Value::Number(Number::Integer(1));
@@ -1619,4 +2135,67 @@ mod tests {
         assert_eq!(res.actual, "float <1.0>");
         assert_eq!(res.expected, "number");
     }
+
+    #[test]
+    fn test_predicate_equal_file() {
+        let variables = HashMap::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("tests");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        let file = |filename: &str| {
+            PredicateValue::File(File {
+                space0: whitespace(),
+                filename: Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: filename.to_string(),
+                        encoded: filename.to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 28)),
+                },
+                space1: whitespace(),
+            })
+        };
+
+        // jsonpath "$" == file,bookstore.json;
+        let json = std::fs::read_to_string("tests/bookstore.json").unwrap();
+        let json = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+        let actual = Value::from_json(&json);
+        let expected = file("bookstore.json");
+        let res = eval_equal(&expected, &variables, &actual, false, &context_dir).unwrap();
+        assert!(res.success);
+        let res = eval_not_equal(&expected, &variables, &actual, false, &context_dir).unwrap();
+        assert!(!res.success);
+
+        let actual = Value::from_json(&json["store"]);
+        let res = eval_equal(&expected, &variables, &actual, false, &context_dir).unwrap();
+        assert!(!res.success);
+
+        // body == file,hello.txt;
+        let actual = Value::String("Hello World!".to_string());
+        let res = eval_equal(&file("hello.txt"), &variables, &actual, false, &context_dir).unwrap();
+        assert!(res.success);
+
+        // bytes == file,hello.txt;
+        let actual = Value::Bytes(b"Hello World!".to_vec());
+        let res = eval_equal(&file("hello.txt"), &variables, &actual, false, &context_dir).unwrap();
+        assert!(res.success);
+
+        // jsonpath "$" == file,hello.txt;
+        let error = eval_equal(
+            &file("hello.txt"),
+            &variables,
+            &Value::Null,
+            false,
+            &context_dir,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::InvalidJson {
+                value: "Hello World!".to_string()
+            }
+        );
+    }
 }
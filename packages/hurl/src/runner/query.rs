@@ -22,6 +22,7 @@ use regex::Regex;
 use sha2::Digest;
 
 use crate::http;
+use crate::http::HeaderMatch;
 use crate::runner::error::{Error, RunnerError};
 use crate::runner::template::eval_template;
 use crate::runner::{filter, Number, Value};
@@ -29,15 +30,20 @@ use crate::runner::{filter, Number, Value};
 pub type QueryResult = Result<Option<Value>, Error>;
 
 /// Evaluates this `query` and returns a [`QueryResult`], using the HTTP response `http_response` and `variables`.
+///
+/// Header names are matched against the response headers with `header_match`.
 pub fn eval_query(
     query: &Query,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    header_match: HeaderMatch,
 ) -> QueryResult {
     match query.value.clone() {
         QueryValue::Status => eval_query_status(http_response),
         QueryValue::Url => eval_query_url(http_response),
-        QueryValue::Header { name, .. } => eval_query_header(http_response, &name, variables),
+        QueryValue::Header { name, .. } => {
+            eval_query_header(http_response, &name, variables, header_match)
+        }
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
             ..
@@ -49,18 +55,36 @@ pub fn eval_query(
         QueryValue::Jsonpath { expr, .. } => {
             eval_query_jsonpath(http_response, &expr, variables, query.source_info)
         }
+        QueryValue::Jsonpointer { expr, .. } => {
+            eval_query_jsonpointer(http_response, &expr, variables, query.source_info)
+        }
         QueryValue::Regex { value, .. } => {
             eval_query_regex(http_response, &value, variables, query.source_info)
         }
         QueryValue::Variable { name, .. } => eval_query_variable(&name, variables),
         QueryValue::Duration => eval_query_duration(http_response),
         QueryValue::Bytes => eval_query_bytes(http_response, query.source_info),
+        QueryValue::BodyBytes => eval_query_body_bytes(http_response),
         QueryValue::Sha256 => eval_query_sha256(http_response, query.source_info),
         QueryValue::Md5 => eval_query_md5(http_response, query.source_info),
+        QueryValue::ContentType => eval_query_content_type(http_response, query.source_info),
+        QueryValue::CompressionRatio => {
+            eval_query_compression_ratio(http_response, query.source_info)
+        }
+        QueryValue::Raw => eval_query_raw(http_response, query.source_info),
         QueryValue::Certificate {
             attribute_name: field,
             ..
         } => eval_query_certificate(http_response, field),
+        QueryValue::Transfer {
+            attribute_name: field,
+            ..
+        } => eval_query_transfer(http_response, field),
+        QueryValue::TransferEncoding => eval_query_transfer_encoding(http_response),
+        QueryValue::Link { rel, .. } => eval_query_link(http_response, &rel, variables),
+        QueryValue::HeaderCount { name, .. } => {
+            eval_query_header_count(http_response, &name, variables, header_match)
+        }
     }
 }
 
@@ -78,9 +102,10 @@ fn eval_query_header(
     response: &http::Response,
     name: &Template,
     variables: &HashMap<String, Value>,
+    header_match: HeaderMatch,
 ) -> QueryResult {
     let name = eval_template(name, variables)?;
-    let values = response.headers.values(&name);
+    let values = response.headers.values_matching(&name, header_match);
     if values.is_empty() {
         Ok(None)
     } else if values.len() == 1 {
@@ -95,6 +120,18 @@ fn eval_query_header(
     }
 }
 
+/// Returns the number of occurrences of the header `name` in the `response`.
+fn eval_query_header_count(
+    response: &http::Response,
+    name: &Template,
+    variables: &HashMap<String, Value>,
+    header_match: HeaderMatch,
+) -> QueryResult {
+    let name = eval_template(name, variables)?;
+    let count = response.headers.values_matching(&name, header_match).len();
+    Ok(Some(Value::Number(Number::Integer(count as i64))))
+}
+
 fn eval_query_cookie(
     response: &http::Response,
     name: &Template,
@@ -149,6 +186,18 @@ fn eval_query_jsonpath(
     }
 }
 
+fn eval_query_jsonpointer(
+    response: &http::Response,
+    expr: &Template,
+    variables: &HashMap<String, Value>,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    match response.text() {
+        Ok(json) => filter::eval_jsonpointer_string(&json, expr, variables, query_source_info),
+        Err(inner) => Err(Error::new(query_source_info, inner.into(), false)),
+    }
+}
+
 fn eval_query_regex(
     response: &http::Response,
     regex: &RegexValue,
@@ -193,13 +242,46 @@ fn eval_query_duration(response: &http::Response) -> QueryResult {
     ))))
 }
 
+fn eval_query_transfer(
+    response: &http::Response,
+    transfer_attribute: TransferAttributeName,
+) -> QueryResult {
+    let bytes = match transfer_attribute {
+        TransferAttributeName::Sent => response.bytes_sent,
+        TransferAttributeName::Received => response.bytes_received,
+    };
+    Ok(Some(Value::Number(Number::Integer(bytes as i64))))
+}
+
+/// Returns the transfer encodings of this `response` (like `chunked`), or `identity` if the body
+/// has been transferred as is.
+fn eval_query_transfer_encoding(response: &http::Response) -> QueryResult {
+    Ok(Some(Value::String(response.transfer_encoding())))
+}
+
+/// Returns the URL of the first link with the relation type `rel` in the `Link` headers of this
+/// `response`. Absolute URLs are returned as is, relative URLs are resolved against the response URL.
+fn eval_query_link(
+    response: &http::Response,
+    rel: &Template,
+    variables: &HashMap<String, Value>,
+) -> QueryResult {
+    let rel = eval_template(rel, variables)?;
+    Ok(response.get_link_url(&rel).map(Value::String))
+}
+
 fn eval_query_bytes(response: &http::Response, query_source_info: SourceInfo) -> QueryResult {
     match response.uncompress_body() {
-        Ok(s) => Ok(Some(Value::Bytes(s))),
+        Ok(s) => Ok(Some(Value::Bytes(s.to_vec()))),
         Err(inner) => Err(Error::new(query_source_info, inner.into(), false)),
     }
 }
 
+/// Returns the raw bytes of the response body, as received (without any decompression).
+fn eval_query_body_bytes(response: &http::Response) -> QueryResult {
+    Ok(Some(Value::Bytes(response.body.to_vec())))
+}
+
 fn eval_query_sha256(response: &http::Response, query_source_info: SourceInfo) -> QueryResult {
     let bytes = match response.uncompress_body() {
         Ok(s) => s,
@@ -225,6 +307,46 @@ fn eval_query_md5(response: &http::Response, query_source_info: SourceInfo) -> Q
     Ok(Some(Value::Bytes(bytes)))
 }
 
+fn eval_query_content_type(
+    response: &http::Response,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    match response.sniff_content_type() {
+        Ok(content_type) => Ok(content_type.map(|c| Value::String(c.to_string()))),
+        Err(inner) => Err(Error::new(query_source_info, inner.into(), false)),
+    }
+}
+
+/// Returns the ratio between the size of the response body as received (compressed if the response
+/// has a `Content-Encoding`) and the size of the decompressed body.
+fn eval_query_compression_ratio(
+    response: &http::Response,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    let bytes = match response.uncompress_body() {
+        Ok(s) => s,
+        Err(inner) => {
+            return Err(Error::new(query_source_info, inner.into(), false));
+        }
+    };
+    let ratio = if bytes.is_empty() {
+        1.0
+    } else {
+        response.body.len() as f64 / bytes.len() as f64
+    };
+    Ok(Some(Value::Number(Number::Float(ratio))))
+}
+
+/// Returns the raw HTTP response: status line, headers, an empty line and the decoded body.
+fn eval_query_raw(response: &http::Response, query_source_info: SourceInfo) -> QueryResult {
+    let body = match response.text() {
+        Ok(s) => s,
+        Err(inner) => return Err(Error::new(query_source_info, inner.into(), false)),
+    };
+    let raw = format!("{}\n{body}", response.get_status_line_headers(false, false));
+    Ok(Some(Value::String(raw)))
+}
+
 fn eval_query_certificate(
     response: &http::Response,
     certificate_attribute: CertificateAttributeName,
@@ -528,6 +650,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                HeaderMatch::default(),
             )
             .unwrap()
             .unwrap(),
@@ -560,7 +683,13 @@ pub mod tests {
         //    assert_eq!(error.source_info.start, Pos { line: 1, column: 8 });
         //    assert_eq!(error.inner, RunnerError::QueryHeaderNotFound);
         assert_eq!(
-            eval_query(&query_header, &variables, &http::hello_http_response()).unwrap(),
+            eval_query(
+                &query_header,
+                &variables,
+                &http::hello_http_response(),
+                HeaderMatch::default(),
+            )
+            .unwrap(),
             None
         );
     }
@@ -587,13 +716,57 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query_header, &variables, &http::hello_http_response())
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &query_header,
+                &variables,
+                &http::hello_http_response(),
+                HeaderMatch::default(),
+            )
+            .unwrap()
+            .unwrap(),
             Value::String(String::from("text/html; charset=utf-8"))
         );
     }
 
+    #[test]
+    fn test_header_match() {
+        // header "content-type"
+        let variables = HashMap::new();
+        let query_header = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Header {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                name: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "content-type".to_string(),
+                        encoded: "content-type".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 22)),
+                },
+            },
+        };
+        let response = http::hello_http_response();
+        assert_eq!(
+            eval_query(
+                &query_header,
+                &variables,
+                &response,
+                HeaderMatch::CaseInsensitive
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String(String::from("text/html; charset=utf-8"))
+        );
+        assert_eq!(
+            eval_query(&query_header, &variables, &response, HeaderMatch::Exact).unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn test_query_cookie() {
         let variables = HashMap::new();
@@ -628,7 +801,9 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response).unwrap().unwrap(),
+            eval_query(&query, &variables, &response, HeaderMatch::default())
+                .unwrap()
+                .unwrap(),
             Value::String("DQAAAKEaem_vYg".to_string())
         );
 
@@ -655,7 +830,9 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response).unwrap().unwrap(),
+            eval_query(&query, &variables, &response, HeaderMatch::default())
+                .unwrap()
+                .unwrap(),
             Value::String("/accounts".to_string())
         );
 
@@ -682,7 +859,9 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response).unwrap().unwrap(),
+            eval_query(&query, &variables, &response, HeaderMatch::default())
+                .unwrap()
+                .unwrap(),
             Value::Unit
         );
 
@@ -708,7 +887,10 @@ pub mod tests {
                 },
             },
         };
-        assert_eq!(eval_query(&query, &variables, &response).unwrap(), None);
+        assert_eq!(
+            eval_query(&query, &variables, &response, HeaderMatch::default()).unwrap(),
+            None
+        );
     }
 
     #[test]
@@ -804,6 +986,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                HeaderMatch::default(),
             )
             .unwrap()
             .unwrap(),
@@ -816,6 +999,7 @@ pub mod tests {
             },
             &variables,
             &http::bytes_http_response(),
+            HeaderMatch::default(),
         )
         .err()
         .unwrap();
@@ -835,12 +1019,17 @@ pub mod tests {
     fn test_query_invalid_utf8() {
         let variables = HashMap::new();
         let http_response = http::Response {
-            body: vec![200],
+            body: vec![200].into(),
             ..Default::default()
         };
-        let error = eval_query(&xpath_users(), &variables, &http_response)
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &xpath_users(),
+            &variables,
+            &http_response,
+            HeaderMatch::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
         assert_eq!(
             error.inner,
@@ -871,9 +1060,14 @@ pub mod tests {
                 },
             },
         };
-        let error = eval_query(&query, &variables, &http::xml_two_users_http_response())
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &query,
+            &variables,
+            &http::xml_two_users_http_response(),
+            HeaderMatch::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.inner, RunnerError::QueryInvalidXpathEval);
         assert_eq!(error.source_info.start, Pos { line: 1, column: 7 });
     }
@@ -887,6 +1081,7 @@ pub mod tests {
                 &xpath_users(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                HeaderMatch::default(),
             )
             .unwrap()
             .unwrap(),
@@ -897,6 +1092,7 @@ pub mod tests {
                 &xpath_count_user_query(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                HeaderMatch::default(),
             )
             .unwrap()
             .unwrap(),
@@ -935,6 +1131,7 @@ pub mod tests {
                 &xpath_html_charset(),
                 &variables,
                 &http::html_http_response(),
+                HeaderMatch::default(),
             )
             .unwrap()
             .unwrap(),
@@ -965,9 +1162,14 @@ pub mod tests {
             },
         };
 
-        let error = eval_query(&jsonpath_query, &variables, &http::json_http_response())
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &jsonpath_query,
+            &variables,
+            &http::json_http_response(),
+            HeaderMatch::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.source_info.start,
             Pos {
@@ -987,12 +1189,17 @@ pub mod tests {
     fn test_query_invalid_json() {
         let variables = HashMap::new();
         let http_response = http::Response {
-            body: String::into_bytes(String::from("xxx")),
+            body: String::into_bytes(String::from("xxx")).into(),
             ..Default::default()
         };
-        let error = eval_query(&jsonpath_success(), &variables, &http_response)
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &jsonpath_success(),
+            &variables,
+            &http_response,
+            HeaderMatch::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
         assert_eq!(error.inner, RunnerError::QueryInvalidJson);
     }
@@ -1001,12 +1208,18 @@ pub mod tests {
     fn test_query_json_not_found() {
         let variables = HashMap::new();
         let http_response = http::Response {
-            body: String::into_bytes(String::from("{}")),
+            body: String::into_bytes(String::from("{}")).into(),
             ..Default::default()
         };
         //assert_eq!(jsonpath_success().eval(http_response).unwrap(), Value::List(vec![]));
         assert_eq!(
-            eval_query(&jsonpath_success(), &variables, &http_response).unwrap(),
+            eval_query(
+                &jsonpath_success(),
+                &variables,
+                &http_response,
+                HeaderMatch::default(),
+            )
+            .unwrap(),
             None
         );
     }
@@ -1015,15 +1228,25 @@ pub mod tests {
     fn test_query_json() {
         let variables = HashMap::new();
         assert_eq!(
-            eval_query(&jsonpath_success(), &variables, &http::json_http_response())
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &jsonpath_success(),
+                &variables,
+                &http::json_http_response(),
+                HeaderMatch::default(),
+            )
+            .unwrap()
+            .unwrap(),
             Value::Bool(false)
         );
         assert_eq!(
-            eval_query(&jsonpath_errors(), &variables, &http::json_http_response())
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &jsonpath_errors(),
+                &variables,
+                &http::json_http_response(),
+                HeaderMatch::default(),
+            )
+            .unwrap()
+            .unwrap(),
             Value::List(vec![
                 Value::Object(vec![(
                     String::from("id"),
@@ -1041,15 +1264,25 @@ pub mod tests {
     fn test_query_regex() {
         let variables = HashMap::new();
         assert_eq!(
-            eval_query(&regex_name(), &variables, &http::hello_http_response())
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &regex_name(),
+                &variables,
+                &http::hello_http_response(),
+                HeaderMatch::default(),
+            )
+            .unwrap()
+            .unwrap(),
             Value::String("World".to_string())
         );
 
-        let error = eval_query(&regex_invalid(), &variables, &http::hello_http_response())
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &regex_invalid(),
+            &variables,
+            &http::hello_http_response(),
+            HeaderMatch::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.source_info,
             SourceInfo::new(Pos::new(1, 7), Pos::new(1, 10))
@@ -1068,6 +1301,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                HeaderMatch::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1086,9 +1320,10 @@ pub mod tests {
                 },
                 &variables,
                 &http::Response {
-                    body: vec![0xff],
+                    body: vec![0xff].into(),
                     ..Default::default()
-                }
+                },
+                HeaderMatch::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1098,6 +1333,52 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_content_type() {
+        let variables = HashMap::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::ContentType,
+        };
+        let png = vec![0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00];
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &http::Response {
+                    body: png.into(),
+                    ..Default::default()
+                },
+                HeaderMatch::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("image/png".to_string())
+        );
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &http::Response {
+                    body: b"%PDF-1.4\n".as_slice().into(),
+                    ..Default::default()
+                },
+                HeaderMatch::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("application/pdf".to_string())
+        );
+        assert!(eval_query(
+            &query,
+            &variables,
+            &http::hello_http_response(),
+            HeaderMatch::default(),
+        )
+        .unwrap()
+        .is_none());
+    }
+
     #[test]
     fn test_query_certificate() {
         assert!(eval_query_certificate(
@@ -1127,4 +1408,238 @@ pub mod tests {
             Value::String("A=B, C=D".to_string())
         );
     }
+
+    #[test]
+    fn test_query_transfer() {
+        let response = http::Response {
+            body: b"Hello World!".as_slice().into(),
+            bytes_sent: 5,
+            bytes_received: 12,
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_transfer(&response, TransferAttributeName::Sent)
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(5))
+        );
+        assert_eq!(
+            eval_query_transfer(&response, TransferAttributeName::Received)
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(12))
+        );
+    }
+
+    #[test]
+    fn test_query_transfer_encoding() {
+        assert_eq!(
+            eval_query_transfer_encoding(&http::hello_http_response())
+                .unwrap()
+                .unwrap(),
+            Value::String("identity".to_string())
+        );
+
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new("Transfer-Encoding", "chunked"));
+        let response = http::Response {
+            headers,
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_transfer_encoding(&response).unwrap().unwrap(),
+            Value::String("chunked".to_string())
+        );
+    }
+
+    #[test]
+    fn test_query_link() {
+        let mut headers = http::HeaderVec::new();
+        headers.push(http::Header::new(
+            "Link",
+            r#"<https://x?page=2>; rel="next", </items?page=9>; rel="last""#,
+        ));
+        let response = http::Response {
+            headers,
+            url: "https://x/items?page=1".to_string(),
+            ..Default::default()
+        };
+        let template = |rel: &str| Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: rel.to_string(),
+                encoded: rel.to_string(),
+            }],
+            source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 12)),
+        };
+        let variables = HashMap::new();
+        assert_eq!(
+            eval_query_link(&response, &template("next"), &variables)
+                .unwrap()
+                .unwrap(),
+            Value::String("https://x?page=2".to_string())
+        );
+        // Relative links are resolved against the response URL.
+        assert_eq!(
+            eval_query_link(&response, &template("last"), &variables)
+                .unwrap()
+                .unwrap(),
+            Value::String("https://x/items?page=9".to_string())
+        );
+        assert_eq!(
+            eval_query_link(&response, &template("prev"), &variables).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_body_bytes() {
+        use std::io::Write;
+
+        let body = "Hello World!";
+        let mut encoder = libflate::gzip::Encoder::new(Vec::new()).unwrap();
+        encoder.write_all(body.as_bytes()).unwrap();
+        let compressed = encoder.finish().into_result().unwrap();
+
+        let mut headers = http::HeaderVec::new();
+        headers.push(http::Header::new("Content-Encoding", "gzip"));
+        let response = http::Response {
+            headers,
+            body: compressed.clone().into(),
+            ..Default::default()
+        };
+        let query = |value| Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+            value,
+        };
+        let variables = HashMap::new();
+
+        // The body bytes are kept as received, contrary to `bytes` that decompresses the body.
+        assert_eq!(
+            eval_query(
+                &query(QueryValue::BodyBytes),
+                &variables,
+                &response,
+                HeaderMatch::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(compressed)
+        );
+        assert_eq!(
+            eval_query(
+                &query(QueryValue::Bytes),
+                &variables,
+                &response,
+                HeaderMatch::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(body.as_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn test_query_compression_ratio() {
+        use std::io::Write;
+
+        let body = "Hello World! ".repeat(100);
+        let mut encoder = libflate::gzip::Encoder::new(Vec::new()).unwrap();
+        encoder.write_all(body.as_bytes()).unwrap();
+        let compressed = encoder.finish().into_result().unwrap();
+
+        let mut headers = http::HeaderVec::new();
+        headers.push(http::Header::new("Content-Encoding", "gzip"));
+        let response = http::Response {
+            headers,
+            body: compressed.clone().into(),
+            ..Default::default()
+        };
+        let ratio = match eval_query_compression_ratio(
+            &response,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 17)),
+        )
+        .unwrap()
+        .unwrap()
+        {
+            Value::Number(Number::Float(ratio)) => ratio,
+            value => panic!("unexpected value {value:?}"),
+        };
+        assert_eq!(ratio, compressed.len() as f64 / body.len() as f64);
+        assert!(ratio < 0.5);
+
+        // Uncompressed responses have a ratio of 1.
+        let response = http::Response {
+            body: body.into_bytes().into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_compression_ratio(
+                &response,
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 17))
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(1.0))
+        );
+    }
+
+    #[test]
+    fn test_query_raw() {
+        assert_eq!(
+            eval_query_raw(
+                &http::hello_http_response(),
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 4))
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String(
+                "HTTP/1.0 200\n\
+                 Content-Type: text/html; charset=utf-8\n\
+                 Content-Length: 12\n\
+                 \n\
+                 Hello World!"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_query_header_count() {
+        let variables = HashMap::new();
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new("x-foo", "xxx"));
+        headers.push(http::Header::new("x-bar", "yyy0"));
+        headers.push(http::Header::new("x-bar", "yyy1"));
+        headers.push(http::Header::new("x-bar", "yyy2"));
+        headers.push(http::Header::new("x-baz", "zzz"));
+        let response = http::Response {
+            headers,
+            ..Default::default()
+        };
+        let header_count = |name: &str, header_match: HeaderMatch| {
+            let name = Template {
+                delimiter: Some('"'),
+                elements: vec![TemplateElement::String {
+                    value: name.to_string(),
+                    encoded: name.to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 20)),
+            };
+            eval_query_header_count(&response, &name, &variables, header_match)
+                .unwrap()
+                .unwrap()
+        };
+
+        let ci = HeaderMatch::CaseInsensitive;
+        assert_eq!(header_count("x-foo", ci), Value::Number(Number::Integer(1)));
+        assert_eq!(header_count("x-bar", ci), Value::Number(Number::Integer(3)));
+        assert_eq!(header_count("X-Bar", ci), Value::Number(Number::Integer(3)));
+        assert_eq!(header_count("x-qux", ci), Value::Number(Number::Integer(0)));
+        let exact = HeaderMatch::Exact;
+        assert_eq!(
+            header_count("X-Bar", exact),
+            Value::Number(Number::Integer(0))
+        );
+    }
 }
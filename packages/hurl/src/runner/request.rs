@@ -23,7 +23,7 @@ use hurl_core::ast::*;
 
 use crate::http;
 use crate::http::{HeaderVec, AUTHORIZATION};
-use crate::runner::error::Error;
+use crate::runner::error::{Error, RunnerError};
 use crate::runner::value::Value;
 use crate::runner::{body, multipart, template};
 use crate::util::path::ContextDir;
@@ -34,7 +34,7 @@ pub fn eval_request(
     variables: &HashMap<String, Value>,
     context_dir: &ContextDir,
 ) -> Result<http::RequestSpec, Error> {
-    let method = eval_method(&request.method);
+    let method = eval_method(&request.method, variables)?;
     let url = template::eval_template(&request.url, variables)?;
 
     // Headers
@@ -78,11 +78,14 @@ pub fn eval_request(
 
     // Cookies
     let mut cookies = vec![];
+    let mut scoped_cookies = vec![];
     for cookie in &request.cookies() {
         let name = template::eval_template(&cookie.name, variables)?;
         let value = template::eval_template(&cookie.value, variables)?;
-        let cookie = http::RequestCookie { name, value };
-        cookies.push(cookie);
+        match eval_scoped_cookie(&name, &value, &url) {
+            Some(cookie) => scoped_cookies.push(cookie),
+            None => cookies.push(http::RequestCookie { name, value }),
+        }
     }
 
     let body = match &request.body {
@@ -127,11 +130,53 @@ pub fn eval_request(
         form,
         multipart,
         cookies,
+        scoped_cookies,
         body,
         implicit_content_type,
     })
 }
 
+/// Returns a cookie scoped by domain and path if the cookie `value` has `Domain` or `Path`
+/// attributes (ex: `abc123; Path=/api; Domain=example.org`), or `None` for a plain cookie.
+///
+/// As with a `Set-Cookie` header, a cookie without `Domain` is only sent to the host of `url`,
+/// while a cookie with `Domain` is also sent to its subdomains. The default path is `/`.
+fn eval_scoped_cookie(name: &str, value: &str, url: &str) -> Option<http::Cookie> {
+    let mut parts = value.split(';');
+    let value = parts.next().unwrap_or_default().trim();
+    let mut domain = None;
+    let mut path = None;
+    for attribute in parts {
+        let (attr_name, attr_value) = attribute.split_once('=')?;
+        let attr_value = attr_value.trim().to_string();
+        match attr_name.trim().to_lowercase().as_str() {
+            "domain" => domain = Some(attr_value),
+            "path" => path = Some(attr_value),
+            _ => return None,
+        }
+    }
+    if domain.is_none() && path.is_none() {
+        return None;
+    }
+    let (domain, include_subdomain) = match domain {
+        Some(domain) => (domain.trim_start_matches('.').to_string(), "TRUE"),
+        None => {
+            let url = url::Url::parse(url).ok()?;
+            (url.host_str()?.to_string(), "FALSE")
+        }
+    };
+    Some(http::Cookie {
+        domain,
+        include_subdomain: include_subdomain.to_string(),
+        path: path.unwrap_or_else(|| "/".to_string()),
+        https: "FALSE".to_string(),
+        expires: "0".to_string(),
+        name: name.to_string(),
+        value: value.to_string(),
+        http_only: false,
+    })
+}
+
 /// Experimental feature
 /// @cookie_storage_add
 pub fn cookie_storage_set(request: &Request) -> Option<String> {
@@ -160,15 +205,28 @@ pub fn cookie_storage_clear(request: &Request) -> bool {
     false
 }
 
-fn eval_method(method: &Method) -> http::Method {
-    http::Method(method.0.clone())
+/// Evaluates an AST `method` given a set of `variables`.
+///
+/// A method evaluated from an expression must be made of uppercase letters, like a literal method.
+fn eval_method(method: &Method, variables: &HashMap<String, Value>) -> Result<http::Method, Error> {
+    match method {
+        Method::Literal(name) => Ok(http::Method(name.clone())),
+        Method::Expression(expr) => {
+            let name = template::render_expression(expr, variables)?;
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_uppercase()) {
+                let source_info = expr.variable.source_info;
+                let inner = RunnerError::InvalidMethod(name);
+                return Err(Error::new(source_info, inner, false));
+            }
+            Ok(http::Method(name))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use hurl_core::ast::SourceInfo;
 
-    use super::super::error::RunnerError;
     use super::*;
 
     fn whitespace() -> Whitespace {
@@ -187,7 +245,7 @@ mod tests {
         Request {
             line_terminators: vec![],
             space0: whitespace(),
-            method: Method("GET".to_string()),
+            method: Method::Literal("GET".to_string()),
             space1: whitespace(),
             url: Template {
                 elements: vec![
@@ -241,7 +299,7 @@ mod tests {
         Request {
             line_terminators: vec![],
             space0: whitespace(),
-            method: Method("GET".to_string()),
+            method: Method::Literal("GET".to_string()),
             space1: whitespace(),
             url: Template {
                 elements: vec![TemplateElement::String {
@@ -336,6 +394,41 @@ mod tests {
         assert_eq!(http_request, http::hello_http_request());
     }
 
+    #[test]
+    fn test_method_expression() {
+        let method = Method::Expression(Expr {
+            space0: whitespace(),
+            variable: Variable {
+                name: String::from("method"),
+                source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 9)),
+            },
+            space1: whitespace(),
+        });
+        let mut variables = HashMap::new();
+        variables.insert(
+            String::from("method"),
+            Value::String(String::from("DELETE")),
+        );
+        assert_eq!(
+            eval_method(&method, &variables).unwrap(),
+            http::Method("DELETE".to_string())
+        );
+
+        variables.insert(
+            String::from("method"),
+            Value::String(String::from("delete")),
+        );
+        let error = eval_method(&method, &variables).err().unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 3), Pos::new(1, 9))
+        );
+        assert_eq!(
+            error.inner,
+            RunnerError::InvalidMethod("delete".to_string())
+        );
+    }
+
     #[test]
     fn test_query_request() {
         let mut variables = HashMap::new();
@@ -367,7 +460,7 @@ mod tests {
                 newline: whitespace(),
             }],
             space0: whitespace(),
-            method: Method("GET".to_string()),
+            method: Method::Literal("GET".to_string()),
             space1: whitespace(),
             url: Template {
                 elements: vec![TemplateElement::String {
@@ -408,7 +501,7 @@ mod tests {
                     newline: whitespace(),
                 }],
                 space0: whitespace(),
-                method: Method("GET".to_string()),
+                method: Method::Literal("GET".to_string()),
                 space1: whitespace(),
                 url: Template {
                     elements: vec![TemplateElement::String {
@@ -426,4 +519,27 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_eval_scoped_cookie() {
+        assert!(eval_scoped_cookie("theme", "light", "http://localhost/api").is_none());
+        assert!(eval_scoped_cookie("theme", "a; b", "http://localhost/api").is_none());
+
+        let cookie = eval_scoped_cookie("token", "abc123; Path=/api", "http://localhost/api");
+        let cookie = cookie.unwrap();
+        assert_eq!(cookie.value, "abc123");
+        assert_eq!(cookie.domain, "localhost");
+        assert_eq!(cookie.include_subdomain, "FALSE");
+        assert_eq!(cookie.path, "/api");
+
+        let cookie = eval_scoped_cookie(
+            "token",
+            "abc123; Domain=.example.org",
+            "http://localhost/api",
+        );
+        let cookie = cookie.unwrap();
+        assert_eq!(cookie.domain, "example.org");
+        assert_eq!(cookie.include_subdomain, "TRUE");
+        assert_eq!(cookie.path, "/");
+    }
 }
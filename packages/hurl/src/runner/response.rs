@@ -17,12 +17,15 @@
  */
 use std::collections::HashMap;
 
+use chrono::FixedOffset;
 use hurl_core::ast::*;
 
 use crate::http;
+use crate::http::HeaderMatch;
+use crate::runner::assert::AssertOptions;
 use crate::runner::error::{Error, RunnerError};
 use crate::runner::result::{AssertResult, CaptureResult};
-use crate::runner::{assert, body, capture, json, multiline, template, Value};
+use crate::runner::{assert, body, capture, json, multiline, template, value, Value};
 use crate::util::path::ContextDir;
 
 /// Returns a list of assert results on the response status code and HTTP version,
@@ -54,118 +57,174 @@ pub fn eval_version_status_asserts(
 /// Returns a list of assert results, given a set of `variables`, an actual `http_response` and a spec `response`.
 ///
 /// Asserts on status and version and not run in this function, there are run with `eval_version_status_asserts`
-/// as they're semantically stronger.
+/// as they're semantically stronger. Asserts are evaluated with the assert `options`.
 pub fn eval_asserts(
     response: &Response,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    options: &AssertOptions,
     context_dir: &ContextDir,
 ) -> Vec<AssertResult> {
     let mut asserts = vec![];
 
     // First, evaluates implicit asserts on response headers.
     for header in &response.headers {
-        match template::eval_template(&header.value, variables) {
-            Err(e) => {
-                let result = AssertResult::Header {
-                    actual: Err(e),
-                    expected: String::new(),
-                    source_info: header.key.source_info,
-                };
-                asserts.push(result);
-            }
-            Ok(expected) => {
-                match template::eval_template(&header.key, variables) {
-                    Ok(header_name) => {
-                        let actuals = http_response.headers.values(&header_name);
-                        if actuals.is_empty() {
-                            let result = AssertResult::Header {
-                                actual: Err(Error::new(
-                                    header.key.source_info,
-                                    RunnerError::QueryHeaderNotFound,
-                                    false,
-                                )),
-                                expected,
-                                source_info: header.key.source_info,
-                            };
-                            asserts.push(result);
-                        } else if actuals.len() == 1 {
-                            let actual = actuals.first().unwrap().to_string();
-                            let result = AssertResult::Header {
-                                actual: Ok(actual),
-                                expected,
-                                source_info: header.value.source_info,
-                            };
-                            asserts.push(result);
-                        } else {
-                            // failure by default
-                            // expected value not found in the list
-                            // actual is therefore the full list
-                            let mut actual = format!(
-                                "[{}]",
-                                actuals
-                                    .iter()
-                                    .map(|v| format!("\"{v}\""))
-                                    .collect::<Vec<_>>()
-                                    .join(", ")
-                            );
-                            for value in actuals {
-                                if value == expected {
-                                    actual = value.to_string();
-                                    break;
-                                }
-                            }
-                            let result = AssertResult::Header {
-                                actual: Ok(actual),
-                                expected,
-                                source_info: header.value.source_info,
-                            };
-                            asserts.push(result);
-                        }
-                    }
-                    Err(e) => {
-                        let result = AssertResult::Header {
-                            actual: Err(e),
-                            expected,
-                            source_info: header.value.source_info,
-                        };
-                        asserts.push(result);
-                    }
-                }
-            }
+        let assert =
+            eval_implicit_header_assert(header, variables, http_response, options.header_match);
+        let failed = assert.error().is_some();
+        asserts.push(assert);
+        if options.abort_on_first_failure && failed {
+            return asserts;
         }
     }
 
     // Second, evaluates implicit asserts on response body.
     if let Some(body) = &response.body {
-        let assert = eval_implicit_body_asserts(body, variables, http_response, context_dir);
+        let assert =
+            eval_implicit_body_asserts(body, variables, http_response, options, context_dir);
+        let failed = assert.error().is_some();
         asserts.push(assert);
+        if options.abort_on_first_failure && failed {
+            return asserts;
+        }
     }
 
     // Then, checks all the explicit asserts.
     for assert in &response.asserts() {
         let assert_result =
-            assert::eval_explicit_assert(assert, variables, http_response, context_dir);
+            assert::eval_explicit_assert(assert, variables, http_response, options, context_dir);
+        let failed = assert_result.error().is_some();
         asserts.push(assert_result);
+        if options.abort_on_first_failure && failed {
+            return asserts;
+        }
     }
     asserts
 }
 
+/// Returns a list of soft assert results, given a set of `variables`, an actual `http_response`
+/// and a spec `response`.
+///
+/// Soft asserts are evaluated with the same assert `options` as the other asserts, but are never
+/// aborted on a failure.
+pub fn eval_soft_asserts(
+    response: &Response,
+    variables: &HashMap<String, Value>,
+    http_response: &http::Response,
+    options: &AssertOptions,
+    context_dir: &ContextDir,
+) -> Vec<AssertResult> {
+    response
+        .soft_asserts()
+        .iter()
+        .map(|a| assert::eval_soft_assert(a, variables, http_response, options, context_dir))
+        .collect()
+}
+
+/// Check a header of an actual HTTP response against a spec header, given a set of variables.
+fn eval_implicit_header_assert(
+    header: &Header,
+    variables: &HashMap<String, Value>,
+    http_response: &http::Response,
+    header_match: HeaderMatch,
+) -> AssertResult {
+    match template::eval_template(&header.value, variables) {
+        Err(e) => AssertResult::Header {
+            actual: Err(e),
+            expected: String::new(),
+            source_info: header.key.source_info,
+        },
+        Ok(expected) => {
+            match template::eval_template(&header.key, variables) {
+                Ok(header_name) => {
+                    let actuals = http_response
+                        .headers
+                        .values_matching(&header_name, header_match);
+                    if actuals.is_empty() {
+                        AssertResult::Header {
+                            actual: Err(Error::new(
+                                header.key.source_info,
+                                RunnerError::QueryHeaderNotFound,
+                                false,
+                            )),
+                            expected,
+                            source_info: header.key.source_info,
+                        }
+                    } else if actuals.len() == 1 {
+                        let actual = actuals.first().unwrap().to_string();
+                        AssertResult::Header {
+                            actual: Ok(actual),
+                            expected,
+                            source_info: header.value.source_info,
+                        }
+                    } else {
+                        // failure by default
+                        // expected value not found in the list
+                        // actual is therefore the full list
+                        let mut actual = format!(
+                            "[{}]",
+                            actuals
+                                .iter()
+                                .map(|v| format!("\"{v}\""))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        );
+                        for value in actuals {
+                            if value == expected {
+                                actual = value.to_string();
+                                break;
+                            }
+                        }
+                        AssertResult::Header {
+                            actual: Ok(actual),
+                            expected,
+                            source_info: header.value.source_info,
+                        }
+                    }
+                }
+                Err(e) => AssertResult::Header {
+                    actual: Err(e),
+                    expected,
+                    source_info: header.value.source_info,
+                },
+            }
+        }
+    }
+}
+
 /// Check the body of an actual HTTP response against a spec body, given a set of variables.
 fn eval_implicit_body_asserts(
     spec_body: &Body,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    options: &AssertOptions,
     context_dir: &ContextDir,
 ) -> AssertResult {
+    let normalize_newlines = options.normalize_newlines;
+    let normalize_json_numbers = options.normalize_json_numbers;
+    let normalize_text = |s: String| {
+        if normalize_newlines {
+            value::normalize_newlines(&s)
+        } else {
+            s
+        }
+    };
+    let normalize_json = |s: String| {
+        let s = normalize_text(s);
+        if normalize_json_numbers {
+            json::normalize_json_numbers(&s)
+        } else {
+            s
+        }
+    };
     match &spec_body.value {
         Bytes::Json(value) => {
             let expected = match json::eval_json_value(value, variables, true) {
-                Ok(s) => Ok(Value::String(s)),
+                Ok(s) => Ok(Value::String(normalize_json(s))),
                 Err(e) => Err(e),
             };
             let actual = match http_response.text() {
-                Ok(s) => Ok(Value::String(s)),
+                Ok(s) => Ok(Value::String(normalize_json(s))),
                 Err(e) => {
                     let source_info = SourceInfo {
                         start: spec_body.space0.source_info.end,
@@ -181,9 +240,9 @@ fn eval_implicit_body_asserts(
             }
         }
         Bytes::Xml(value) => {
-            let expected = Ok(Value::String(value.to_string()));
+            let expected = Ok(Value::String(normalize_text(value.to_string())));
             let actual = match http_response.text() {
-                Ok(s) => Ok(Value::String(s)),
+                Ok(s) => Ok(Value::String(normalize_text(s))),
                 Err(e) => {
                     let source_info = SourceInfo {
                         start: spec_body.space0.source_info.end,
@@ -200,11 +259,11 @@ fn eval_implicit_body_asserts(
         }
         Bytes::OnelineString(value) => {
             let expected = match template::eval_template(value, variables) {
-                Ok(s) => Ok(Value::String(s)),
+                Ok(s) => Ok(Value::String(normalize_text(s))),
                 Err(e) => Err(e),
             };
             let actual = match http_response.text() {
-                Ok(s) => Ok(Value::String(s)),
+                Ok(s) => Ok(Value::String(normalize_text(s))),
                 Err(e) => {
                     let source_info = SourceInfo {
                         start: spec_body.space0.source_info.end,
@@ -221,11 +280,11 @@ fn eval_implicit_body_asserts(
         }
         Bytes::MultilineString(multi) => {
             let expected = match multiline::eval_multiline(multi, variables) {
-                Ok(s) => Ok(Value::String(s)),
+                Ok(s) => Ok(Value::String(normalize_text(s))),
                 Err(e) => Err(e),
             };
             let actual = match http_response.text() {
-                Ok(s) => Ok(Value::String(s)),
+                Ok(s) => Ok(Value::String(normalize_text(s))),
                 Err(e) => {
                     let source_info = SourceInfo {
                         start: spec_body.space0.source_info.end,
@@ -248,7 +307,7 @@ fn eval_implicit_body_asserts(
         }) => {
             let expected = Ok(Value::Bytes(value.to_vec()));
             let actual = match http_response.uncompress_body() {
-                Ok(b) => Ok(Value::Bytes(b)),
+                Ok(b) => Ok(Value::Bytes(b.to_vec())),
                 Err(e) => {
                     let source_info = SourceInfo {
                         start: spec_body.space0.source_info.end,
@@ -274,7 +333,7 @@ fn eval_implicit_body_asserts(
         }) => {
             let expected = Ok(Value::Bytes(value.to_vec()));
             let actual = match http_response.uncompress_body() {
-                Ok(b) => Ok(Value::Bytes(b)),
+                Ok(b) => Ok(Value::Bytes(b.to_vec())),
                 Err(e) => {
                     let source_info = SourceInfo {
                         start: spec_body.space0.source_info.end,
@@ -298,7 +357,7 @@ fn eval_implicit_body_asserts(
                 Err(e) => Err(e),
             };
             let actual = match http_response.uncompress_body() {
-                Ok(b) => Ok(Value::Bytes(b)),
+                Ok(b) => Ok(Value::Bytes(b.to_vec())),
                 Err(e) => {
                     let source_info = SourceInfo {
                         start: spec_body.space0.source_info.end,
@@ -316,15 +375,83 @@ fn eval_implicit_body_asserts(
     }
 }
 
+/// Returns the strings that the body of a response must contain, given a set of `variables`,
+/// for a body only used by `body contains` asserts of this spec `response`.
+///
+/// An empty list is returned if the body is used elsewhere (captures, explicit body, other
+/// asserts, soft asserts), in which case the body must be fully read.
+pub fn eval_body_contains(response: &Response, variables: &HashMap<String, Value>) -> Vec<String> {
+    if response.body.is_some()
+        || !response.captures().is_empty()
+        || !response.soft_asserts().is_empty()
+    {
+        return vec![];
+    }
+    let mut needles = vec![];
+    for assert in &response.asserts() {
+        if is_body_independent_query(&assert.query.value) {
+            continue;
+        }
+        let template = match (&assert.query.value, &assert.predicate) {
+            (
+                QueryValue::Body,
+                Predicate {
+                    not: false,
+                    predicate_func:
+                        PredicateFunc {
+                            value:
+                                PredicateFuncValue::Contain {
+                                    value: PredicateValue::String(template),
+                                    ..
+                                },
+                            ..
+                        },
+                    ..
+                },
+            ) if assert.filters.is_empty() => template,
+            _ => return vec![],
+        };
+        match template::eval_template(template, variables) {
+            Ok(needle) => needles.push(needle),
+            Err(_) => return vec![],
+        }
+    }
+    needles
+}
+
+/// Returns `true` if this `query` can be evaluated without the response body.
+///
+/// Any other query (including future ones) is considered to depend on the body, so a truncated
+/// body never silently changes a query value.
+fn is_body_independent_query(query: &QueryValue) -> bool {
+    matches!(
+        query,
+        QueryValue::Status
+            | QueryValue::Url
+            | QueryValue::Header { .. }
+            | QueryValue::Cookie { .. }
+            | QueryValue::Duration
+            | QueryValue::Certificate { .. }
+            | QueryValue::Variable { .. }
+            | QueryValue::Link { .. }
+            | QueryValue::HeaderCount { .. }
+            | QueryValue::TransferEncoding
+    )
+}
+
 /// Evaluates captures from this HTTP `http_response`, given a set of `variables`.
+/// Header names are matched with `header_match`.
 pub fn eval_captures(
     response: &Response,
     http_response: &http::Response,
     variables: &mut HashMap<String, Value>,
+    header_match: HeaderMatch,
+    timezone: FixedOffset,
 ) -> Result<Vec<CaptureResult>, Error> {
     let mut captures = vec![];
     for capture in &response.captures() {
-        let capture_result = capture::eval_capture(capture, variables, http_response)?;
+        let capture_result =
+            capture::eval_capture(capture, variables, http_response, header_match, timezone)?;
         // Update variables now so the captures set is ready in case
         // the next captures reference this new variable.
         variables.insert(capture_result.name.clone(), capture_result.value.clone());
@@ -338,6 +465,7 @@ mod tests {
     use self::super::super::{assert, capture};
     use super::*;
     use crate::runner::Number;
+    use chrono::{Offset, Utc};
 
     pub fn user_response() -> Response {
         let whitespace = Whitespace {
@@ -394,6 +522,7 @@ mod tests {
                 &user_response(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                &AssertOptions::default(),
                 &context_dir,
             ),
             vec![AssertResult::Explicit {
@@ -412,6 +541,206 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_eval_asserts_abort_on_first_failure() {
+        let content = "GET http://localhost:8000/users\n\
+            HTTP 200\n\
+            [Asserts]\n\
+            status == 200\n\
+            xpath \"count(//user)\" == 3\n\
+            xpath \"count(//user)\" == 4\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response_spec = hurl_file.entries[0].response.clone().unwrap();
+        let variables = HashMap::new();
+        let context_dir = ContextDir::default();
+        let http_response = http::xml_two_users_http_response();
+
+        let asserts = eval_asserts(
+            &response_spec,
+            &variables,
+            &http_response,
+            &AssertOptions::default(),
+            &context_dir,
+        );
+        assert_eq!(asserts.len(), 3);
+        assert_eq!(asserts.iter().filter(|a| a.error().is_some()).count(), 2);
+
+        let asserts = eval_asserts(
+            &response_spec,
+            &variables,
+            &http_response,
+            &AssertOptions {
+                abort_on_first_failure: true,
+                ..AssertOptions::default()
+            },
+            &context_dir,
+        );
+        assert_eq!(asserts.len(), 2);
+        assert!(asserts[0].error().is_none());
+        assert_eq!(
+            asserts[1].error().unwrap().inner,
+            RunnerError::AssertFailure {
+                actual: "float <2.0>".to_string(),
+                expected: "int <3>".to_string(),
+                type_mismatch: false,
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_eval_implicit_asserts() {
+        // An inline expected response (headers and body) is evaluated as implicit asserts.
+        let content = "GET http://localhost:8000/hello\n\
+            HTTP 200\n\
+            Content-Type: text/html; charset=utf-8\n\
+            `Hello World!`\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response_spec = hurl_file.entries[0].response.clone().unwrap();
+        let variables = HashMap::new();
+        let context_dir = ContextDir::default();
+
+        let asserts = eval_asserts(
+            &response_spec,
+            &variables,
+            &http::hello_http_response(),
+            &AssertOptions::default(),
+            &context_dir,
+        );
+        assert_eq!(asserts.len(), 2);
+        assert!(asserts.iter().all(|a| a.error().is_none()));
+        assert_eq!(
+            asserts[1],
+            AssertResult::Body {
+                actual: Ok(Value::String("Hello World!".to_string())),
+                expected: Ok(Value::String("Hello World!".to_string())),
+                source_info: SourceInfo::new(Pos::new(4, 1), Pos::new(4, 15)),
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_eval_asserts_normalize_json_numbers() {
+        let content = "GET http://localhost:8000/count\n\
+            HTTP 200\n\
+            {\"count\": 1e2}\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response_spec = hurl_file.entries[0].response.clone().unwrap();
+        let variables = HashMap::new();
+        let context_dir = ContextDir::default();
+        let http_response = http::Response {
+            body: "{\"count\": 100}".into(),
+            ..Default::default()
+        };
+
+        let asserts = eval_asserts(
+            &response_spec,
+            &variables,
+            &http_response,
+            &AssertOptions::default(),
+            &context_dir,
+        );
+        assert!(asserts[0].error().is_some());
+
+        let asserts = eval_asserts(
+            &response_spec,
+            &variables,
+            &http_response,
+            &AssertOptions {
+                normalize_json_numbers: true,
+                ..AssertOptions::default()
+            },
+            &context_dir,
+        );
+        assert!(asserts[0].error().is_none());
+    }
+
+    #[test]
+    pub fn test_eval_asserts_normalize_newlines() {
+        let content = "GET http://localhost:8000/text\n\
+            HTTP 200\n\
+            [Asserts]\n\
+            body == \"Hello\\nWorld\\n\"\n\
+            body startsWith \"Hello\\n\"\n\
+            ```\n\
+            Hello\n\
+            World\n\
+            ```\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response_spec = hurl_file.entries[0].response.clone().unwrap();
+        let variables = HashMap::new();
+        let context_dir = ContextDir::default();
+        let http_response = http::Response {
+            body: "Hello\r\nWorld\r\n".into(),
+            ..Default::default()
+        };
+
+        let asserts = eval_asserts(
+            &response_spec,
+            &variables,
+            &http_response,
+            &AssertOptions::default(),
+            &context_dir,
+        );
+        assert_eq!(asserts.len(), 3);
+        assert!(asserts.iter().all(|a| a.error().is_some()));
+
+        let asserts = eval_asserts(
+            &response_spec,
+            &variables,
+            &http_response,
+            &AssertOptions {
+                normalize_newlines: true,
+                ..AssertOptions::default()
+            },
+            &context_dir,
+        );
+        assert_eq!(asserts.len(), 3);
+        assert!(asserts.iter().all(|a| a.error().is_none()));
+    }
+
+    #[test]
+    pub fn test_eval_soft_asserts_same_options_as_asserts() {
+        let content = "GET http://localhost:8000/text\n\
+            HTTP 200\n\
+            [Asserts]\n\
+            body == \"Hello\\nWorld\\n\"\n\
+            [SoftAsserts]\n\
+            body == \"Hello\\nWorld\\n\"\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response_spec = hurl_file.entries[0].response.clone().unwrap();
+        let variables = HashMap::new();
+        let context_dir = ContextDir::default();
+        let http_response = http::Response {
+            body: "Hello\r\nWorld\r\n".into(),
+            ..Default::default()
+        };
+
+        for normalize_newlines in [false, true] {
+            let options = AssertOptions {
+                normalize_newlines,
+                ..AssertOptions::default()
+            };
+            let asserts = eval_asserts(
+                &response_spec,
+                &variables,
+                &http_response,
+                &options,
+                &context_dir,
+            );
+            let soft_asserts = eval_soft_asserts(
+                &response_spec,
+                &variables,
+                &http_response,
+                &options,
+                &context_dir,
+            );
+            assert_eq!(asserts.len(), 1);
+            assert_eq!(soft_asserts.len(), 1);
+            assert_eq!(asserts[0].error().is_none(), normalize_newlines);
+            assert_eq!(soft_asserts[0].error().is_none(), normalize_newlines);
+        }
+    }
+
     #[test]
     pub fn test_eval_version_status_asserts() {
         assert_eq!(
@@ -439,6 +768,8 @@ mod tests {
                 &user_response(),
                 &http::xml_two_users_http_response(),
                 &mut variables,
+                HeaderMatch::default(),
+                Utc.fix(),
             )
             .unwrap(),
             vec![CaptureResult {
@@ -447,4 +778,34 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    pub fn test_eval_body_contains() {
+        let response_spec = |asserts: &str| {
+            let content =
+                format!("GET http://localhost:8000/large\nHTTP 200\n[Asserts]\n{asserts}");
+            let hurl_file = hurl_core::parser::parse_hurl_file(&content).unwrap();
+            hurl_file.entries[0].response.clone().unwrap()
+        };
+        let mut variables = HashMap::new();
+        variables.insert("name".to_string(), Value::String("Bob".to_string()));
+
+        assert_eq!(
+            eval_body_contains(
+                &response_spec("header \"Content-Type\" == \"text/plain\"\nbody contains \"Hello\"\nbody contains \"{{name}}\"\n"),
+                &variables
+            ),
+            vec!["Hello".to_string(), "Bob".to_string()]
+        );
+        assert!(eval_body_contains(&response_spec("status == 200\n"), &variables).is_empty());
+        assert!(eval_body_contains(
+            &response_spec("body contains \"Hello\"\nbytes count == 12\n"),
+            &variables
+        )
+        .is_empty());
+        assert!(
+            eval_body_contains(&response_spec("body not contains \"Hello\"\n"), &variables)
+                .is_empty()
+        );
+    }
 }
@@ -0,0 +1,102 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+
+use chrono::FixedOffset;
+use hurl_core::ast::{Filter, Query};
+use hurl_core::parser;
+
+use crate::http;
+use crate::http::HeaderMatch;
+use crate::runner::error::Error;
+use crate::runner::filter::{eval_filters, FilterContext};
+use crate::runner::query::eval_query;
+use crate::runner::Value;
+
+/// A query, optionally followed by filters, evaluated on a stored response body.
+///
+/// Contrary to a capture or an assert, the query is not evaluated on the response of an HTTP
+/// request, but on a body read from a file, without any HTTP exchange.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResponseQuery {
+    query: Query,
+    filters: Vec<Filter>,
+}
+
+impl ResponseQuery {
+    /// Creates a new response query, parsing its `content` made of a query and optional filters.
+    pub fn new(content: &str) -> Result<ResponseQuery, parser::Error> {
+        let (query, filters) = parser::parse_query_filters(content)?;
+        let filters = filters.into_iter().map(|(_, f)| f).collect();
+        Ok(ResponseQuery { query, filters })
+    }
+
+    /// Evaluates this query and its filters against a response whose body is `body`.
+    ///
+    /// Returns `None` if the query or one of the filters returns no value. Dates are parsed and
+    /// formatted by filters in the `timezone` offset.
+    pub fn eval(&self, body: &[u8], timezone: FixedOffset) -> Result<Option<Value>, Error> {
+        let variables = HashMap::new();
+        let http_response = http::Response {
+            body: body.to_vec().into(),
+            ..Default::default()
+        };
+        let value = eval_query(
+            &self.query,
+            &variables,
+            &http_response,
+            HeaderMatch::default(),
+        )?;
+        match value {
+            None => Ok(None),
+            Some(value) => {
+                let context = FilterContext {
+                    in_assert: false,
+                    timezone,
+                };
+                eval_filters(&self.filters, &value, &variables, &context)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Number;
+    use chrono::{Offset, Utc};
+
+    #[test]
+    fn eval_response_query() {
+        let body = b"{\"id\": 42, \"name\": \"Bob\"}";
+
+        let query = ResponseQuery::new("jsonpath \"$.id\"").unwrap();
+        assert_eq!(
+            query.eval(body, Utc.fix()).unwrap(),
+            Some(Value::Number(Number::Integer(42)))
+        );
+
+        assert!(ResponseQuery::new("jsonpath").is_err());
+
+        let query = ResponseQuery::new("jsonpath \"$.name\" count").unwrap();
+        assert!(query.eval(body, Utc.fix()).is_err());
+
+        let query = ResponseQuery::new("jsonpath \"$.unknown\"").unwrap();
+        assert_eq!(query.eval(body, Utc.fix()).unwrap(), None);
+    }
+}
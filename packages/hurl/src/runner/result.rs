@@ -15,7 +15,10 @@
  * limitations under the License.
  *
  */
+use std::collections::HashMap;
+
 use hurl_core::ast::{Pos, SourceInfo};
+use serde::{Deserialize, Serialize};
 
 use crate::http::{Call, Cookie};
 use crate::runner::error::Error;
@@ -24,8 +27,19 @@ use crate::runner::value::Value;
 use crate::util::path::ContextDir;
 use crate::util::term::Stdout;
 
+/// Version of the serialized representation of [`HurlResult`].
+///
+/// The version is incremented each time the serialized representation changes in a non
+/// backward compatible way: a field is removed, renamed or has a new type.
+pub const HURL_RESULT_SCHEMA_VERSION: u32 = 1;
+
 /// Represents the result of a valid Hurl file execution.
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// A `HurlResult` can be serialized and deserialized with [`serde`]. The serialized representation
+/// has the same fields as this struct, with an additional `schema_version` field (see
+/// [`HURL_RESULT_SCHEMA_VERSION`]). Deserializing a result with another schema version fails.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "HurlResultSchema", into = "HurlResultSchema")]
 pub struct HurlResult {
     /// The entries result for this run.
     pub entries: Vec<EntryResult>,
@@ -46,29 +60,96 @@ impl HurlResult {
     /// The errors are only the "effective" ones: those that are due to retry are
     /// ignored.
     pub fn errors(&self) -> Vec<(&Error, SourceInfo)> {
-        let mut errors = vec![];
-        let mut next_entries = self.entries.iter().skip(1);
-        for entry in self.entries.iter() {
-            match next_entries.next() {
-                None => {
-                    let new_errors = entry.errors.iter().map(|error| (error, entry.source_info));
-                    errors.extend(new_errors);
-                }
-                Some(next) => {
-                    if next.entry_index != entry.entry_index {
-                        let new_errors =
-                            entry.errors.iter().map(|error| (error, entry.source_info));
-                        errors.extend(new_errors);
-                    }
-                }
-            }
+        self.effective_entries()
+            .flat_map(|entry| entry.errors.iter().map(|error| (error, entry.source_info)))
+            .collect()
+    }
+
+    /// Returns all the effective soft asserts failures of this `HurlResult`, with the source
+    /// information of the entry where the failure happens.
+    ///
+    /// As for [`HurlResult::errors`], the failures of retried entries are ignored.
+    pub fn warnings(&self) -> Vec<(Error, SourceInfo)> {
+        self.effective_entries()
+            .flat_map(|entry| {
+                entry
+                    .asserts
+                    .iter()
+                    .filter(|assert| assert.is_soft())
+                    .filter_map(|assert| assert.error())
+                    .map(|error| (error, entry.source_info))
+            })
+            .collect()
+    }
+
+    /// Returns the entries results that are not retried: the last result of each entry.
+    fn effective_entries(&self) -> impl Iterator<Item = &EntryResult> {
+        let next_indexes = self.entries.iter().skip(1).map(|e| Some(e.entry_index));
+        self.entries
+            .iter()
+            .zip(next_indexes.chain([None]))
+            .filter(|(entry, next_index)| *next_index != Some(entry.entry_index))
+            .map(|(entry, _)| entry)
+    }
+
+    /// Returns the variables captured during this run.
+    ///
+    /// When a variable is captured several times, the last captured value is returned.
+    pub fn captured_variables(&self) -> HashMap<String, Value> {
+        self.entries
+            .iter()
+            .flat_map(|e| &e.captures)
+            .map(|c| (c.name.clone(), c.value.clone()))
+            .collect()
+    }
+}
+
+/// Serialized representation of a [`HurlResult`].
+#[derive(Serialize, Deserialize)]
+struct HurlResultSchema {
+    schema_version: u32,
+    entries: Vec<EntryResult>,
+    time_in_ms: u128,
+    success: bool,
+    cookies: Vec<Cookie>,
+    timestamp: i64,
+}
+
+impl From<HurlResult> for HurlResultSchema {
+    fn from(result: HurlResult) -> Self {
+        HurlResultSchema {
+            schema_version: HURL_RESULT_SCHEMA_VERSION,
+            entries: result.entries,
+            time_in_ms: result.time_in_ms,
+            success: result.success,
+            cookies: result.cookies,
+            timestamp: result.timestamp,
         }
-        errors
+    }
+}
+
+impl TryFrom<HurlResultSchema> for HurlResult {
+    type Error = String;
+
+    fn try_from(schema: HurlResultSchema) -> Result<Self, Self::Error> {
+        if schema.schema_version != HURL_RESULT_SCHEMA_VERSION {
+            return Err(format!(
+                "unsupported schema version {}, expecting {HURL_RESULT_SCHEMA_VERSION}",
+                schema.schema_version
+            ));
+        }
+        Ok(HurlResult {
+            entries: schema.entries,
+            time_in_ms: schema.time_in_ms,
+            success: schema.success,
+            cookies: schema.cookies,
+            timestamp: schema.timestamp,
+        })
     }
 }
 
 /// Represents the execution result of an entry.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EntryResult {
     /// 1-based index of the entry on the file execution.
     pub entry_index: usize,
@@ -87,6 +168,10 @@ pub struct EntryResult {
     /// server is requested to send compressed response, and the response should be uncompressed
     /// when outputted on stdout.
     pub compressed: bool,
+    /// The curl command line equivalent to the entry request, empty if the request has not been
+    /// evaluated.
+    #[serde(default)]
+    pub curl_cmd: String,
 }
 
 impl Default for EntryResult {
@@ -100,6 +185,7 @@ impl Default for EntryResult {
             errors: vec![],
             time_in_ms: 0,
             compressed: false,
+            curl_cmd: String::new(),
         }
     }
 }
@@ -124,7 +210,10 @@ impl Default for EntryResult {
 /// - `x-baz: bar`: implicit HTTP header assert
 /// - `header "toto" == "tutu"`: explicit HTTP header assert
 /// - `jsonpath "$.state" = "running"`: explicit JSONPath assert on HTTP body response
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// Explicit asserts of a `[SoftAsserts]` section are soft asserts: their failures are reported
+/// as warnings and don't make the entry fail.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AssertResult {
     /// Implicit HTTP version assert (like HTTP/3, HTTP/2 etc...).
     Version {
@@ -156,13 +245,19 @@ pub enum AssertResult {
         source_info: SourceInfo,
         predicate_result: Option<PredicateResult>,
     },
+    /// Explicit soft assert on HTTP response.
+    Soft {
+        actual: Result<Option<Value>, Error>,
+        source_info: SourceInfo,
+        predicate_result: Option<PredicateResult>,
+    },
 }
 
 /// Represents a [capture](https://hurl.dev/docs/capturing-response.html) of an HTTP response.
 ///
 /// Captures are datas extracted by querying the HTTP response. Captures can be part of the response
 /// body, headers, cookies etc... Captures can be used to re-inject datas in next HTTP requests.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CaptureResult {
     /// Name of the capture.
     pub name: String,
@@ -200,3 +295,213 @@ impl EntryResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+    use hurl_core::ast::{Pos, SourceInfo};
+
+    use super::*;
+    use crate::http::{Certificate, Header, HeaderVec, HttpVersion, Request, Response, Timings};
+    use crate::runner::{Number, RunnerError};
+
+    fn hurl_result() -> HurlResult {
+        let source_info = SourceInfo::new(Pos::new(4, 1), Pos::new(4, 20));
+        let mut request_headers = HeaderVec::new();
+        request_headers.push(Header::new("Accept", "application/json"));
+        let mut response_headers = HeaderVec::new();
+        response_headers.push(Header::new("Content-Type", "application/json"));
+        response_headers.push(Header::new("Set-Cookie", "foo=bar"));
+        response_headers.push(Header::new("Set-Cookie", "baz=qux"));
+        let begin_call = Utc.with_ymd_and_hms(2024, 4, 1, 12, 0, 0).unwrap();
+        let end_call = Utc.with_ymd_and_hms(2024, 4, 1, 12, 0, 1).unwrap();
+
+        let call = Call {
+            request: Request {
+                url: "https://foo.com/users".to_string(),
+                method: "POST".to_string(),
+                headers: request_headers,
+                body: b"{\"name\":\"Bob\"}".to_vec(),
+            },
+            response: Response {
+                version: HttpVersion::Http2,
+                status: 201,
+                headers: response_headers,
+                body: r#"{"id":42,"name":"Bob"}"#.into(),
+                duration: Duration::from_millis(120),
+                url: "https://foo.com/users".to_string(),
+                certificate: Some(Certificate {
+                    subject: "CN=foo.com".to_string(),
+                    issuer: "CN=Foo CA".to_string(),
+                    start_date: begin_call,
+                    expire_date: end_call,
+                    serial_number: "1e:42".to_string(),
+                }),
+                bytes_sent: 14,
+                bytes_received: 22,
+            },
+            timings: Timings {
+                begin_call,
+                end_call,
+                name_lookup: Duration::from_micros(10),
+                connect: Duration::from_micros(20),
+                app_connect: Duration::from_micros(30),
+                pre_transfer: Duration::from_micros(40),
+                start_transfer: Duration::from_micros(50),
+                total: Duration::from_micros(60),
+            },
+        };
+        let error = Error::new(
+            source_info,
+            RunnerError::AssertFailure {
+                actual: "int <42>".to_string(),
+                expected: "int <43>".to_string(),
+                type_mismatch: false,
+            },
+            true,
+        );
+
+        HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 28)),
+                calls: vec![call],
+                captures: vec![
+                    CaptureResult {
+                        name: "id".to_string(),
+                        value: Value::Number(Number::Integer(42)),
+                    },
+                    CaptureResult {
+                        name: "tags".to_string(),
+                        value: Value::List(vec![
+                            Value::String("admin".to_string()),
+                            Value::Bool(true),
+                            Value::Null,
+                        ]),
+                    },
+                ],
+                asserts: vec![
+                    AssertResult::Status {
+                        actual: 201,
+                        expected: 201,
+                        source_info,
+                    },
+                    AssertResult::Explicit {
+                        actual: Ok(Some(Value::Number(Number::Integer(42)))),
+                        source_info,
+                        predicate_result: Some(Err(error.clone())),
+                    },
+                ],
+                errors: vec![error],
+                time_in_ms: 120,
+                compressed: false,
+                curl_cmd: "curl --data '{\"name\":\"Bob\"}' https://foo.com/users".to_string(),
+            }],
+            time_in_ms: 130,
+            success: false,
+            cookies: vec![Cookie {
+                domain: "foo.com".to_string(),
+                include_subdomain: "FALSE".to_string(),
+                path: "/".to_string(),
+                https: "FALSE".to_string(),
+                expires: "0".to_string(),
+                name: "foo".to_string(),
+                value: "bar".to_string(),
+                http_only: false,
+            }],
+            timestamp: 1711972800,
+        }
+    }
+
+    #[test]
+    fn test_warnings() {
+        let mut result = hurl_result();
+        assert!(result.warnings().is_empty());
+
+        let source_info = SourceInfo::new(Pos::new(6, 1), Pos::new(6, 10));
+        let error = Error::new(
+            source_info,
+            RunnerError::AssertFailure {
+                actual: "int <200>".to_string(),
+                expected: "int <100>".to_string(),
+                type_mismatch: false,
+            },
+            true,
+        );
+        result.entries[0].asserts.push(AssertResult::Soft {
+            actual: Ok(Some(Value::Number(Number::Integer(200)))),
+            source_info,
+            predicate_result: Some(Err(error.clone())),
+        });
+        let warnings = result.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].0, error);
+        // Soft asserts failures are not errors.
+        assert_eq!(result.errors().len(), 1);
+
+        // Failures of a retried entry are ignored.
+        let mut retry = result.entries[0].clone();
+        retry.asserts.clear();
+        result.entries.push(retry);
+        assert!(result.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_captured_variables() {
+        let mut result = hurl_result();
+        let mut entry = result.entries[0].clone();
+        entry.entry_index = 2;
+        entry.captures = vec![CaptureResult {
+            name: "id".to_string(),
+            value: Value::Number(Number::Integer(43)),
+        }];
+        result.entries.push(entry);
+
+        let variables = result.captured_variables();
+        assert_eq!(variables.len(), 2);
+        assert_eq!(variables["id"], Value::Number(Number::Integer(43)));
+        assert!(variables.contains_key("tags"));
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let result = hurl_result();
+        let json = serde_json::to_string(&result).unwrap();
+        let deserialized = serde_json::from_str::<HurlResult>(&json).unwrap();
+        assert_eq!(deserialized, result);
+        // Headers order and duplicates are preserved.
+        let headers = &deserialized.entries[0].calls[0].response.headers;
+        assert_eq!(headers.get_all("set-cookie").len(), 2);
+    }
+
+    #[test]
+    fn test_serde_schema_version() {
+        let json = serde_json::to_value(hurl_result()).unwrap();
+        assert_eq!(json["schema_version"], HURL_RESULT_SCHEMA_VERSION);
+
+        let mut json = json;
+        json["schema_version"] = serde_json::Value::from(HURL_RESULT_SCHEMA_VERSION + 1);
+        let error = serde_json::from_value::<HurlResult>(json).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unsupported schema version 2, expecting 1"
+        );
+    }
+
+    #[test]
+    fn test_serde_without_curl_cmd() {
+        // Results serialized before the curl command was recorded are still deserialized.
+        let mut result = hurl_result();
+        let mut json = serde_json::to_value(&result).unwrap();
+        json["entries"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("curl_cmd")
+            .unwrap();
+        let deserialized = serde_json::from_value::<HurlResult>(json).unwrap();
+        result.entries[0].curl_cmd = String::new();
+        assert_eq!(deserialized, result);
+    }
+}
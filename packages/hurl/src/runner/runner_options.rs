@@ -17,14 +17,19 @@
  */
 use std::time::Duration;
 
+use chrono::{FixedOffset, Offset, Utc};
 use hurl_core::ast::{Entry, Retry};
 
-use crate::http::{IpResolve, RequestedHttpVersion};
-use crate::runner::Output;
+use crate::http::{HeaderMatch, IpResolve, RequestedHttpVersion};
+use crate::runner::{AssertFile, Output, Value};
 use crate::util::path::ContextDir;
 
 pub struct RunnerOptionsBuilder {
+    abort_on_first_assert_failure_within_entry: bool,
+    accept_encoding: Option<String>,
+    assert_file: Option<AssertFile>,
     aws_sigv4: Option<String>,
+    buffer_size: Option<usize>,
     cacert_file: Option<String>,
     client_cert_file: Option<String>,
     client_key_file: Option<String>,
@@ -35,39 +40,66 @@ pub struct RunnerOptionsBuilder {
     continue_on_error: bool,
     cookie_input_file: Option<String>,
     delay: Duration,
+    explain: bool,
+    fail_on: Vec<u32>,
     follow_location: bool,
     follow_location_trusted: bool,
+    header_match: HeaderMatch,
     from_entry: Option<usize>,
     http_version: RequestedHttpVersion,
+    idempotency_key_header: Option<String>,
     ignore_asserts: bool,
     insecure: bool,
+    insecure_hosts: Vec<String>,
     ip_resolve: IpResolve,
+    max_header_size: Option<u64>,
+    max_pages: usize,
     max_redirect: Option<usize>,
     netrc: bool,
     netrc_file: Option<String>,
     netrc_optional: bool,
     no_proxy: Option<String>,
+    normalize_json_numbers: bool,
+    normalize_newlines: bool,
     output: Option<Output>,
+    paginate: Option<String>,
+    partial_body: bool,
     path_as_is: bool,
     post_entry: Option<fn() -> bool>,
     pre_entry: Option<fn(Entry) -> bool>,
+    print_curl_on_failure: bool,
     proxy: Option<String>,
+    proxy_headers: Vec<String>,
+    proxy_tunnel: bool,
+    randomize_order: bool,
     resolves: Vec<String>,
     retry: Retry,
+    retry_connect: Retry,
     retry_interval: Duration,
+    seed: u64,
+    set_at: Vec<(usize, String, Value)>,
     skip: bool,
+    sni: Option<String>,
     ssl_no_revoke: bool,
     timeout: Duration,
+    timezone: FixedOffset,
     to_entry: Option<usize>,
+    trace_context: bool,
+    trace_state: Option<String>,
     unix_socket: Option<String>,
     user: Option<String>,
     user_agent: Option<String>,
+    verify_content_length: bool,
 }
 
 impl Default for RunnerOptionsBuilder {
     fn default() -> Self {
         RunnerOptionsBuilder {
+            abort_on_first_assert_failure_within_entry: false,
+            accept_encoding: None,
+            assert_file: None,
             aws_sigv4: None,
+            buffer_size: None,
             cacert_file: None,
             client_cert_file: None,
             client_key_file: None,
@@ -78,33 +110,56 @@ impl Default for RunnerOptionsBuilder {
             continue_on_error: false,
             cookie_input_file: None,
             delay: Duration::from_millis(0),
+            explain: false,
+            fail_on: vec![],
             follow_location: false,
             follow_location_trusted: false,
+            header_match: HeaderMatch::default(),
             from_entry: None,
             http_version: RequestedHttpVersion::default(),
+            idempotency_key_header: None,
             ignore_asserts: false,
             insecure: false,
+            insecure_hosts: vec![],
             ip_resolve: IpResolve::default(),
+            max_header_size: None,
+            max_pages: 10,
             max_redirect: Some(50),
             netrc: false,
             netrc_file: None,
             netrc_optional: false,
             no_proxy: None,
+            normalize_json_numbers: false,
+            normalize_newlines: false,
             output: None,
+            paginate: None,
+            partial_body: false,
             path_as_is: false,
             post_entry: None,
             pre_entry: None,
+            print_curl_on_failure: false,
             proxy: None,
+            proxy_headers: vec![],
+            proxy_tunnel: false,
+            randomize_order: false,
             resolves: vec![],
             retry: Retry::None,
+            retry_connect: Retry::None,
             retry_interval: Duration::from_millis(1000),
+            seed: 0,
+            set_at: vec![],
             skip: false,
+            sni: None,
             ssl_no_revoke: false,
             timeout: Duration::from_secs(300),
+            timezone: Utc.fix(),
             to_entry: None,
+            trace_context: false,
+            trace_state: None,
             unix_socket: None,
             user: None,
             user_agent: None,
+            verify_content_length: false,
         }
     }
 }
@@ -115,12 +170,50 @@ impl RunnerOptionsBuilder {
         RunnerOptionsBuilder::default()
     }
 
+    /// Stops evaluating the asserts of an entry at the first failing assert.
+    ///
+    /// By default, all the asserts of an entry are evaluated.
+    pub fn abort_on_first_assert_failure_within_entry(
+        &mut self,
+        abort_on_first_assert_failure_within_entry: bool,
+    ) -> &mut Self {
+        self.abort_on_first_assert_failure_within_entry =
+            abort_on_first_assert_failure_within_entry;
+        self
+    }
+
+    /// Sets the encodings of the `Accept-Encoding` request header (like `gzip` or `gzip, br`),
+    /// responses being decompressed accordingly. With `none`, no `Accept-Encoding` header is sent
+    /// and responses are not decompressed, even if [`RunnerOptionsBuilder::compressed`] is set.
+    ///
+    /// Default is `None`: the header depends on [`RunnerOptionsBuilder::compressed`].
+    pub fn accept_encoding(&mut self, accept_encoding: Option<String>) -> &mut Self {
+        self.accept_encoding = accept_encoding;
+        self
+    }
+
+    /// Sets asserts, read from an external file, to apply to the last response of the run.
+    ///
+    /// Default is `None`.
+    pub fn assert_file(&mut self, assert_file: Option<AssertFile>) -> &mut Self {
+        self.assert_file = assert_file;
+        self
+    }
+
     /// Specifies the AWS SigV4 option
     pub fn aws_sigv4(&mut self, aws_sigv4: Option<String>) -> &mut Self {
         self.aws_sigv4 = aws_sigv4;
         self
     }
 
+    /// Sets the size in bytes of the buffer used to read the response body.
+    ///
+    /// Default is `None`, the libcurl default buffer size is used.
+    pub fn buffer_size(&mut self, buffer_size: Option<usize>) -> &mut Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
     /// Specifies the certificate file for peer verification.
     /// The file may contain multiple CA certificates and must be in PEM format.
     pub fn cacert_file(&mut self, cacert_file: Option<String>) -> &mut Self {
@@ -204,6 +297,24 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the logging of each assert result (passed or failed, with its query value) after
+    /// running an entry.
+    ///
+    /// Default is false.
+    pub fn explain(&mut self, explain: bool) -> &mut Self {
+        self.explain = explain;
+        self
+    }
+
+    /// Sets the HTTP status classes (`4` for `4xx`, `5` for `5xx` etc...) that make an entry fail.
+    ///
+    /// The check is made on every response of an entry, regardless of its asserts. Default is
+    /// empty.
+    pub fn fail_on(&mut self, fail_on: &[u32]) -> &mut Self {
+        self.fail_on = fail_on.to_vec();
+        self
+    }
+
     /// Sets follow redirect.
     ///
     /// To limit the amount of redirects to follow use [`self.max_redirect()`]
@@ -226,12 +337,30 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets how header names of queries and implicit header asserts are matched against the
+    /// response headers.
+    ///
+    /// Default is case-insensitive.
+    pub fn header_match(&mut self, header_match: HeaderMatch) -> &mut Self {
+        self.header_match = header_match;
+        self
+    }
+
     /// Set requested HTTP version (can be different of the effective HTTP version).
     pub fn http_version(&mut self, version: RequestedHttpVersion) -> &mut Self {
         self.http_version = version;
         self
     }
 
+    /// Sets the name of the header used to send an idempotency key with mutating requests.
+    ///
+    /// A key is generated for each entry and is reused when this entry is retried. Default is
+    /// `None`, no header is added.
+    pub fn idempotency_key_header(&mut self, idempotency_key_header: Option<String>) -> &mut Self {
+        self.idempotency_key_header = idempotency_key_header;
+        self
+    }
+
     /// Ignores all asserts defined in the Hurl file.
     pub fn ignore_asserts(&mut self, ignore_asserts: bool) -> &mut Self {
         self.ignore_asserts = ignore_asserts;
@@ -244,12 +373,34 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Allows Hurl to perform “insecure” SSL connections and transfers only for these hosts.
+    pub fn insecure_hosts(&mut self, insecure_hosts: &[String]) -> &mut Self {
+        self.insecure_hosts = insecure_hosts.to_vec();
+        self
+    }
+
     /// Set IP version.
     pub fn ip_resolve(&mut self, ip_resolve: IpResolve) -> &mut Self {
         self.ip_resolve = ip_resolve;
         self
     }
 
+    /// Sets the maximum cumulative size in bytes of the response headers.
+    ///
+    /// A response with bigger headers is aborted and reported as an error. Default is no limit.
+    pub fn max_header_size(&mut self, max_header_size: Option<u64>) -> &mut Self {
+        self.max_header_size = max_header_size;
+        self
+    }
+
+    /// Sets the maximum number of pages to fetch when following pagination links.
+    ///
+    /// The first page is included. Default is 10.
+    pub fn max_pages(&mut self, max_pages: usize) -> &mut Self {
+        self.max_pages = max_pages;
+        self
+    }
+
     /// Set maximum number of redirection-followings allowed
     ///
     /// By default, the limit is set to 50 redirections
@@ -258,6 +409,23 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Follows the pagination links with the relation type `paginate` of each response.
+    ///
+    /// The pages bodies are aggregated in a single body, on which asserts and captures are
+    /// computed. Default is `None`, no pagination link is followed.
+    pub fn paginate(&mut self, paginate: Option<String>) -> &mut Self {
+        self.paginate = paginate;
+        self
+    }
+
+    /// Stops reading a response body as soon as every `body contains` assert has matched.
+    ///
+    /// This is only done for entries whose body is not used by any other assert or capture.
+    pub fn partial_body(&mut self, partial_body: bool) -> &mut Self {
+        self.partial_body = partial_body;
+        self
+    }
+
     /// Sets the path-as-is flag.
     pub fn path_as_is(&mut self, path_as_is: bool) -> &mut Self {
         self.path_as_is = path_as_is;
@@ -288,6 +456,20 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Normalizes numbers when comparing implicit JSON body asserts, so numbers with the same
+    /// value (like `1e2` and `100`) are considered equal.
+    pub fn normalize_json_numbers(&mut self, normalize_json_numbers: bool) -> &mut Self {
+        self.normalize_json_numbers = normalize_json_numbers;
+        self
+    }
+
+    /// Normalizes line endings when comparing texts in asserts, so `\r\n` and `\n` are considered
+    /// equal.
+    pub fn normalize_newlines(&mut self, normalize_newlines: bool) -> &mut Self {
+        self.normalize_newlines = normalize_newlines;
+        self
+    }
+
     /// Specifies the file to output the HTTP response instead of stdout.
     pub fn output(&mut self, output: Option<Output>) -> &mut Self {
         self.output = output;
@@ -310,12 +492,32 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Prints the curl command line equivalent to the request of each failing entry.
+    pub fn print_curl_on_failure(&mut self, print_curl_on_failure: bool) -> &mut Self {
+        self.print_curl_on_failure = print_curl_on_failure;
+        self
+    }
+
     /// Sets the specified proxy to be used.
     pub fn proxy(&mut self, proxy: Option<String>) -> &mut Self {
         self.proxy = proxy;
         self
     }
 
+    /// Sets headers to pass only to the proxy (and not to the host).
+    pub fn proxy_headers(&mut self, proxy_headers: &[String]) -> &mut Self {
+        self.proxy_headers = proxy_headers.to_vec();
+        self
+    }
+
+    /// Sets tunneling through the proxy with a CONNECT request for all hosts, including HTTP ones.
+    ///
+    /// Default is false: only HTTPS hosts are tunneled.
+    pub fn proxy_tunnel(&mut self, proxy_tunnel: bool) -> &mut Self {
+        self.proxy_tunnel = proxy_tunnel;
+        self
+    }
+
     /// Provides a custom address for a specific host and port pair.
     pub fn resolves(&mut self, resolves: &[String]) -> &mut Self {
         self.resolves = resolves.to_vec();
@@ -330,6 +532,15 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets maximum number of retries on connection errors.
+    ///
+    /// Contrary to [`RunnerOptionsBuilder::retry`], only transport errors (failure to connect to
+    /// the server, connection reset etc...) are retried, asserts errors are not. Default is 0.
+    pub fn retry_connect(&mut self, retry_connect: Retry) -> &mut Self {
+        self.retry_connect = retry_connect;
+        self
+    }
+
     /// Sets duration between each retry.
     ///
     /// Default is 1000 ms.
@@ -338,6 +549,38 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Runs the entries in a random order, given by [`RunnerOptionsBuilder::seed`].
+    ///
+    /// Entries that depend on each other (through captures and variables) are kept in their
+    /// original order.
+    pub fn randomize_order(&mut self, randomize_order: bool) -> &mut Self {
+        self.randomize_order = randomize_order;
+        self
+    }
+
+    /// Sets the seed used to randomize the order of entries.
+    ///
+    /// Default is 0.
+    pub fn seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets variables values starting at a given entry, as a list of (entry index, name, value).
+    /// The entry index starts at 1, and a variable is set before running its entry.
+    ///
+    /// Default is empty.
+    pub fn set_at(&mut self, set_at: &[(usize, String, Value)]) -> &mut Self {
+        self.set_at = set_at.to_vec();
+        self
+    }
+
+    /// Sets the TLS server name (SNI), and the `Host` header, used instead of the URL host.
+    pub fn sni(&mut self, sni: Option<String>) -> &mut Self {
+        self.sni = sni;
+        self
+    }
+
     pub fn ssl_no_revoke(&mut self, ssl_no_revoke: bool) -> &mut Self {
         self.ssl_no_revoke = ssl_no_revoke;
         self
@@ -351,12 +594,33 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the timezone offset used by the `toDate` and `format` filters.
+    ///
+    /// Dates without offset are parsed in this timezone, and dates are formatted in this timezone.
+    /// Default is UTC.
+    pub fn timezone(&mut self, timezone: FixedOffset) -> &mut Self {
+        self.timezone = timezone;
+        self
+    }
+
     /// Executes Hurl file to `to_entry` (starting at 1), ignores the remaining of the file.
     pub fn to_entry(&mut self, to_entry: Option<usize>) -> &mut Self {
         self.to_entry = to_entry;
         self
     }
 
+    /// Adds a W3C Trace Context `traceparent` header, generated for each entry, to the requests.
+    pub fn trace_context(&mut self, trace_context: bool) -> &mut Self {
+        self.trace_context = trace_context;
+        self
+    }
+
+    /// Sets the W3C Trace Context `tracestate` header sent with the `traceparent` header.
+    pub fn trace_state(&mut self, trace_state: Option<String>) -> &mut Self {
+        self.trace_state = trace_state;
+        self
+    }
+
     /// Sets the specified unix domain socket to connect through, instead of using the network.
     pub fn unix_socket(&mut self, unix_socket: Option<String>) -> &mut Self {
         self.unix_socket = unix_socket;
@@ -375,10 +639,23 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Checks that the length of each response body matches its `Content-Length` header.
+    ///
+    /// A truncated response is reported as an error. Default is false.
+    pub fn verify_content_length(&mut self, verify_content_length: bool) -> &mut Self {
+        self.verify_content_length = verify_content_length;
+        self
+    }
+
     /// Create an instance of [`RunnerOptions`].
     pub fn build(&self) -> RunnerOptions {
         RunnerOptions {
+            abort_on_first_assert_failure_within_entry: self
+                .abort_on_first_assert_failure_within_entry,
+            accept_encoding: self.accept_encoding.clone(),
+            assert_file: self.assert_file.clone(),
             aws_sigv4: self.aws_sigv4.clone(),
+            buffer_size: self.buffer_size,
             cacert_file: self.cacert_file.clone(),
             client_cert_file: self.client_cert_file.clone(),
             client_key_file: self.client_key_file.clone(),
@@ -389,40 +666,67 @@ impl RunnerOptionsBuilder {
             context_dir: self.context_dir.clone(),
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
+            explain: self.explain,
+            fail_on: self.fail_on.clone(),
             follow_location: self.follow_location,
             follow_location_trusted: self.follow_location_trusted,
+            header_match: self.header_match,
             from_entry: self.from_entry,
             http_version: self.http_version,
+            idempotency_key_header: self.idempotency_key_header.clone(),
             ignore_asserts: self.ignore_asserts,
             insecure: self.insecure,
+            insecure_hosts: self.insecure_hosts.clone(),
             ip_resolve: self.ip_resolve,
+            max_header_size: self.max_header_size,
+            max_pages: self.max_pages,
             max_redirect: self.max_redirect,
             netrc: self.netrc,
             netrc_file: self.netrc_file.clone(),
             netrc_optional: self.netrc_optional,
             no_proxy: self.no_proxy.clone(),
+            normalize_json_numbers: self.normalize_json_numbers,
+            normalize_newlines: self.normalize_newlines,
             output: self.output.clone(),
+            paginate: self.paginate.clone(),
+            partial_body: self.partial_body,
             path_as_is: self.path_as_is,
             post_entry: self.post_entry,
             pre_entry: self.pre_entry,
+            print_curl_on_failure: self.print_curl_on_failure,
             proxy: self.proxy.clone(),
+            proxy_headers: self.proxy_headers.clone(),
+            proxy_tunnel: self.proxy_tunnel,
+            randomize_order: self.randomize_order,
             resolves: self.resolves.clone(),
             retry: self.retry,
+            retry_connect: self.retry_connect,
             retry_interval: self.retry_interval,
+            seed: self.seed,
+            set_at: self.set_at.clone(),
             skip: self.skip,
+            sni: self.sni.clone(),
             ssl_no_revoke: self.ssl_no_revoke,
             timeout: self.timeout,
+            timezone: self.timezone,
             to_entry: self.to_entry,
+            trace_context: self.trace_context,
+            trace_state: self.trace_state.clone(),
             unix_socket: self.unix_socket.clone(),
             user: self.user.clone(),
             user_agent: self.user_agent.clone(),
+            verify_content_length: self.verify_content_length,
         }
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RunnerOptions {
+    pub(crate) abort_on_first_assert_failure_within_entry: bool,
+    pub(crate) accept_encoding: Option<String>,
+    pub(crate) assert_file: Option<AssertFile>,
     pub(crate) aws_sigv4: Option<String>,
+    pub(crate) buffer_size: Option<usize>,
     pub(crate) cacert_file: Option<String>,
     pub(crate) client_cert_file: Option<String>,
     pub(crate) client_key_file: Option<String>,
@@ -433,33 +737,56 @@ pub struct RunnerOptions {
     pub(crate) context_dir: ContextDir,
     pub(crate) continue_on_error: bool,
     pub(crate) cookie_input_file: Option<String>,
+    pub(crate) explain: bool,
+    pub(crate) fail_on: Vec<u32>,
     pub(crate) follow_location: bool,
     pub(crate) follow_location_trusted: bool,
+    pub(crate) header_match: HeaderMatch,
     pub(crate) from_entry: Option<usize>,
     pub(crate) http_version: RequestedHttpVersion,
+    pub(crate) idempotency_key_header: Option<String>,
     pub(crate) ignore_asserts: bool,
     pub(crate) ip_resolve: IpResolve,
     pub(crate) insecure: bool,
+    pub(crate) insecure_hosts: Vec<String>,
+    pub(crate) max_header_size: Option<u64>,
+    pub(crate) max_pages: usize,
     pub(crate) max_redirect: Option<usize>,
     pub(crate) netrc: bool,
     pub(crate) netrc_file: Option<String>,
     pub(crate) netrc_optional: bool,
     pub(crate) no_proxy: Option<String>,
+    pub(crate) normalize_json_numbers: bool,
+    pub(crate) normalize_newlines: bool,
     pub(crate) output: Option<Output>,
+    pub(crate) paginate: Option<String>,
+    pub(crate) partial_body: bool,
     pub(crate) path_as_is: bool,
     pub(crate) post_entry: Option<fn() -> bool>,
     pub(crate) pre_entry: Option<fn(Entry) -> bool>,
+    pub(crate) print_curl_on_failure: bool,
     pub(crate) proxy: Option<String>,
+    pub(crate) proxy_headers: Vec<String>,
+    pub(crate) proxy_tunnel: bool,
+    pub(crate) randomize_order: bool,
     pub(crate) resolves: Vec<String>,
     pub(crate) retry: Retry,
+    pub(crate) retry_connect: Retry,
     pub(crate) retry_interval: Duration,
+    pub(crate) seed: u64,
+    pub(crate) set_at: Vec<(usize, String, Value)>,
     pub(crate) skip: bool,
+    pub(crate) sni: Option<String>,
     pub(crate) ssl_no_revoke: bool,
     pub(crate) timeout: Duration,
+    pub(crate) timezone: FixedOffset,
     pub(crate) to_entry: Option<usize>,
+    pub(crate) trace_context: bool,
+    pub(crate) trace_state: Option<String>,
     pub(crate) unix_socket: Option<String>,
     pub(crate) user: Option<String>,
     pub(crate) user_agent: Option<String>,
+    pub(crate) verify_content_length: bool,
 }
 
 impl Default for RunnerOptions {
@@ -17,12 +17,14 @@
  */
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 use crate::runner::Number;
 
 /// System types used in Hurl.
 ///
 /// Values are used by queries, captures, asserts and predicates.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Value {
     Bool(bool),
     Bytes(Vec<u8>),
@@ -32,6 +34,7 @@ pub enum Value {
     Null,
     Number(Number),
     Object(Vec<(String, Value)>),
+    #[serde(with = "regex_pattern")]
     Regex(regex::Regex),
     String(String),
     Unit,
@@ -83,6 +86,21 @@ impl fmt::Display for Value {
     }
 }
 
+/// Serializes a regex value as its pattern.
+mod regex_pattern {
+    use regex::Regex;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(regex: &Regex, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(regex.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Regex, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Regex::new(&pattern).map_err(de::Error::custom)
+    }
+}
+
 impl Value {
     pub fn _type(&self) -> String {
         match self {
@@ -103,6 +121,20 @@ impl Value {
     pub fn is_scalar(&self) -> bool {
         !matches!(self, Value::Nodeset(_) | Value::List(_))
     }
+
+    /// Returns this value with `\r\n` line endings replaced by `\n`, if it's a string, or the
+    /// value unchanged otherwise.
+    pub fn normalize_newlines(self) -> Value {
+        match self {
+            Value::String(s) => Value::String(normalize_newlines(&s)),
+            value => value,
+        }
+    }
+}
+
+/// Returns the string `s` with `\r\n` line endings replaced by `\n`.
+pub fn normalize_newlines(s: &str) -> String {
+    s.replace("\r\n", "\n")
 }
 
 #[cfg(test)]
@@ -114,4 +146,27 @@ mod tests {
         assert!(Value::Number(Number::Integer(1)).is_scalar());
         assert!(!Value::List(vec![]).is_scalar());
     }
+
+    #[test]
+    fn test_normalize_newlines() {
+        assert_eq!(
+            Value::String("Hello\r\nWorld\n".to_string()).normalize_newlines(),
+            Value::String("Hello\nWorld\n".to_string())
+        );
+        assert_eq!(
+            Value::Bytes(b"a\r\n".to_vec()).normalize_newlines(),
+            Value::Bytes(b"a\r\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_serde_regex() {
+        let value = Value::Regex(regex::Regex::new("[a-z]+").unwrap());
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"Regex":"[a-z]+"}"#);
+        let Value::Regex(regex) = serde_json::from_str::<Value>(&json).unwrap() else {
+            panic!("expecting a regex value");
+        };
+        assert_eq!(regex.as_str(), "[a-z]+");
+    }
 }
@@ -17,10 +17,11 @@
  */
 /// Unique entry point to libxml2.
 use std::ffi::{CStr, CString};
-use std::os::raw::c_char;
+use std::fmt;
+use std::os::raw::{c_char, c_void};
 use std::ptr;
 
-use libxml::bindings::{htmlReadMemory, xmlReadMemory};
+use libxml::bindings::{htmlReadMemory, xmlError, xmlReadMemory};
 use libxml::parser::{ParseFormat, Parser, XmlParseError};
 use libxml::tree::Document;
 
@@ -66,6 +67,137 @@ pub fn eval_html(html: &str, expr: &str) -> Result<Value, XpathError> {
     }
 }
 
+/// A parsing error of a XML or HTML document, located at `line` and `column`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for DocumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {} column {}",
+            self.message, self.line, self.column
+        )
+    }
+}
+
+/// libxml2 error level of fatal and non fatal errors (`XML_ERR_ERROR`).
+const XML_ERR_ERROR: u32 = 2;
+
+/// libxml2 error code for unknown HTML tags (`XML_HTML_UNKNOWN_TAG`).
+const XML_HTML_UNKNOWN_TAG: i32 = 801;
+
+/// Checks that `xml` is a well-formed XML document.
+pub fn check_xml(xml: &str) -> Result<(), DocumentError> {
+    check_document(xml, ParseFormat::XML)
+}
+
+/// Checks that `html` can be parsed as an HTML document.
+///
+/// Unknown tags (like HTML5 tags unsupported by libxml2) are not considered as errors.
+pub fn check_html(html: &str) -> Result<(), DocumentError> {
+    check_document(html, ParseFormat::HTML)
+}
+
+/// Parses strictly `input` (without recovery) and returns the first parsing error, if any.
+fn check_document(input: &str, format: ParseFormat) -> Result<(), DocumentError> {
+    let input_bytes: &[u8] = input.as_ref();
+    let input_ptr = input_bytes.as_ptr() as *const c_char;
+    let Ok(input_len) = try_usize_to_i32(input_bytes.len()) else {
+        return Err(DocumentError {
+            line: 0,
+            column: 0,
+            message: "Document too large".to_string(),
+        });
+    };
+    let encoding = CString::new("utf-8").unwrap();
+    let encoding_ptr = encoding.as_ptr();
+    let url_ptr = ptr::null();
+
+    let mut first_error: Option<DocumentError> = None;
+    let is_html = matches!(format, ParseFormat::HTML);
+    let mut ctx = ErrorContext {
+        is_html,
+        first_error: &mut first_error,
+    };
+    let has_root = unsafe {
+        set_structured_error_func(
+            &mut ctx as *mut ErrorContext as *mut c_void,
+            Some(on_structured_error),
+        );
+        let doc_ptr = match format {
+            ParseFormat::XML => xmlReadMemory(input_ptr, input_len, url_ptr, encoding_ptr, 0),
+            ParseFormat::HTML => htmlReadMemory(input_ptr, input_len, url_ptr, encoding_ptr, 0),
+        };
+        set_structured_error_func(ptr::null_mut(), None);
+        if doc_ptr.is_null() {
+            false
+        } else {
+            let doc = Document::new_ptr(doc_ptr);
+            doc.get_root_element().is_some()
+        }
+    };
+
+    if let Some(error) = first_error {
+        return Err(error);
+    }
+    if !has_root {
+        return Err(DocumentError {
+            line: 1,
+            column: 1,
+            message: "Document is empty".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Context shared with the libxml2 structured error handler while checking a document.
+struct ErrorContext<'a> {
+    is_html: bool,
+    first_error: &'a mut Option<DocumentError>,
+}
+
+/// libxml2 structured error handler, keeping the first error of the document.
+unsafe extern "C" fn on_structured_error(ctx: *mut c_void, error: *const xmlError) {
+    if ctx.is_null() || error.is_null() {
+        return;
+    }
+    let ctx = &mut *(ctx as *mut ErrorContext);
+    let error = &*error;
+    if ctx.first_error.is_some() || error.level < XML_ERR_ERROR {
+        return;
+    }
+    if ctx.is_html && error.code == XML_HTML_UNKNOWN_TAG {
+        return;
+    }
+    let message = if error.message.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(error.message)
+            .to_string_lossy()
+            .trim()
+            .to_string()
+    };
+    *ctx.first_error = Some(DocumentError {
+        line: error.line.max(0) as usize,
+        column: error.int2.max(0) as usize,
+        message,
+    });
+}
+
+extern "C" {
+    // The binding of libxml crate declares the handler as a Rust function, so we declare our own.
+    #[link_name = "xmlSetStructuredErrorFunc"]
+    fn set_structured_error_func(
+        ctx: *mut c_void,
+        handler: Option<unsafe extern "C" fn(*mut c_void, *const xmlError)>,
+    );
+}
+
 /// FIXME: Here are some patched functions of libxml crate.
 /// Started from libxml 2.11.1+, we have some encoding issue.
 /// See:
@@ -0,0 +1,147 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Hexadecimal dumps of bytes, like `hexdump -C`.
+
+/// Number of bytes displayed on each line of a dump.
+const LINE_LEN: usize = 16;
+
+/// Returns a canonical hexdump of `bytes` (like `hexdump -C`): offset, 16 bytes in hexadecimal
+/// and the same bytes as ASCII characters on each line.
+pub fn hexdump(bytes: &[u8]) -> String {
+    let mut dump = String::new();
+    for (i, chunk) in bytes.chunks(LINE_LEN).enumerate() {
+        dump.push_str(&hexdump_line(i * LINE_LEN, chunk));
+        dump.push('\n');
+    }
+    dump.push_str(&format!("{:08x}\n", bytes.len()));
+    dump
+}
+
+/// Returns a diff of `actual` and `expected` bytes, displaying the hexdump line of the first
+/// differing byte for both, with a marker under this byte.
+///
+/// If one of the bytes is a prefix of the other, the first differing offset is the length of the
+/// shortest one.
+pub fn hexdump_diff(actual: &[u8], expected: &[u8]) -> String {
+    let offset = actual
+        .iter()
+        .zip(expected)
+        .position(|(a, e)| a != e)
+        .unwrap_or(actual.len().min(expected.len()));
+    let start = offset - offset % LINE_LEN;
+    let line = |bytes: &[u8]| {
+        let chunk = &bytes[start.min(bytes.len())..(start + LINE_LEN).min(bytes.len())];
+        hexdump_line(start, chunk)
+    };
+
+    // The marker is aligned on the hexadecimal value of the differing byte, after the labels
+    // (10 chars) and the offset (10 chars). There is an additional space after the 8th byte.
+    let index = offset - start;
+    let column = 20 + index * 3 + if index >= 8 { 1 } else { 0 };
+    format!(
+        "bytes differ at offset {offset} (0x{offset:x}), actual has {} bytes, expected has {} bytes\n\
+         actual:   {}\n\
+         expected: {}\n\
+         {:column$}^^",
+        actual.len(),
+        expected.len(),
+        line(actual),
+        line(expected),
+        "",
+    )
+}
+
+/// Returns a line of hexdump for a `chunk` of at most 16 bytes, starting at `offset`.
+fn hexdump_line(offset: usize, chunk: &[u8]) -> String {
+    let mut hex = String::new();
+    for (j, byte) in chunk.iter().enumerate() {
+        if j == 8 {
+            hex.push(' ');
+        }
+        hex.push_str(&format!("{byte:02x} "));
+    }
+    let ascii = chunk
+        .iter()
+        .map(|&b| {
+            if b.is_ascii_graphic() || b == b' ' {
+                b as char
+            } else {
+                '.'
+            }
+        })
+        .collect::<String>();
+    format!("{offset:08x}  {hex:<49} |{ascii}|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hexdump_bytes() {
+        assert_eq!(
+            hexdump(b"{\"say\": \"Hello World!\"}"),
+            "00000000  7b 22 73 61 79 22 3a 20  22 48 65 6c 6c 6f 20 57  |{\"say\": \"Hello W|\n\
+             00000010  6f 72 6c 64 21 22 7d                              |orld!\"}|\n\
+             00000017\n"
+        );
+        assert_eq!(hexdump(b""), "00000000\n");
+    }
+
+    #[test]
+    fn hexdump_diff_nearly_identical_bytes() {
+        assert_eq!(
+            hexdump_diff(
+                b"{\"say\": \"Hello World!\"}",
+                b"{\"say\": \"Hello Wxrld!\"}"
+            ),
+            [
+                "bytes differ at offset 16 (0x10), actual has 23 bytes, expected has 23 bytes",
+                "actual:   00000010  6f 72 6c 64 21 22 7d                              |orld!\"}|",
+                "expected: 00000010  78 72 6c 64 21 22 7d                              |xrld!\"}|",
+                "                    ^^",
+            ]
+            .join("\n")
+        );
+
+        assert_eq!(
+            hexdump_diff(b"0123456789abcdef", b"0123456789Abcdef"),
+            [
+                "bytes differ at offset 10 (0xa), actual has 16 bytes, expected has 16 bytes",
+                "actual:   00000000  30 31 32 33 34 35 36 37  38 39 61 62 63 64 65 66  |0123456789abcdef|",
+                "expected: 00000000  30 31 32 33 34 35 36 37  38 39 41 62 63 64 65 66  |0123456789Abcdef|",
+                "                                                   ^^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn hexdump_diff_prefix_bytes() {
+        assert_eq!(
+            hexdump_diff(b"Hello", b"Hello World!"),
+            [
+                "bytes differ at offset 5 (0x5), actual has 5 bytes, expected has 12 bytes",
+                "actual:   00000000  48 65 6c 6c 6f                                    |Hello|",
+                "expected: 00000000  48 65 6c 6c 6f 20 57 6f  72 6c 64 21              |Hello World!|",
+                "                                   ^^",
+            ]
+            .join("\n")
+        );
+    }
+}
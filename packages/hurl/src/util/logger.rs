@@ -333,6 +333,37 @@ impl Logger {
         }
     }
 
+    pub fn warning_rich<E: Error>(&mut self, content: &str, warning: &E) {
+        let message = error_string(&self.filename, content, warning, None, self.color);
+        if self.color {
+            self.stderr
+                .eprintln(&format!("{}: {message}\n", "warning".yellow().bold()));
+        } else {
+            self.stderr.eprintln(&format!("warning: {message}\n"));
+        }
+    }
+
+    pub fn warning_runtime_rich<E: Error>(
+        &mut self,
+        content: &str,
+        warning: &E,
+        entry_src_info: SourceInfo,
+    ) {
+        let message = error_string(
+            &self.filename,
+            content,
+            warning,
+            Some(entry_src_info),
+            self.color,
+        );
+        if self.color {
+            self.stderr
+                .eprintln(&format!("{}: {message}\n", "warning".yellow().bold()));
+        } else {
+            self.stderr.eprintln(&format!("warning: {message}\n"));
+        }
+    }
+
     pub fn error(&mut self, message: &str) {
         if self.color {
             self.stderr
@@ -347,6 +378,13 @@ impl Logger {
         self.error_rich(&message);
     }
 
+    /// Displays an `error` located in another file than the current one, given its `filename`
+    /// and `content`.
+    pub fn error_file_rich<E: Error>(&mut self, filename: &str, content: &str, error: &E) {
+        let message = error_string(filename, content, error, None, self.color);
+        self.error_rich(&message);
+    }
+
     pub fn error_runtime_rich<E: Error>(
         &mut self,
         content: &str,
@@ -383,6 +421,22 @@ impl Logger {
         }
     }
 
+    /// Displays the result of an assert, given its `line` and `source` in the Hurl file, the
+    /// value `actual` of its query and its outcome `success`.
+    pub fn explain_assert(&mut self, line: usize, source: &str, actual: &str, success: bool) {
+        let outcome = if success { "pass" } else { "fail" };
+        let outcome = if !self.color {
+            outcome.to_string()
+        } else if success {
+            outcome.green().bold().to_string()
+        } else {
+            outcome.red().bold().to_string()
+        };
+        self.stderr.eprintln(&format!(
+            "  {outcome}  line {line}: {source} (actual: {actual})"
+        ));
+    }
+
     pub fn capture(&mut self, name: &str, value: &Value) {
         if self.verbosity.is_none() {
             return;
@@ -17,6 +17,7 @@
  */
 pub use bar::progress_bar;
 mod bar;
+pub mod hexdump;
 pub mod logger;
 pub mod path;
 pub mod term;
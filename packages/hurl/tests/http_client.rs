@@ -0,0 +1,263 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+
+use hurl::http::{
+    Call, Cassette, ClientOptions, Cookie, Header, HeaderVec, HttpClient, HttpError, HttpVersion,
+    RecordingClient, ReplayClient, Request, RequestSpec, Response, Timings,
+};
+use hurl::runner;
+use hurl::runner::{RunnerOptionsBuilder, Value};
+use hurl::util::logger::{Logger, LoggerOptionsBuilder};
+
+/// An in-memory HTTP backend: responses are computed by a handler, without any network.
+struct InMemoryClient {
+    handler: fn(&RequestSpec) -> Response,
+    requests: Vec<String>,
+}
+
+impl HttpClient for InMemoryClient {
+    fn execute_with_redirect(
+        &mut self,
+        request_spec: &RequestSpec,
+        _options: &ClientOptions,
+        _logger: &mut Logger,
+    ) -> Result<Vec<Call>, HttpError> {
+        let method = request_spec.method.to_string();
+        self.requests.push(format!("{method} {}", request_spec.url));
+        let request = Request::new(
+            &method,
+            &request_spec.url,
+            request_spec.headers.clone(),
+            request_spec.body.bytes(),
+        );
+        let response = (self.handler)(request_spec);
+        let timings = Timings::default();
+        Ok(vec![Call {
+            request,
+            response,
+            timings,
+        }])
+    }
+
+    fn get_cookie_storage(&mut self) -> Vec<Cookie> {
+        vec![]
+    }
+
+    fn add_cookie(&mut self, _cookie: &Cookie, _options: &ClientOptions) {}
+
+    fn clear_cookie_storage(&mut self, _options: &ClientOptions) {}
+}
+
+fn json_response(status: u32, body: &str) -> Response {
+    let mut headers = HeaderVec::new();
+    headers.push(Header::new("Content-Type", "application/json"));
+    Response {
+        version: HttpVersion::Http11,
+        status,
+        headers,
+        body: body.to_string().into(),
+        ..Default::default()
+    }
+}
+
+fn users_api(request_spec: &RequestSpec) -> Response {
+    match (request_spec.method.0.as_str(), request_spec.url.as_str()) {
+        ("GET", "http://api.example.org/users/1") => json_response(200, r#"{"id":1,"name":"Bob"}"#),
+        ("POST", "http://api.example.org/greetings") => {
+            let body = String::from_utf8(request_spec.body.bytes()).unwrap();
+            let name = if body.contains("Bob") {
+                "Bob"
+            } else {
+                "stranger"
+            };
+            json_response(201, &format!(r#"{{"message":"Hello {name}"}}"#))
+        }
+        _ => json_response(404, "{}"),
+    }
+}
+
+#[test]
+fn run_with_in_memory_client() {
+    let content = r#"
+GET http://api.example.org/users/1
+HTTP 200
+Content-Type: application/json
+[Captures]
+name: jsonpath "$.name"
+[Asserts]
+jsonpath "$.id" == 1
+
+POST http://api.example.org/greetings
+{"name": "{{name}}"}
+HTTP 201
+[Asserts]
+jsonpath "$.message" == "Hello Bob"
+
+GET http://api.example.org/users/2
+HTTP 404
+"#;
+    let mut http_client = InMemoryClient {
+        handler: users_api,
+        requests: vec![],
+    };
+    let runner_options = RunnerOptionsBuilder::new().build();
+    let logger_options = LoggerOptionsBuilder::new().build();
+    let variables = HashMap::new();
+
+    let result = runner::run_with_client(
+        content,
+        &mut http_client,
+        &runner_options,
+        &variables,
+        &logger_options,
+    )
+    .unwrap();
+
+    assert!(result.success);
+    assert_eq!(result.entries.len(), 3);
+    assert_eq!(
+        result.entries[0].captures[0].value,
+        Value::String("Bob".to_string())
+    );
+    assert_eq!(
+        http_client.requests,
+        vec![
+            "GET http://api.example.org/users/1",
+            "POST http://api.example.org/greetings",
+            "GET http://api.example.org/users/2",
+        ]
+    );
+}
+
+#[test]
+fn run_with_in_memory_client_failure() {
+    let content = r#"
+GET http://api.example.org/users/1
+HTTP 200
+[Asserts]
+jsonpath "$.name" == "Alice"
+"#;
+    let mut http_client = InMemoryClient {
+        handler: users_api,
+        requests: vec![],
+    };
+    let runner_options = RunnerOptionsBuilder::new().build();
+    let logger_options = LoggerOptionsBuilder::new().build();
+    let variables = HashMap::new();
+
+    let result = runner::run_with_client(
+        content,
+        &mut http_client,
+        &runner_options,
+        &variables,
+        &logger_options,
+    )
+    .unwrap();
+
+    assert!(!result.success);
+    assert_eq!(result.entries[0].errors.len(), 1);
+}
+
+#[test]
+fn record_and_replay_cassette() {
+    let content = r#"
+GET http://api.example.org/users/1
+HTTP 200
+[Captures]
+name: jsonpath "$.name"
+
+POST http://api.example.org/greetings
+{"name": "{{name}}"}
+HTTP 201
+[Asserts]
+jsonpath "$.message" == "Hello Bob"
+"#;
+    let runner_options = RunnerOptionsBuilder::new().build();
+    let logger_options = LoggerOptionsBuilder::new().build();
+    let variables = HashMap::new();
+
+    // First, we record the exchanges with the in-memory backend...
+    let http_client = InMemoryClient {
+        handler: users_api,
+        requests: vec![],
+    };
+    let mut recorder = RecordingClient::with_client(Box::new(http_client), Cassette::default());
+    let result = runner::run_with_client(
+        content,
+        &mut recorder,
+        &runner_options,
+        &variables,
+        &logger_options,
+    )
+    .unwrap();
+    assert!(result.success);
+    let cassette = recorder.into_cassette();
+    assert_eq!(cassette.interactions.len(), 2);
+
+    // ... then we replay them from the serialized cassette, without any backend.
+    let json = serde_json::to_string(&cassette).unwrap();
+    let cassette = serde_json::from_str::<Cassette>(&json).unwrap();
+    let mut replayer = ReplayClient::new(cassette);
+    let result = runner::run_with_client(
+        content,
+        &mut replayer,
+        &runner_options,
+        &variables,
+        &logger_options,
+    )
+    .unwrap();
+    assert!(result.success);
+    assert_eq!(result.entries.len(), 2);
+    assert_eq!(
+        result.entries[0].captures[0].value,
+        Value::String("Bob".to_string())
+    );
+    assert!(replayer.into_cassette().interactions.is_empty());
+}
+
+#[test]
+fn replay_cassette_without_recorded_response() {
+    let content = r#"
+GET http://api.example.org/users/2
+HTTP 200
+"#;
+    let runner_options = RunnerOptionsBuilder::new().build();
+    let logger_options = LoggerOptionsBuilder::new().build();
+    let variables = HashMap::new();
+
+    let mut replayer = ReplayClient::new(Cassette::default());
+    let result = runner::run_with_client(
+        content,
+        &mut replayer,
+        &runner_options,
+        &variables,
+        &logger_options,
+    )
+    .unwrap();
+
+    assert!(!result.success);
+    assert_eq!(result.entries[0].errors.len(), 1);
+    assert_eq!(
+        result.entries[0].errors[0].inner,
+        runner::RunnerError::HttpConnection(
+            "no recorded response for GET http://api.example.org/users/2 in the cassette"
+                .to_string()
+        )
+    );
+}
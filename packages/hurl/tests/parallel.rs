@@ -0,0 +1,264 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use std::{fs, thread};
+
+use hurl::parallel::job::Job;
+use hurl::parallel::runner::{ParallelRunner, ParallelRunnerOptions};
+use hurl::parallel::stats::Stats;
+use hurl::runner::{Input, RunnerOptions, RunnerOptionsBuilder};
+use hurl::util::logger::LoggerOptionsBuilder;
+
+/// Returns the jobs running the Hurl `files` with `runner_options`.
+fn jobs(files: &[PathBuf], runner_options: &RunnerOptions) -> Vec<Job> {
+    let logger_options = LoggerOptionsBuilder::new().build();
+    let variables = HashMap::new();
+    files
+        .iter()
+        .enumerate()
+        .map(|(seq, file)| {
+            let input = Input::from(file.as_path());
+            Job::new(&input, seq, runner_options, &variables, &logger_options)
+        })
+        .collect()
+}
+
+/// Starts a minimal HTTP server on a random port, serving `count` requests, and returns its port.
+///
+/// `/hello` responds `Hello` with a 200 status code, any other path responds with a 404 status
+/// code and an empty body.
+fn start_server(count: usize) -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    thread::spawn(move || {
+        for stream in listener.incoming().take(count) {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let response = if request_line.contains(" /hello ") {
+                "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nHello"
+            } else {
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            };
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    port
+}
+
+#[test]
+fn parallel_run_aggregates_stats() {
+    let port = start_server(3);
+    let dir = std::env::temp_dir().join(format!("hurl-parallel-stats-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let file_1 = dir.join("test_1.hurl");
+    let content_1 = format!(
+        "GET http://127.0.0.1:{port}/hello\nHTTP 200\n\nPOST http://127.0.0.1:{port}/hello\n`Bonjour`\nHTTP 200\n"
+    );
+    fs::write(&file_1, content_1).unwrap();
+    let file_2 = dir.join("test_2.hurl");
+    let content_2 = format!("GET http://127.0.0.1:{port}/not-found\nHTTP 404\n");
+    fs::write(&file_2, content_2).unwrap();
+
+    let runner_options = RunnerOptionsBuilder::new().build();
+    let jobs = jobs(&[file_1, file_2], &runner_options);
+
+    let mut runner = ParallelRunner::new(2, &ParallelRunnerOptions::default());
+    let (results, stats) = runner.run(&jobs).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.hurl_result.success));
+    let time_in_ms = results.iter().map(|r| r.hurl_result.time_in_ms).sum();
+    assert_eq!(
+        stats,
+        Stats {
+            requests: 3,
+            bytes_sent: 7,
+            bytes_received: 10,
+            time_in_ms,
+            status_counts: BTreeMap::from([(200, 2), (404, 1)]),
+        }
+    );
+}
+
+/// Concurrent requests received by a server: the number of requests currently in flight and the
+/// maximum of concurrent requests, by host and for all the hosts.
+#[derive(Default)]
+struct Concurrency {
+    current: HashMap<String, usize>,
+    max: HashMap<String, usize>,
+    current_all: usize,
+    max_all: usize,
+}
+
+/// Starts an HTTP server on a random port, serving `count` requests concurrently, and returns its
+/// port with the record of the concurrent requests.
+///
+/// Each request is responded after a delay, to let concurrent requests overlap.
+fn start_concurrency_server(count: usize) -> (u16, Arc<Mutex<Concurrency>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let concurrency = Arc::new(Mutex::new(Concurrency::default()));
+    let server_concurrency = concurrency.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming().take(count) {
+            let mut stream = stream.unwrap();
+            let concurrency = server_concurrency.clone();
+            thread::spawn(move || {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut host = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("host") {
+                            host = value.trim().split(':').next().unwrap().to_string();
+                        }
+                    }
+                }
+
+                {
+                    let mut c = concurrency.lock().unwrap();
+                    let current = c.current.entry(host.clone()).or_insert(0);
+                    *current += 1;
+                    let current = *current;
+                    let max = c.max.entry(host.clone()).or_insert(0);
+                    *max = (*max).max(current);
+                    c.current_all += 1;
+                    c.max_all = c.max_all.max(c.current_all);
+                }
+                thread::sleep(Duration::from_millis(200));
+                {
+                    let mut c = concurrency.lock().unwrap();
+                    *c.current.get_mut(&host).unwrap() -= 1;
+                    c.current_all -= 1;
+                }
+
+                let response =
+                    "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nHello";
+                stream.write_all(response.as_bytes()).unwrap();
+            });
+        }
+    });
+    (port, concurrency)
+}
+
+#[test]
+fn parallel_run_limits_concurrent_requests_per_host() {
+    let (port, concurrency) = start_concurrency_server(6);
+    let dir = std::env::temp_dir().join(format!("hurl-parallel-per-host-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let hosts = [
+        "foo.com", "foo.com", "foo.com", "foo.com", "bar.com", "bar.com",
+    ];
+    let files = hosts
+        .iter()
+        .enumerate()
+        .map(|(i, host)| {
+            let file = dir.join(format!("test_{i}.hurl"));
+            let content = format!("GET http://{host}:{port}/hello\nHTTP 200\n");
+            fs::write(&file, content).unwrap();
+            file
+        })
+        .collect::<Vec<_>>();
+
+    let resolves = ["foo.com", "bar.com"]
+        .iter()
+        .map(|host| format!("{host}:{port}:127.0.0.1"))
+        .collect::<Vec<_>>();
+    let runner_options = RunnerOptionsBuilder::new().resolves(&resolves).build();
+    let jobs = jobs(&files, &runner_options);
+
+    let mut runner = ParallelRunner::new(
+        6,
+        &ParallelRunnerOptions {
+            max_concurrent_per_host: Some(2),
+            ..Default::default()
+        },
+    );
+    let (results, _) = runner.run(&jobs).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(results.iter().all(|r| r.hurl_result.success));
+    // No more than 2 concurrent requests hit a host, while requests to the other host proceed.
+    let concurrency = concurrency.lock().unwrap();
+    assert_eq!(concurrency.max["foo.com"], 2);
+    assert_eq!(concurrency.max["bar.com"], 2);
+    assert!(concurrency.max_all > 2);
+}
+
+#[test]
+fn parallel_run_fail_fast_stops_launching_files() {
+    let port = start_server(1);
+    let dir = std::env::temp_dir().join(format!("hurl-parallel-fail-fast-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let files = ["not-found", "hello", "hello"]
+        .iter()
+        .enumerate()
+        .map(|(i, path)| {
+            let file = dir.join(format!("test_{i}.hurl"));
+            let content = format!("GET http://127.0.0.1:{port}/{path}\nHTTP 200\n");
+            fs::write(&file, content).unwrap();
+            file
+        })
+        .collect::<Vec<_>>();
+
+    let runner_options = RunnerOptionsBuilder::new().build();
+    let jobs = jobs(&files, &runner_options);
+
+    let mut runner = ParallelRunner::new(
+        1,
+        &ParallelRunnerOptions {
+            fail_fast: true,
+            ..Default::default()
+        },
+    );
+    let (results, stats) = runner.run(&jobs).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    // The first file fails, the following files are not started.
+    assert_eq!(results.len(), 1);
+    assert!(!results[0].hurl_result.success);
+    assert_eq!(stats.requests, 1);
+}
@@ -0,0 +1,521 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Builders to create Hurl AST nodes programmatically, without parsing a Hurl content.
+//!
+//! The nodes are created with the source positions they would have if they were parsed, so
+//! a built [`HurlFile`] can be serialized to a Hurl file and parsed back to the same AST.
+use crate::ast::*;
+use crate::parser::{templatize, EncodedString, ParseResult};
+
+/// Builds a [`HurlFile`] from a list of entries.
+///
+/// # Example
+///
+/// ```
+/// use hurl_core::ast::{EntryBuilder, HurlFileBuilder};
+///
+/// let mut entry = EntryBuilder::new();
+/// entry
+///     .method("GET")
+///     .url("https://example.org/api/users/{{id}}")
+///     .header("Accept", "application/json")
+///     .status(200)
+///     .assert_jsonpath("$.name", "Bob");
+/// let hurl_file = HurlFileBuilder::new().entry(&entry).build().unwrap();
+/// assert_eq!(hurl_file.entries.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HurlFileBuilder {
+    entries: Vec<EntryBuilder>,
+}
+
+impl HurlFileBuilder {
+    /// Returns a new empty Hurl file builder.
+    pub fn new() -> Self {
+        HurlFileBuilder::default()
+    }
+
+    /// Adds an `entry`, entries are separated by an empty line.
+    pub fn entry(&mut self, entry: &EntryBuilder) -> &mut Self {
+        self.entries.push(entry.clone());
+        self
+    }
+
+    /// Creates an instance of [`HurlFile`].
+    ///
+    /// An error is returned if a value contains an invalid template expression.
+    pub fn build(&self) -> ParseResult<HurlFile> {
+        let mut writer = Writer::new();
+        let mut entries = vec![];
+        for (i, entry) in self.entries.iter().enumerate() {
+            let blank_lines = if i == 0 { 0 } else { 1 };
+            entries.push(entry.write(&mut writer, blank_lines)?);
+        }
+        Ok(HurlFile {
+            entries,
+            line_terminators: vec![],
+        })
+    }
+}
+
+/// Builds an [`Entry`], with a request and an optional response.
+///
+/// A response is added if a status code or an assert is specified.
+#[derive(Clone, Debug)]
+pub struct EntryBuilder {
+    method: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    status: Option<u64>,
+    asserts: Vec<(AssertQuery, AssertValue)>,
+}
+
+#[derive(Clone, Debug)]
+enum AssertQuery {
+    Header(String),
+    Jsonpath(String),
+}
+
+/// Represents the expected value of an assert built with an [`EntryBuilder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AssertValue {
+    Bool(bool),
+    Integer(i64),
+    String(String),
+}
+
+impl From<bool> for AssertValue {
+    fn from(value: bool) -> Self {
+        AssertValue::Bool(value)
+    }
+}
+
+impl From<i64> for AssertValue {
+    fn from(value: i64) -> Self {
+        AssertValue::Integer(value)
+    }
+}
+
+impl From<&str> for AssertValue {
+    fn from(value: &str) -> Self {
+        AssertValue::String(value.to_string())
+    }
+}
+
+impl Default for EntryBuilder {
+    fn default() -> Self {
+        EntryBuilder {
+            method: "GET".to_string(),
+            url: String::new(),
+            headers: vec![],
+            status: None,
+            asserts: vec![],
+        }
+    }
+}
+
+impl EntryBuilder {
+    /// Returns a new entry builder, with a `GET` method.
+    pub fn new() -> Self {
+        EntryBuilder::default()
+    }
+
+    /// Sets the request method.
+    pub fn method(&mut self, method: &str) -> &mut Self {
+        self.method = method.to_string();
+        self
+    }
+
+    /// Sets the request URL.
+    pub fn url(&mut self, url: &str) -> &mut Self {
+        self.url = url.to_string();
+        self
+    }
+
+    /// Adds a request header.
+    pub fn header(&mut self, name: &str, value: &str) -> &mut Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets the expected response status code.
+    pub fn status(&mut self, status: u64) -> &mut Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Adds an assert on the response header `name`: `header "name" == value`.
+    pub fn assert_header(&mut self, name: &str, value: impl Into<AssertValue>) -> &mut Self {
+        let query = AssertQuery::Header(name.to_string());
+        self.asserts.push((query, value.into()));
+        self
+    }
+
+    /// Adds an assert on the response JSONPath expression `expr`: `jsonpath "expr" == value`.
+    pub fn assert_jsonpath(&mut self, expr: &str, value: impl Into<AssertValue>) -> &mut Self {
+        let query = AssertQuery::Jsonpath(expr.to_string());
+        self.asserts.push((query, value.into()));
+        self
+    }
+
+    /// Creates an instance of [`Entry`], as if it were the first entry of a Hurl file.
+    ///
+    /// An error is returned if a value contains an invalid template expression.
+    pub fn build(&self) -> ParseResult<Entry> {
+        let mut writer = Writer::new();
+        self.write(&mut writer, 0)
+    }
+
+    fn write(&self, w: &mut Writer, blank_lines: usize) -> ParseResult<Entry> {
+        let request = self.write_request(w, blank_lines)?;
+        let response = if self.status.is_some() || !self.asserts.is_empty() {
+            Some(self.write_response(w)?)
+        } else {
+            None
+        };
+        Ok(Entry { request, response })
+    }
+
+    fn write_request(&self, w: &mut Writer, blank_lines: usize) -> ParseResult<Request> {
+        let start = w.pos;
+        let line_terminators = (0..blank_lines).map(|_| w.line_terminator()).collect();
+        let space0 = w.whitespace("");
+        w.write(&self.method);
+        let method = Method::Literal(self.method.clone());
+        let space1 = w.whitespace(" ");
+        let url = w.template(&self.url, None, false)?;
+        let line_terminator0 = w.line_terminator();
+        let mut headers = vec![];
+        for (name, value) in &self.headers {
+            headers.push(w.key_value(name, value)?);
+        }
+        Ok(Request {
+            line_terminators,
+            space0,
+            method,
+            space1,
+            url,
+            line_terminator0,
+            headers,
+            sections: vec![],
+            body: None,
+            source_info: SourceInfo::new(start, w.pos),
+        })
+    }
+
+    fn write_response(&self, w: &mut Writer) -> ParseResult<Response> {
+        let start = w.pos;
+        let space0 = w.whitespace("");
+        let version = Version {
+            value: VersionValue::VersionAny,
+            source_info: w.write("HTTP"),
+        };
+        let space1 = w.whitespace(" ");
+        let status = match self.status {
+            Some(status) => Status {
+                value: StatusValue::Specific(status),
+                source_info: w.write(&status.to_string()),
+            },
+            None => Status {
+                value: StatusValue::Any,
+                source_info: w.write("*"),
+            },
+        };
+        let line_terminator0 = w.line_terminator();
+        let mut sections = vec![];
+        if !self.asserts.is_empty() {
+            let space0 = w.whitespace("");
+            let source_info = w.write("[Asserts]");
+            let line_terminator0 = w.line_terminator();
+            let mut asserts = vec![];
+            for (query, value) in &self.asserts {
+                asserts.push(w.assert(query, value)?);
+            }
+            sections.push(Section {
+                line_terminators: vec![],
+                space0,
+                line_terminator0,
+                value: SectionValue::Asserts(asserts),
+                source_info,
+            });
+        }
+        Ok(Response {
+            line_terminators: vec![],
+            version,
+            space0,
+            status,
+            space1,
+            line_terminator0,
+            headers: vec![],
+            sections,
+            body: None,
+            source_info: SourceInfo::new(start, w.pos),
+        })
+    }
+}
+
+/// Tracks the position of the nodes written in a virtual Hurl content.
+struct Writer {
+    pos: Pos,
+}
+
+impl Writer {
+    fn new() -> Self {
+        Writer {
+            pos: Pos::new(1, 1),
+        }
+    }
+
+    /// Writes `s` and returns its source info.
+    fn write(&mut self, s: &str) -> SourceInfo {
+        let start = self.pos;
+        for c in s.chars() {
+            if c == '\n' {
+                self.pos.line += 1;
+                self.pos.column = 1;
+            } else {
+                self.pos.column += 1;
+            }
+        }
+        SourceInfo::new(start, self.pos)
+    }
+
+    fn whitespace(&mut self, value: &str) -> Whitespace {
+        Whitespace {
+            value: value.to_string(),
+            source_info: self.write(value),
+        }
+    }
+
+    fn line_terminator(&mut self) -> LineTerminator {
+        LineTerminator {
+            space0: self.whitespace(""),
+            comment: None,
+            newline: self.whitespace("\n"),
+        }
+    }
+
+    /// Writes a template of `value`, escaping the chars that can't be used verbatim if `escape`
+    /// is true.
+    fn template(
+        &mut self,
+        value: &str,
+        delimiter: Option<char>,
+        escape: bool,
+    ) -> ParseResult<Template> {
+        let start = self.pos;
+        if let Some(delimiter) = delimiter {
+            self.write(&delimiter.to_string());
+        }
+        let mut chars = vec![];
+        for c in value.chars() {
+            let encoded = if escape {
+                encode_char(c, delimiter)
+            } else {
+                c.to_string()
+            };
+            chars.push((c, encoded.clone(), self.pos));
+            self.write(&encoded);
+        }
+        let encoded_string = EncodedString {
+            source_info: SourceInfo::new(start, self.pos),
+            chars,
+        };
+        let elements = templatize(encoded_string)?;
+        if let Some(delimiter) = delimiter {
+            self.write(&delimiter.to_string());
+        }
+        Ok(Template {
+            delimiter,
+            elements,
+            source_info: SourceInfo::new(start, self.pos),
+        })
+    }
+
+    fn key_value(&mut self, key: &str, value: &str) -> ParseResult<KeyValue> {
+        let space0 = self.whitespace("");
+        let key = Template {
+            delimiter: None,
+            elements: vec![TemplateElement::String {
+                value: key.to_string(),
+                encoded: key.to_string(),
+            }],
+            source_info: self.write(key),
+        };
+        let space1 = self.whitespace("");
+        self.write(":");
+        let space2 = self.whitespace(" ");
+        let value = self.template(value.trim(), None, true)?;
+        let line_terminator0 = self.line_terminator();
+        Ok(KeyValue {
+            line_terminators: vec![],
+            space0,
+            key,
+            space1,
+            space2,
+            value,
+            line_terminator0,
+        })
+    }
+
+    fn assert(&mut self, query: &AssertQuery, value: &AssertValue) -> ParseResult<Assert> {
+        let space0 = self.whitespace("");
+        let start = self.pos;
+        let query_value = match query {
+            AssertQuery::Header(name) => {
+                self.write("header");
+                let space0 = self.whitespace(" ");
+                let name = self.template(name, Some('"'), true)?;
+                QueryValue::Header { space0, name }
+            }
+            AssertQuery::Jsonpath(expr) => {
+                self.write("jsonpath");
+                let space0 = self.whitespace(" ");
+                let expr = self.template(expr, Some('"'), true)?;
+                QueryValue::Jsonpath { space0, expr }
+            }
+        };
+        let query = Query {
+            source_info: SourceInfo::new(start, self.pos),
+            value: query_value,
+        };
+        let space1 = self.whitespace(" ");
+        let predicate_space0 = self.whitespace("");
+        let start = self.pos;
+        self.write("==");
+        let func_space0 = self.whitespace(" ");
+        let value = match value {
+            AssertValue::Bool(value) => {
+                self.write(&value.to_string());
+                PredicateValue::Bool(*value)
+            }
+            AssertValue::Integer(value) => {
+                self.write(&value.to_string());
+                PredicateValue::Number(Number::Integer(*value))
+            }
+            AssertValue::String(value) => {
+                PredicateValue::String(self.template(value, Some('"'), true)?)
+            }
+        };
+        let predicate = Predicate {
+            not: false,
+            space0: predicate_space0,
+            predicate_func: PredicateFunc {
+                source_info: SourceInfo::new(start, self.pos),
+                value: PredicateFuncValue::Equal {
+                    space0: func_space0,
+                    value,
+                    operator: true,
+                },
+            },
+        };
+        let line_terminator0 = self.line_terminator();
+        Ok(Assert {
+            line_terminators: vec![],
+            space0,
+            query,
+            filters: vec![],
+            space1,
+            predicate,
+            line_terminator0,
+        })
+    }
+}
+
+/// Returns the encoded form of the char `c` in a template delimited by `delimiter`.
+fn encode_char(c: char, delimiter: Option<char>) -> String {
+    match c {
+        '\\' => "\\\\".to_string(),
+        '\n' => "\\n".to_string(),
+        '\r' => "\\r".to_string(),
+        '\t' => "\\t".to_string(),
+        '#' if delimiter.is_none() => "\\#".to_string(),
+        '"' if delimiter == Some('"') => "\\\"".to_string(),
+        _ => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_hurl_file;
+
+    #[test]
+    fn test_build_entry() {
+        let entry = EntryBuilder::new()
+            .method("POST")
+            .url("http://localhost:8000/users/{{id}}")
+            .header("Accept", "application/json")
+            .header("X-Comment", "Not a # comment")
+            .status(201)
+            .assert_header("Location", "/users/{{ id }}")
+            .assert_jsonpath("$.name", "Bob \"the builder\"")
+            .assert_jsonpath("$.age", 42)
+            .assert_jsonpath("$.admin", false)
+            .build()
+            .unwrap();
+
+        let content = r#"POST http://localhost:8000/users/{{id}}
+Accept: application/json
+X-Comment: Not a \# comment
+HTTP 201
+[Asserts]
+header "Location" == "/users/{{ id }}"
+jsonpath "$.name" == "Bob \"the builder\""
+jsonpath "$.age" == 42
+jsonpath "$.admin" == false
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        assert_eq!(entry, hurl_file.entries[0]);
+    }
+
+    #[test]
+    fn test_build_hurl_file() {
+        let mut entry1 = EntryBuilder::new();
+        entry1.url("http://localhost:8000/hello");
+        let mut entry2 = EntryBuilder::new();
+        entry2
+            .method("DELETE")
+            .url("http://localhost:8000/hello")
+            .assert_header("X-Status", "deleted");
+        let hurl_file = HurlFileBuilder::new()
+            .entry(&entry1)
+            .entry(&entry2)
+            .build()
+            .unwrap();
+
+        let content = r#"GET http://localhost:8000/hello
+
+DELETE http://localhost:8000/hello
+HTTP *
+[Asserts]
+header "X-Status" == "deleted"
+"#;
+        assert_eq!(hurl_file, parse_hurl_file(content).unwrap());
+    }
+
+    #[test]
+    fn test_build_invalid_template() {
+        let error = EntryBuilder::new()
+            .url("http://localhost:8000/{{id")
+            .build()
+            .unwrap_err();
+        let content = "GET http://localhost:8000/{{id\n";
+        assert_eq!(error.pos, parse_hurl_file(content).unwrap_err().pos);
+    }
+}
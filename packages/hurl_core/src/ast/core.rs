@@ -15,6 +15,8 @@
  * limitations under the License.
  *
  */
+use serde::{Deserialize, Serialize};
+
 use crate::ast::json;
 
 ///
@@ -141,10 +143,26 @@ impl Response {
         }
         vec![]
     }
+
+    /// Returns the soft asserts of this response: their failures are reported as warnings and
+    /// don't make the entry fail.
+    pub fn soft_asserts(&self) -> Vec<Assert> {
+        for section in self.sections.iter() {
+            if let SectionValue::SoftAsserts(asserts) = &section.value {
+                return asserts.clone();
+            }
+        }
+        vec![]
+    }
 }
 
+/// The method of a request: either a literal method name (`GET`, `POST`, etc...), or an
+/// expression (`{{method}}`) evaluated at runtime.
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Method(pub String);
+pub enum Method {
+    Literal(String),
+    Expression(Expr),
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Version {
@@ -201,6 +219,7 @@ impl Section {
     pub fn name(&self) -> &str {
         match self.value {
             SectionValue::Asserts(_) => "Asserts",
+            SectionValue::SoftAsserts(_) => "SoftAsserts",
             SectionValue::QueryParams(_) => "QueryStringParams",
             SectionValue::BasicAuth(_) => "BasicAuth",
             SectionValue::FormParams(_) => "FormParams",
@@ -222,6 +241,7 @@ pub enum SectionValue {
     Cookies(Vec<Cookie>),
     Captures(Vec<Capture>),
     Asserts(Vec<Assert>),
+    SoftAsserts(Vec<Assert>),
     Options(Vec<EntryOption>),
 }
 
@@ -324,6 +344,10 @@ pub enum QueryValue {
         space0: Whitespace,
         expr: Template,
     },
+    Jsonpointer {
+        space0: Whitespace,
+        expr: Template,
+    },
     Regex {
         space0: Whitespace,
         value: RegexValue,
@@ -334,12 +358,29 @@ pub enum QueryValue {
     },
     Duration,
     Bytes,
+    BodyBytes,
     Sha256,
     Md5,
+    ContentType,
+    CompressionRatio,
+    Raw,
     Certificate {
         space0: Whitespace,
         attribute_name: CertificateAttributeName,
     },
+    Transfer {
+        space0: Whitespace,
+        attribute_name: TransferAttributeName,
+    },
+    TransferEncoding,
+    Link {
+        space0: Whitespace,
+        rel: Template,
+    },
+    HeaderCount {
+        space0: Whitespace,
+        name: Template,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -397,6 +438,13 @@ pub enum CertificateAttributeName {
     SerialNumber,
 }
 
+/// The attribute of a `transfer` query: the number of body bytes sent or received over the network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransferAttributeName {
+    Sent,
+    Received,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Predicate {
     pub not: bool,
@@ -494,6 +542,10 @@ pub enum PredicateFuncValue {
     Exist,
     IsEmpty,
     IsNumber,
+    IsJson,
+    IsXml,
+    IsHtml,
+    HasNoDuplicateKeys,
 }
 
 //
@@ -664,7 +716,7 @@ impl PartialEq for Regex {
 }
 impl Eq for Regex {}
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Pos {
     pub line: usize,
     pub column: usize,
@@ -676,7 +728,7 @@ impl Pos {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SourceInfo {
     pub start: Pos,
     pub end: Pos,
@@ -718,6 +770,7 @@ pub enum OptionKind {
     ClientCert(Template),
     ClientKey(Template),
     Compressed(BooleanOption),
+    ConnectTimeout(NaturalOption),
     ConnectTo(Template),
     Delay(NaturalOption),
     Http10(BooleanOption),
@@ -740,6 +793,7 @@ pub enum OptionKind {
     Retry(RetryOption),
     RetryInterval(NaturalOption),
     Skip(BooleanOption),
+    Timeout(NaturalOption),
     UnixSocket(Template),
     User(Template),
     Variable(VariableDefinition),
@@ -755,6 +809,7 @@ impl OptionKind {
             OptionKind::ClientCert(_) => "cert",
             OptionKind::ClientKey(_) => "key",
             OptionKind::Compressed(_) => "compressed",
+            OptionKind::ConnectTimeout(_) => "connect-timeout",
             OptionKind::ConnectTo(_) => "connect-to",
             OptionKind::Delay(_) => "delay",
             OptionKind::FollowLocation(_) => "location",
@@ -777,6 +832,7 @@ impl OptionKind {
             OptionKind::Retry(_) => "retry",
             OptionKind::RetryInterval(_) => "retry-interval",
             OptionKind::Skip(_) => "skip",
+            OptionKind::Timeout(_) => "timeout",
             OptionKind::UnixSocket(_) => "unix-socket",
             OptionKind::User(_) => "user",
             OptionKind::Variable(_) => "variable",
@@ -792,6 +848,7 @@ impl OptionKind {
             OptionKind::ClientCert(filename) => filename.to_string(),
             OptionKind::ClientKey(filename) => filename.to_string(),
             OptionKind::Compressed(value) => value.to_string(),
+            OptionKind::ConnectTimeout(value) => value.to_string(),
             OptionKind::ConnectTo(value) => value.to_string(),
             OptionKind::Delay(value) => value.to_string(),
             OptionKind::FollowLocation(value) => value.to_string(),
@@ -814,6 +871,7 @@ impl OptionKind {
             OptionKind::Retry(value) => value.to_string(),
             OptionKind::RetryInterval(value) => value.to_string(),
             OptionKind::Skip(value) => value.to_string(),
+            OptionKind::Timeout(value) => value.to_string(),
             OptionKind::UnixSocket(value) => value.to_string(),
             OptionKind::User(value) => value.to_string(),
             OptionKind::Variable(VariableDefinition { name, value, .. }) => {
@@ -884,6 +942,11 @@ pub enum FilterValue {
         space0: Whitespace,
         expr: Template,
     },
+    JsonPointer {
+        space0: Whitespace,
+        expr: Template,
+    },
+    LineCount,
     Nth {
         space0: Whitespace,
         n: u64,
@@ -21,7 +21,10 @@ use crate::ast::core::*;
 
 impl fmt::Display for Method {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        match self {
+            Method::Literal(name) => write!(f, "{name}"),
+            Method::Expression(expr) => write!(f, "{{{{{expr}}}}}"),
+        }
     }
 }
 
@@ -274,6 +277,10 @@ impl PredicateFuncValue {
             PredicateFuncValue::Exist => "exists".to_string(),
             PredicateFuncValue::IsEmpty => "isEmpty".to_string(),
             PredicateFuncValue::IsNumber => "isNumber".to_string(),
+            PredicateFuncValue::IsJson => "isJson".to_string(),
+            PredicateFuncValue::IsXml => "isXml".to_string(),
+            PredicateFuncValue::IsHtml => "isHtml".to_string(),
+            PredicateFuncValue::HasNoDuplicateKeys => "hasNoDuplicateKeys".to_string(),
         }
     }
 }
@@ -16,11 +16,13 @@
  *
  */
 
+pub use self::builder::{AssertValue, EntryBuilder, HurlFileBuilder};
 pub use self::core::*;
 pub use self::json::{
     ListElement as JsonListElement, ObjectElement as JsonObjectElement, Value as JsonValue,
 };
 
+mod builder;
 mod core;
 mod display;
 mod json;
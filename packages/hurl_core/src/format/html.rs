@@ -174,6 +174,9 @@ impl HtmlFormatter {
     fn fmt_section_value(&mut self, section_value: &SectionValue) {
         match section_value {
             SectionValue::Asserts(items) => items.iter().for_each(|item| self.fmt_assert(item)),
+            SectionValue::SoftAsserts(items) => {
+                items.iter().for_each(|item| self.fmt_assert(item));
+            }
             SectionValue::QueryParams(items) => items.iter().for_each(|item| self.fmt_kv(item)),
             SectionValue::BasicAuth(item) => {
                 if let Some(kv) = item {
@@ -219,6 +222,7 @@ impl HtmlFormatter {
             OptionKind::ClientCert(filename) => self.fmt_filename(filename),
             OptionKind::ClientKey(filename) => self.fmt_filename(filename),
             OptionKind::Compressed(value) => self.fmt_bool_option(value),
+            OptionKind::ConnectTimeout(value) => self.fmt_natural_option(value),
             OptionKind::ConnectTo(value) => self.fmt_template(value),
             OptionKind::Delay(value) => self.fmt_natural_option(value),
             OptionKind::FollowLocation(value) => self.fmt_bool_option(value),
@@ -240,6 +244,7 @@ impl HtmlFormatter {
             OptionKind::Resolve(value) => self.fmt_template(value),
             OptionKind::Retry(value) => self.fmt_retry_option(value),
             OptionKind::RetryInterval(value) => self.fmt_natural_option(value),
+            OptionKind::Timeout(value) => self.fmt_natural_option(value),
             OptionKind::Skip(value) => self.fmt_bool_option(value),
             OptionKind::UnixSocket(value) => self.fmt_template(value),
             OptionKind::User(value) => self.fmt_template(value),
@@ -380,6 +385,11 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(expr);
             }
+            QueryValue::Jsonpointer { space0, expr } => {
+                self.fmt_span("query-type", "jsonpointer");
+                self.fmt_space(space0);
+                self.fmt_template(expr);
+            }
             QueryValue::Regex { space0, value } => {
                 self.fmt_span("query-type", "regex");
                 self.fmt_space(space0);
@@ -392,8 +402,12 @@ impl HtmlFormatter {
             }
             QueryValue::Duration => self.fmt_span("query-type", "duration"),
             QueryValue::Bytes => self.fmt_span("query-type", "bytes"),
+            QueryValue::BodyBytes => self.fmt_span("query-type", "bodyBytes"),
             QueryValue::Sha256 => self.fmt_span("query-type", "sha256"),
             QueryValue::Md5 => self.fmt_span("query-type", "md5"),
+            QueryValue::ContentType => self.fmt_span("query-type", "contentType"),
+            QueryValue::CompressionRatio => self.fmt_span("query-type", "compressionRatio"),
+            QueryValue::Raw => self.fmt_span("query-type", "raw"),
             QueryValue::Certificate {
                 space0,
                 attribute_name: field,
@@ -402,6 +416,25 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_certificate_attribute_name(field);
             }
+            QueryValue::Transfer {
+                space0,
+                attribute_name: field,
+            } => {
+                self.fmt_span("query-type", "transfer");
+                self.fmt_space(space0);
+                self.fmt_transfer_attribute_name(field);
+            }
+            QueryValue::TransferEncoding => self.fmt_span("query-type", "transferEncoding"),
+            QueryValue::Link { space0, rel } => {
+                self.fmt_span("query-type", "link");
+                self.fmt_space(space0);
+                self.fmt_template(rel);
+            }
+            QueryValue::HeaderCount { space0, name } => {
+                self.fmt_span("query-type", "headerCount");
+                self.fmt_space(space0);
+                self.fmt_template(name);
+            }
         }
     }
 
@@ -447,6 +480,14 @@ impl HtmlFormatter {
         self.fmt_span_close();
     }
 
+    fn fmt_transfer_attribute_name(&mut self, name: &TransferAttributeName) {
+        let value = match name {
+            TransferAttributeName::Sent => "sent",
+            TransferAttributeName::Received => "received",
+        };
+        self.fmt_span("query-type", value);
+    }
+
     fn fmt_assert(&mut self, assert: &Assert) {
         self.fmt_lts(&assert.line_terminators);
         self.fmt_span_open("line");
@@ -534,6 +575,10 @@ impl HtmlFormatter {
             PredicateFuncValue::Exist => {}
             PredicateFuncValue::IsEmpty => {}
             PredicateFuncValue::IsNumber => {}
+            PredicateFuncValue::IsJson => {}
+            PredicateFuncValue::IsXml => {}
+            PredicateFuncValue::IsHtml => {}
+            PredicateFuncValue::HasNoDuplicateKeys => {}
         }
     }
 
@@ -796,6 +841,12 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(expr);
             }
+            FilterValue::JsonPointer { space0, expr } => {
+                self.fmt_span("filter-type", "jsonpointer");
+                self.fmt_space(space0);
+                self.fmt_template(expr);
+            }
+            FilterValue::LineCount => self.fmt_span("filter-type", "lineCount"),
             FilterValue::Nth { space0, n: value } => {
                 self.fmt_span("filter-type", "nth");
                 self.fmt_space(space0);
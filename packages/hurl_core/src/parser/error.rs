@@ -149,6 +149,7 @@ impl crate::error::Error for Error {
                     "cacert",
                     "cert",
                     "compressed",
+                    "connect-timeout",
                     "connect-to",
                     "delay",
                     "insecure",
@@ -168,6 +169,7 @@ impl crate::error::Error for Error {
                     "retry",
                     "retry-interval",
                     "skip",
+                    "timeout",
                     "unix-socket",
                     "variable",
                     "verbose",
@@ -217,8 +219,8 @@ impl crate::error::Error for Error {
             }
             ParseError::ResponseSection => "this is not a valid section for a response".to_string(),
             ParseError::ResponseSectionName { name } => {
-                let valid_values = ["Captures", "Asserts"];
-                let default = "Valid values are Captures or Asserts";
+                let valid_values = ["Captures", "Asserts", "SoftAsserts"];
+                let default = "Valid values are Captures, Asserts or SoftAsserts";
                 let did_your_mean = did_you_mean(&valid_values, name.as_str(), default);
                 format!("the section is not valid. {did_your_mean}")
             }
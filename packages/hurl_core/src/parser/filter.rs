@@ -60,6 +60,8 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
             html_decode_filter,
             html_encode_filter,
             jsonpath_filter,
+            jsonpointer_filter,
+            line_count_filter,
             nth_filter,
             regex_filter,
             replace_filter,
@@ -133,6 +135,18 @@ fn jsonpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::JsonPath { space0, expr })
 }
 
+fn jsonpointer_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonpointer", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::JsonPointer { space0, expr })
+}
+
+fn line_count_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("lineCount", reader)?;
+    Ok(FilterValue::LineCount)
+}
+
 fn nth_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("nth", reader)?;
     let space0 = one_or_more_spaces(reader)?;
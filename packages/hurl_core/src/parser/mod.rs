@@ -18,11 +18,35 @@
 pub type ParseResult<T> = Result<T, Error>;
 pub type ParseFunc<T> = fn(&mut Reader) -> ParseResult<T>;
 
+/// Maximum number of syntax errors reported by [`parse_hurl_file_with_recovery`].
+pub const MAX_PARSE_ERRORS: usize = 10;
+
 pub fn parse_hurl_file(s: &str) -> ParseResult<HurlFile> {
     let mut reader = Reader::new(s);
     parsers::hurl_file(&mut reader)
 }
 
+/// Parses a Hurl file content `s`, reporting up to [`MAX_PARSE_ERRORS`] syntax errors at once.
+///
+/// Contrary to [`parse_hurl_file`] which stops at the first syntax error, the parser resumes
+/// at the next entry after an error.
+pub fn parse_hurl_file_with_recovery(s: &str) -> Result<HurlFile, Vec<Error>> {
+    let mut reader = Reader::new(s);
+    parsers::hurl_file_with_recovery(&mut reader, MAX_PARSE_ERRORS)
+}
+
+/// Parses a `s` content made only of assert lines, like the content of an `[Asserts]` section.
+pub fn parse_asserts(s: &str) -> ParseResult<Vec<Assert>> {
+    let mut reader = Reader::new(s);
+    parsers::asserts(&mut reader)
+}
+
+/// Parses a `s` content made of a query, optionally followed by filters, like `jsonpath "$.id" toInt`.
+pub fn parse_query_filters(s: &str) -> ParseResult<(Query, Vec<(Whitespace, Filter)>)> {
+    let mut reader = Reader::new(s);
+    parsers::query_filters(&mut reader)
+}
+
 pub use self::error::{Error, JsonErrorVariant, ParseError};
 pub use self::json::{
     boolean_value as parse_json_boolean, null_value as parse_json_null,
@@ -30,7 +54,8 @@ pub use self::json::{
 };
 pub use self::reader::Reader;
 pub use self::template::templatize;
-use crate::ast::HurlFile;
+pub(crate) use self::template::EncodedString;
+use crate::ast::{Assert, Filter, HurlFile, Query, Whitespace};
 
 mod base64;
 mod bytes;
@@ -42,6 +42,7 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "cacert" => option_cacert(reader)?,
         "cert" => option_cert(reader)?,
         "compressed" => option_compressed(reader)?,
+        "connect-timeout" => option_connect_timeout(reader)?,
         "connect-to" => option_connect_to(reader)?,
         "delay" => option_delay(reader)?,
         "insecure" => option_insecure(reader)?,
@@ -65,6 +66,7 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "retry" => option_retry(reader)?,
         "retry-interval" => option_retry_interval(reader)?,
         "skip" => option_skip(reader)?,
+        "timeout" => option_timeout(reader)?,
         "unix-socket" => option_unix_socket(reader)?,
         "user" => option_user(reader)?,
         "variable" => option_variable(reader)?,
@@ -110,6 +112,11 @@ fn option_compressed(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Compressed(value))
 }
 
+fn option_connect_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::ConnectTimeout(value))
+}
+
 fn option_connect_to(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::ConnectTo(value))
@@ -225,6 +232,11 @@ fn option_skip(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Skip(value))
 }
 
+fn option_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(natural_option, reader)?;
+    Ok(OptionKind::Timeout(value))
+}
+
 fn option_user(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::User(value))
@@ -20,9 +20,12 @@ use crate::ast::*;
 use crate::parser::bytes::*;
 use crate::parser::combinators::*;
 use crate::parser::error::*;
+use crate::parser::expr;
+use crate::parser::filter::filters;
 use crate::parser::number::natural;
 use crate::parser::primitives::*;
-use crate::parser::reader::Reader;
+use crate::parser::query::query;
+use crate::parser::reader::{Reader, ReaderState};
 use crate::parser::sections::*;
 use crate::parser::url::url;
 use crate::parser::ParseResult;
@@ -37,6 +40,91 @@ pub fn hurl_file(reader: &mut Reader) -> ParseResult<HurlFile> {
     })
 }
 
+/// Parses a list of asserts, without any section header, up to the end of the `reader`.
+pub fn asserts(reader: &mut Reader) -> ParseResult<Vec<Assert>> {
+    let asserts = zero_or_more(assert, reader)?;
+    optional_line_terminators(reader)?;
+    eof(reader)?;
+    Ok(asserts)
+}
+
+/// Parses a query, optionally followed by filters (like the left part of an assert), up to the
+/// end of the `reader`.
+pub fn query_filters(reader: &mut Reader) -> ParseResult<(Query, Vec<(Whitespace, Filter)>)> {
+    zero_or_more_spaces(reader)?;
+    let query = query(reader)?;
+    let filters = filters(reader)?;
+    optional_line_terminators(reader)?;
+    eof(reader)?;
+    Ok((query, filters))
+}
+
+/// Parses a Hurl file, collecting up to `max_errors` syntax errors.
+///
+/// After a syntax error, the parser resynchronizes on the next line that looks like the start of
+/// an entry (a method followed by an URL) and resumes parsing from there.
+pub fn hurl_file_with_recovery(
+    reader: &mut Reader,
+    max_errors: usize,
+) -> Result<HurlFile, Vec<Error>> {
+    let mut errors = vec![];
+    loop {
+        match hurl_file(reader) {
+            Ok(hurl_file) => {
+                return if errors.is_empty() {
+                    Ok(hurl_file)
+                } else {
+                    Err(errors)
+                };
+            }
+            Err(e) => {
+                let line = e.pos.line;
+                errors.push(e);
+                if errors.len() >= max_errors {
+                    return Err(errors);
+                }
+                match next_entry_start(reader, line) {
+                    Some(state) => reader.state = state,
+                    None => return Err(errors),
+                }
+            }
+        }
+    }
+}
+
+/// Returns the reader state at the start of the first entry located after `line`.
+fn next_entry_start(reader: &Reader, line: usize) -> Option<ReaderState> {
+    let mut cursor = 0;
+    let mut current_line = 1;
+    while cursor < reader.buffer.len() {
+        if current_line > line && is_entry_start(&reader.buffer[cursor..]) {
+            return Some(ReaderState {
+                cursor,
+                pos: Pos::new(current_line, 1),
+            });
+        }
+        let next_line = reader.buffer[cursor..].iter().position(|c| *c == '\n')?;
+        cursor += next_line + 1;
+        current_line += 1;
+    }
+    None
+}
+
+/// Returns true if the line beginning `chars` looks like an entry request line: an uppercase
+/// method, followed by spaces and an URL.
+fn is_entry_start(chars: &[char]) -> bool {
+    let line = chars.iter().take_while(|c| **c != '\n').collect::<String>();
+    let line = line.trim_start_matches([' ', '\t']);
+    let Some((method, url)) = line.split_once([' ', '\t']) else {
+        return false;
+    };
+    // `HTTP` starts a response status line, not a request.
+    !method.is_empty()
+        && method != "HTTP"
+        && method.chars().all(|c| c.is_ascii_uppercase())
+        && !url.trim().is_empty()
+}
+
 fn entry(reader: &mut Reader) -> ParseResult<Entry> {
     let req = request(reader)?;
     let resp = optional(response, reader)?;
@@ -120,13 +208,17 @@ fn method(reader: &mut Reader) -> ParseResult<Method> {
         };
         return Err(Error::new(reader.state.pos, true, inner));
     }
+    if reader.peek_n(2) == "{{" {
+        let expr = expr::parse(reader)?;
+        return Ok(Method::Expression(expr));
+    }
     let start = reader.state;
     let name = reader.read_while(|c| c.is_ascii_alphabetic());
     if name.is_empty() || name.to_uppercase() != name {
         let inner = ParseError::Method { name };
         Err(Error::new(start.pos, false, inner))
     } else {
-        Ok(Method(name))
+        Ok(Method::Literal(name))
     }
 }
 
@@ -182,6 +274,14 @@ fn body(reader: &mut Reader) -> ParseResult<Body> {
     //  let start = reader.state.clone();
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
+    // A method expression followed by an URL is the start of the next entry, not a templated
+    // JSON body.
+    if is_request_line_with_expression(reader) {
+        let inner = ParseError::Expecting {
+            value: "body".to_string(),
+        };
+        return Err(Error::new(reader.state.pos, true, inner));
+    }
     let value = bytes(reader)?;
     let line_terminator0 = line_terminator(reader)?;
     Ok(Body {
@@ -192,6 +292,16 @@ fn body(reader: &mut Reader) -> ParseResult<Body> {
     })
 }
 
+/// Returns `true` if the `reader` is at the start of a request line with a method expression
+/// (`{{method}} http://example.org`), without consuming it.
+fn is_request_line_with_expression(reader: &mut Reader) -> bool {
+    let save = reader.state;
+    let is_request_line =
+        expr::parse(reader).is_ok() && one_or_more_spaces(reader).is_ok() && url(reader).is_ok();
+    reader.state = save;
+    is_request_line
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,11 +313,39 @@ mod tests {
         assert_eq!(hurl_file.entries.len(), 1);
     }
 
+    #[test]
+    fn test_asserts() {
+        let mut reader = Reader::new("# Checks\nstatus == 200\n\nbody contains \"Hello\"\n");
+        let values = asserts(&mut reader).unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[1].query.source_info.start, Pos::new(4, 1));
+
+        let mut reader = Reader::new("status == 200\nGET http://google.fr\n");
+        let error = asserts(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos::new(2, 1));
+    }
+
+    #[test]
+    fn test_query_filters() {
+        let mut reader = Reader::new("jsonpath \"$.id\" toInt");
+        let (query, filters) = query_filters(&mut reader).unwrap();
+        assert!(matches!(query.value, QueryValue::Jsonpath { .. }));
+        assert_eq!(filters.len(), 1);
+        assert_eq!(filters[0].1.value, FilterValue::ToInt);
+
+        let mut reader = Reader::new("body");
+        let (_, filters) = query_filters(&mut reader).unwrap();
+        assert!(filters.is_empty());
+
+        let mut reader = Reader::new("body == 1");
+        assert!(query_filters(&mut reader).is_err());
+    }
+
     #[test]
     fn test_entry() {
         let mut reader = Reader::new("GET http://google.fr");
         let e = entry(&mut reader).unwrap();
-        assert_eq!(e.request.method, Method("GET".to_string()));
+        assert_eq!(e.request.method, Method::Literal("GET".to_string()));
         assert_eq!(reader.state.cursor, 20);
     }
 
@@ -216,12 +354,12 @@ mod tests {
         let mut reader = Reader::new("GET http://google.fr\nGET http://google.fr");
 
         let e = entry(&mut reader).unwrap();
-        assert_eq!(e.request.method, Method("GET".to_string()));
+        assert_eq!(e.request.method, Method::Literal("GET".to_string()));
         assert_eq!(reader.state.cursor, 21);
         assert_eq!(reader.state.pos.line, 2);
 
         let e = entry(&mut reader).unwrap();
-        assert_eq!(e.request.method, Method("GET".to_string()));
+        assert_eq!(e.request.method, Method::Literal("GET".to_string()));
         assert_eq!(reader.state.cursor, 41);
         assert_eq!(reader.state.pos.line, 2);
 
@@ -229,12 +367,12 @@ mod tests {
             Reader::new("GET http://google.fr # comment1\nGET http://google.fr # comment2");
 
         let e = entry(&mut reader).unwrap();
-        assert_eq!(e.request.method, Method("GET".to_string()));
+        assert_eq!(e.request.method, Method::Literal("GET".to_string()));
         assert_eq!(reader.state.cursor, 32);
         assert_eq!(reader.state.pos.line, 2);
 
         let e = entry(&mut reader).unwrap();
-        assert_eq!(e.request.method, Method("GET".to_string()));
+        assert_eq!(e.request.method, Method::Literal("GET".to_string()));
         assert_eq!(reader.state.cursor, 63);
         assert_eq!(reader.state.pos.line, 2);
     }
@@ -243,7 +381,7 @@ mod tests {
     fn test_entry_with_response() {
         let mut reader = Reader::new("GET http://google.fr\nHTTP/1.1 200");
         let e = entry(&mut reader).unwrap();
-        assert_eq!(e.request.method, Method("GET".to_string()));
+        assert_eq!(e.request.method, Method::Literal("GET".to_string()));
         assert_eq!(e.response.unwrap().status.value, StatusValue::Specific(200));
     }
 
@@ -256,7 +394,7 @@ mod tests {
                 value: String::new(),
                 source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
             },
-            method: Method("GET".to_string()),
+            method: Method::Literal("GET".to_string()),
             space1: Whitespace {
                 value: " ".to_string(),
                 source_info: SourceInfo::new(Pos::new(1, 4), Pos::new(1, 5)),
@@ -295,7 +433,7 @@ mod tests {
                 value: String::new(),
                 source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
             },
-            method: Method("GET".to_string()),
+            method: Method::Literal("GET".to_string()),
             space1: Whitespace {
                 value: "  ".to_string(),
                 source_info: SourceInfo::new(Pos::new(1, 4), Pos::new(1, 6)),
@@ -332,10 +470,10 @@ mod tests {
 
         let mut reader = Reader::new("GET http://google.fr\nGET http://google.fr");
         let r = request(&mut reader).unwrap();
-        assert_eq!(r.method, Method("GET".to_string()));
+        assert_eq!(r.method, Method::Literal("GET".to_string()));
         assert_eq!(reader.state.cursor, 21);
         let r = request(&mut reader).unwrap();
-        assert_eq!(r.method, Method("GET".to_string()));
+        assert_eq!(r.method, Method::Literal("GET".to_string()));
     }
 
     #[test]
@@ -391,7 +529,7 @@ mod tests {
     fn test_request_post_json() {
         let mut reader = Reader::new("POST http://localhost:8000/post-json-array\n[1,2,3]");
         let r = request(&mut reader).unwrap();
-        assert_eq!(r.method, Method("POST".to_string()));
+        assert_eq!(r.method, Method::Literal("POST".to_string()));
         assert_eq!(
             r.body.unwrap().value,
             Bytes::Json(JsonValue::List {
@@ -418,7 +556,7 @@ mod tests {
 
         let mut reader = Reader::new("POST http://localhost:8000/post-json-string\n\"Hello\"");
         let r = request(&mut reader).unwrap();
-        assert_eq!(r.method, Method("POST".to_string()));
+        assert_eq!(r.method, Method::Literal("POST".to_string()));
         assert_eq!(
             r.body.unwrap().value,
             Bytes::Json(JsonValue::String(Template {
@@ -433,7 +571,7 @@ mod tests {
 
         let mut reader = Reader::new("POST http://localhost:8000/post-json-number\n100");
         let r = request(&mut reader).unwrap();
-        assert_eq!(r.method, Method("POST".to_string()));
+        assert_eq!(r.method, Method::Literal("POST".to_string()));
         assert_eq!(
             r.body.unwrap().value,
             Bytes::Json(JsonValue::Number("100".to_string()))
@@ -470,14 +608,64 @@ mod tests {
         assert_eq!(reader.state.cursor, 0);
 
         let mut reader = Reader::new("GET ");
-        assert_eq!(method(&mut reader).unwrap(), Method("GET".to_string()));
+        assert_eq!(
+            method(&mut reader).unwrap(),
+            Method::Literal("GET".to_string())
+        );
         assert_eq!(reader.state.cursor, 3);
 
         let mut reader = Reader::new("CUSTOM");
-        assert_eq!(method(&mut reader).unwrap(), Method("CUSTOM".to_string()));
+        assert_eq!(
+            method(&mut reader).unwrap(),
+            Method::Literal("CUSTOM".to_string())
+        );
         assert_eq!(reader.state.cursor, 6);
     }
 
+    #[test]
+    fn test_method_expression() {
+        let mut reader = Reader::new("{{method}} ");
+        assert_eq!(
+            method(&mut reader).unwrap(),
+            Method::Expression(Expr {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 3)),
+                },
+                variable: Variable {
+                    name: "method".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 9)),
+                },
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 9)),
+                },
+            })
+        );
+        assert_eq!(reader.state.cursor, 10);
+
+        let mut reader = Reader::new("{{method}} http://localhost:8000/users/1");
+        let r = request(&mut reader).unwrap();
+        assert_eq!(r.method.to_string(), "{{method}}");
+    }
+
+    #[test]
+    fn test_entries_with_method_expression() {
+        let mut reader = Reader::new(
+            "GET http://localhost:8000/users/1\nHTTP 200\n\n{{method}} http://localhost:8000/users/1\n",
+        );
+        let file = hurl_file(&mut reader).unwrap();
+        assert_eq!(file.entries.len(), 2);
+        assert_eq!(file.entries[0].response.as_ref().unwrap().body, None);
+        assert_eq!(file.entries[1].request.method.to_string(), "{{method}}");
+
+        // A JSON body with an expression is still parsed as a body.
+        let mut reader = Reader::new("POST http://localhost:8000/users\n{{user}}\n");
+        let file = hurl_file(&mut reader).unwrap();
+        assert_eq!(file.entries.len(), 1);
+        assert!(file.entries[0].request.body.is_some());
+    }
+
     #[test]
     fn test_version() {
         let mut reader = Reader::new("HTTP/1.1 200");
@@ -562,4 +750,46 @@ mod tests {
         assert_eq!(error.pos, Pos { line: 1, column: 2 });
         assert!(!error.recoverable);
     }
+
+    #[test]
+    fn test_hurl_file_with_recovery() {
+        let content = r#"GET http://localhost:8000/a
+HTTP 20x
+
+GET http://localhost:8000/b
+HTTP 200
+
+POST http://localhost:8000/c
+HTTP 200
+[Asserts]
+status >
+"#;
+        let mut reader = Reader::new(content);
+        let errors = hurl_file_with_recovery(&mut reader, 10).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                Error::new(
+                    Pos::new(2, 8),
+                    false,
+                    ParseError::Expecting {
+                        value: "line_terminator".to_string()
+                    }
+                ),
+                Error::new(Pos::new(10, 9), false, ParseError::PredicateValue),
+            ]
+        );
+
+        // The errors number is capped.
+        let mut reader = Reader::new(content);
+        let errors = hurl_file_with_recovery(&mut reader, 1).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].pos, Pos::new(2, 8));
+
+        // Without any error, we get the whole file.
+        let mut reader =
+            Reader::new("GET http://localhost:8000/a\nHTTP 200\nGET http://localhost:8000/b\n");
+        let hurl_file = hurl_file_with_recovery(&mut reader, 10).unwrap();
+        assert_eq!(hurl_file.entries.len(), 2);
+    }
 }
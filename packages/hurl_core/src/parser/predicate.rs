@@ -91,6 +91,10 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             exist_predicate,
             is_empty_predicate,
             is_number_predicate,
+            is_json_predicate,
+            is_xml_predicate,
+            is_html_predicate,
+            has_no_duplicate_keys_predicate,
         ],
         reader,
     ) {
@@ -347,6 +351,26 @@ fn is_number_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::IsNumber)
 }
 
+fn is_json_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isJson", reader)?;
+    Ok(PredicateFuncValue::IsJson)
+}
+
+fn is_xml_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isXml", reader)?;
+    Ok(PredicateFuncValue::IsXml)
+}
+
+fn is_html_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isHtml", reader)?;
+    Ok(PredicateFuncValue::IsHtml)
+}
+
+fn has_no_duplicate_keys_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("hasNoDuplicateKeys", reader)?;
+    Ok(PredicateFuncValue::HasNoDuplicateKeys)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -416,6 +440,42 @@ mod tests {
         assert_eq!(error.inner, ParseError::Predicate);
     }
 
+    #[test]
+    fn test_document_predicates() {
+        let mut reader = Reader::new("isJson");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsJson
+        );
+        let mut reader = Reader::new("isXml");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsXml
+        );
+        let mut reader = Reader::new("isHtml");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsHtml
+        );
+        assert_eq!(reader.state.pos, Pos { line: 1, column: 7 });
+    }
+
+    #[test]
+    fn test_has_no_duplicate_keys_predicate() {
+        let mut reader = Reader::new("hasNoDuplicateKeys");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::HasNoDuplicateKeys
+        );
+        assert_eq!(
+            reader.state.pos,
+            Pos {
+                line: 1,
+                column: 19
+            }
+        );
+    }
+
     #[test]
     fn test_equal_predicate() {
         let mut reader = Reader::new("==  true");
@@ -110,6 +110,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_predicate_value_bytes() {
+        let mut reader = Reader::new("hex,48656c6c6f;");
+        let PredicateValue::Hex(hex) = predicate_value(&mut reader).unwrap() else {
+            panic!("expected an hex predicate value");
+        };
+        assert_eq!(hex.value, b"Hello");
+        assert_eq!(hex.encoded, "48656c6c6f");
+        assert_eq!(reader.state.cursor, 15);
+
+        let mut reader = Reader::new("base64,SGVsbG8=;");
+        let PredicateValue::Base64(base64) = predicate_value(&mut reader).unwrap() else {
+            panic!("expected a base64 predicate value");
+        };
+        assert_eq!(base64.value, b"Hello");
+        assert_eq!(base64.encoded, "SGVsbG8=");
+        assert_eq!(reader.state.cursor, 16);
+    }
+
     #[test]
     fn test_predicate_value_error() {
         let mut reader = Reader::new("xx");
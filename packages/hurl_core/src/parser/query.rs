@@ -38,18 +38,27 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
         &[
             status_query,
             url_query,
+            header_count_query,
             header_query,
             cookie_query,
+            body_bytes_query,
             body_query,
             xpath_query,
             jsonpath_query,
+            jsonpointer_query,
             regex_query,
             variable_query,
             duration_query,
             bytes_query,
             sha256_query,
             md5_query,
+            content_type_query,
+            compression_ratio_query,
+            raw_query,
             certificate_query,
+            transfer_encoding_query,
+            transfer_query,
+            link_query,
         ],
         reader,
     )
@@ -72,6 +81,13 @@ fn header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Header { space0, name })
 }
 
+fn header_count_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("headerCount", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(QueryValue::HeaderCount { space0, name })
+}
+
 fn cookie_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("cookie", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -90,6 +106,11 @@ fn cookie_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Cookie { space0, expr })
 }
 
+fn body_bytes_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("bodyBytes", reader)?;
+    Ok(QueryValue::BodyBytes)
+}
+
 fn body_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("body", reader)?;
     Ok(QueryValue::Body)
@@ -123,6 +144,13 @@ fn jsonpath_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Jsonpath { space0, expr })
 }
 
+fn jsonpointer_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("jsonpointer", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(QueryValue::Jsonpointer { space0, expr })
+}
+
 fn regex_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("regex", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -179,6 +207,21 @@ fn md5_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Md5)
 }
 
+fn compression_ratio_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("compressionRatio", reader)?;
+    Ok(QueryValue::CompressionRatio)
+}
+
+fn raw_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("raw", reader)?;
+    Ok(QueryValue::Raw)
+}
+
+fn content_type_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("contentType", reader)?;
+    Ok(QueryValue::ContentType)
+}
+
 fn certificate_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("certificate", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -210,6 +253,41 @@ fn certificate_field(reader: &mut Reader) -> ParseResult<CertificateAttributeNam
     }
 }
 
+fn transfer_encoding_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("transferEncoding", reader)?;
+    Ok(QueryValue::TransferEncoding)
+}
+
+fn transfer_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("transfer", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let field = transfer_field(reader)?;
+    Ok(QueryValue::Transfer {
+        space0,
+        attribute_name: field,
+    })
+}
+
+fn link_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("link", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let rel = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(QueryValue::Link { space0, rel })
+}
+
+fn transfer_field(reader: &mut Reader) -> ParseResult<TransferAttributeName> {
+    if try_literal("sent", reader).is_ok() {
+        Ok(TransferAttributeName::Sent)
+    } else if try_literal("received", reader).is_ok() {
+        Ok(TransferAttributeName::Received)
+    } else {
+        let value = "Field <sent> or <received>".to_string();
+        let inner = ParseError::Expecting { value };
+        let pos = reader.state.pos;
+        Err(Error::new(pos, false, inner))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -378,6 +456,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_jsonpointer_query() {
+        let mut reader = Reader::new("jsonpointer \"/data/0/id\"");
+        assert_eq!(
+            query_value(&mut reader).unwrap(),
+            QueryValue::Jsonpointer {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 13)),
+                },
+                expr: Template {
+                    elements: vec![TemplateElement::String {
+                        value: "/data/0/id".to_string(),
+                        encoded: "/data/0/id".to_string(),
+                    }],
+                    delimiter: Some('"'),
+                    source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 25)),
+                },
+            },
+        );
+    }
+
     #[test]
     fn test_query_with_filters() {
         let mut reader = Reader::new("body urlDecode ");
@@ -403,4 +503,138 @@ mod tests {
         );
         assert_eq!(reader.state.cursor, 14);
     }
+
+    #[test]
+    fn test_transfer_query() {
+        let mut reader = Reader::new("transfer received");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 18)),
+                value: QueryValue::Transfer {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 10)),
+                    },
+                    attribute_name: TransferAttributeName::Received,
+                },
+            }
+        );
+
+        let mut reader = Reader::new("transfer size");
+        let error = query(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 10));
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "Field <sent> or <received>".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_link_query() {
+        let mut reader = Reader::new("link \"next\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+                value: QueryValue::Link {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                    },
+                    rel: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "next".to_string(),
+                            encoded: "next".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 12)),
+                    },
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_compression_ratio_query() {
+        let mut reader = Reader::new("compressionRatio < 0.5");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 17)),
+                value: QueryValue::CompressionRatio,
+            }
+        );
+    }
+
+    #[test]
+    fn test_raw_query() {
+        let mut reader = Reader::new("raw");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 4)),
+                value: QueryValue::Raw,
+            }
+        );
+    }
+
+    #[test]
+    fn test_body_bytes_query() {
+        let mut reader = Reader::new("bodyBytes");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+                value: QueryValue::BodyBytes,
+            }
+        );
+    }
+
+    #[test]
+    fn test_transfer_encoding_query() {
+        let mut reader = Reader::new("transferEncoding");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 17)),
+                value: QueryValue::TransferEncoding,
+            }
+        );
+    }
+
+    #[test]
+    fn test_header_count_query() {
+        let mut reader = Reader::new("headerCount \"Set-Cookie\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 25)),
+                value: QueryValue::HeaderCount {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 13)),
+                    },
+                    name: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "Set-Cookie".to_string(),
+                            encoded: "Set-Cookie".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 25)),
+                    },
+                },
+            }
+        );
+
+        // `header` query is still parsed.
+        let mut reader = Reader::new("header \"Set-Cookie\"");
+        assert!(matches!(
+            query(&mut reader).unwrap().value,
+            QueryValue::Header { .. }
+        ));
+    }
 }
@@ -82,6 +82,7 @@ fn response_section(reader: &mut Reader) -> ParseResult<Section> {
     let value = match name.as_str() {
         "Captures" => section_value_captures(reader)?,
         "Asserts" => section_value_asserts(reader)?,
+        "SoftAsserts" => section_value_soft_asserts(reader)?,
         _ => {
             let inner = ParseError::ResponseSectionName { name: name.clone() };
             let pos = Pos::new(start.line, start.column + 1);
@@ -148,6 +149,11 @@ fn section_value_asserts(reader: &mut Reader) -> ParseResult<SectionValue> {
     Ok(SectionValue::Asserts(asserts))
 }
 
+fn section_value_soft_asserts(reader: &mut Reader) -> ParseResult<SectionValue> {
+    let asserts = zero_or_more(assert, reader)?;
+    Ok(SectionValue::SoftAsserts(asserts))
+}
+
 fn section_value_options(reader: &mut Reader) -> ParseResult<SectionValue> {
     let options = zero_or_more(option::parse, reader)?;
     Ok(SectionValue::Options(options))
@@ -293,7 +299,7 @@ fn capture(reader: &mut Reader) -> ParseResult<Capture> {
     })
 }
 
-fn assert(reader: &mut Reader) -> ParseResult<Assert> {
+pub fn assert(reader: &mut Reader) -> ParseResult<Assert> {
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
     let query0 = query(reader)?;
@@ -420,6 +426,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_soft_asserts_section() {
+        let mut reader = Reader::new("[SoftAsserts]\nduration < 1000\nheader \"Age\" exists\n");
+        let section = response_section(&mut reader).unwrap();
+        assert_eq!(section.name(), "SoftAsserts");
+        let SectionValue::SoftAsserts(asserts) = section.value else {
+            panic!("expecting a soft asserts section");
+        };
+        assert_eq!(asserts.len(), 2);
+        assert_eq!(asserts[0].query.value, QueryValue::Duration);
+        assert_eq!(reader.state.cursor, 50);
+    }
+
     #[test]
     fn test_asserts_section_error() {
         let mut reader = Reader::new("x[Assertsx]\nheader Location == \"https://google.fr\"\n");
@@ -113,6 +113,10 @@ impl ToJson for Response {
             let asserts = self.asserts().iter().map(|a| a.to_json()).collect();
             attributes.push(("asserts".to_string(), JValue::List(asserts)));
         }
+        if !self.soft_asserts().is_empty() {
+            let asserts = self.soft_asserts().iter().map(|a| a.to_json()).collect();
+            attributes.push(("soft_asserts".to_string(), JValue::List(asserts)));
+        }
         if let Some(body) = &self.body {
             attributes.push(("body".to_string(), body.to_json()));
         }
@@ -285,6 +289,7 @@ impl ToJson for EntryOption {
             OptionKind::ClientCert(filename) => JValue::String(filename.to_string()),
             OptionKind::ClientKey(filename) => JValue::String(filename.to_string()),
             OptionKind::Compressed(value) => value.to_json(),
+            OptionKind::ConnectTimeout(value) => value.to_json(),
             OptionKind::ConnectTo(value) => JValue::String(value.to_string()),
             OptionKind::Delay(value) => value.to_json(),
             OptionKind::FollowLocation(value) => value.to_json(),
@@ -307,6 +312,7 @@ impl ToJson for EntryOption {
             OptionKind::Retry(value) => value.to_json(),
             OptionKind::RetryInterval(value) => value.to_json(),
             OptionKind::Skip(value) => value.to_json(),
+            OptionKind::Timeout(value) => value.to_json(),
             OptionKind::UnixSocket(value) => JValue::String(value.to_string()),
             OptionKind::User(value) => JValue::String(value.to_string()),
             OptionKind::Variable(value) => {
@@ -407,6 +413,13 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
             attributes.push(("type".to_string(), JValue::String("jsonpath".to_string())));
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
         }
+        QueryValue::Jsonpointer { expr, .. } => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("jsonpointer".to_string()),
+            ));
+            attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+        }
         QueryValue::Header { name, .. } => {
             attributes.push(("type".to_string(), JValue::String("header".to_string())));
             attributes.push(("name".to_string(), JValue::String(name.to_string())));
@@ -433,12 +446,30 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Bytes => {
             attributes.push(("type".to_string(), JValue::String("bytes".to_string())));
         }
+        QueryValue::BodyBytes => {
+            attributes.push(("type".to_string(), JValue::String("bodyBytes".to_string())));
+        }
         QueryValue::Sha256 => {
             attributes.push(("type".to_string(), JValue::String("sha256".to_string())));
         }
         QueryValue::Md5 => {
             attributes.push(("type".to_string(), JValue::String("md5".to_string())));
         }
+        QueryValue::ContentType => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("contentType".to_string()),
+            ));
+        }
+        QueryValue::CompressionRatio => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("compressionRatio".to_string()),
+            ));
+        }
+        QueryValue::Raw => {
+            attributes.push(("type".to_string(), JValue::String("raw".to_string())));
+        }
         QueryValue::Certificate {
             attribute_name: field,
             ..
@@ -449,6 +480,30 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
             ));
             attributes.push(("expr".to_string(), field.to_json()));
         }
+        QueryValue::Transfer {
+            attribute_name: field,
+            ..
+        } => {
+            attributes.push(("type".to_string(), JValue::String("transfer".to_string())));
+            attributes.push(("expr".to_string(), field.to_json()));
+        }
+        QueryValue::TransferEncoding => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("transferEncoding".to_string()),
+            ));
+        }
+        QueryValue::Link { rel, .. } => {
+            attributes.push(("type".to_string(), JValue::String("link".to_string())));
+            attributes.push(("rel".to_string(), JValue::String(rel.to_string())));
+        }
+        QueryValue::HeaderCount { name, .. } => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("headerCount".to_string()),
+            ));
+            attributes.push(("name".to_string(), JValue::String(name.to_string())));
+        }
     };
     attributes
 }
@@ -485,6 +540,16 @@ impl ToJson for CertificateAttributeName {
     }
 }
 
+impl ToJson for TransferAttributeName {
+    fn to_json(&self) -> JValue {
+        let value = match self {
+            TransferAttributeName::Sent => "sent",
+            TransferAttributeName::Received => "received",
+        };
+        JValue::String(value.to_string())
+    }
+}
+
 impl ToJson for Predicate {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![];
@@ -575,6 +640,21 @@ impl ToJson for Predicate {
             PredicateFuncValue::IsNumber => {
                 attributes.push(("type".to_string(), JValue::String("isNumber".to_string())));
             }
+            PredicateFuncValue::IsJson => {
+                attributes.push(("type".to_string(), JValue::String("isJson".to_string())));
+            }
+            PredicateFuncValue::IsXml => {
+                attributes.push(("type".to_string(), JValue::String("isXml".to_string())));
+            }
+            PredicateFuncValue::IsHtml => {
+                attributes.push(("type".to_string(), JValue::String("isHtml".to_string())));
+            }
+            PredicateFuncValue::HasNoDuplicateKeys => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("hasNoDuplicateKeys".to_string()),
+                ));
+            }
         }
         JValue::Object(attributes)
     }
@@ -675,6 +755,16 @@ impl ToJson for FilterValue {
                 attributes.push(("type".to_string(), JValue::String("jsonpath".to_string())));
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
             }
+            FilterValue::JsonPointer { expr, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("jsonpointer".to_string()),
+                ));
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
+            FilterValue::LineCount => {
+                attributes.push(("type".to_string(), JValue::String("lineCount".to_string())));
+            }
             FilterValue::Nth { n, .. } => {
                 attributes.push(("type".to_string(), JValue::String("nth".to_string())));
                 attributes.push(("n".to_string(), JValue::Number(n.to_string())));
@@ -761,7 +851,7 @@ pub mod tests {
             Request {
                 line_terminators: vec![],
                 space0: whitespace(),
-                method: Method("GET".to_string()),
+                method: Method::Literal("GET".to_string()),
                 space1: whitespace(),
                 url: Template {
                     delimiter: None,
@@ -202,6 +202,9 @@ impl Tokenizable for SectionValue {
             SectionValue::Asserts(items) => {
                 tokens.append(&mut items.iter().flat_map(|e| e.tokenize()).collect());
             }
+            SectionValue::SoftAsserts(items) => {
+                tokens.append(&mut items.iter().flat_map(|e| e.tokenize()).collect());
+            }
             SectionValue::QueryParams(items) => {
                 tokens.append(&mut items.iter().flat_map(|e| e.tokenize()).collect());
             }
@@ -428,6 +431,11 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut expr.tokenize());
             }
+            QueryValue::Jsonpointer { space0, expr } => {
+                tokens.push(Token::QueryType(String::from("jsonpointer")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut expr.tokenize());
+            }
             QueryValue::Regex { space0, value } => {
                 tokens.push(Token::QueryType(String::from("regex")));
                 tokens.append(&mut space0.tokenize());
@@ -440,8 +448,18 @@ impl Tokenizable for QueryValue {
             }
             QueryValue::Duration => tokens.push(Token::QueryType(String::from("duration"))),
             QueryValue::Bytes => tokens.push(Token::QueryType(String::from("bytes"))),
+            QueryValue::BodyBytes => tokens.push(Token::QueryType(String::from("bodyBytes"))),
             QueryValue::Sha256 => tokens.push(Token::QueryType(String::from("sha256"))),
             QueryValue::Md5 => tokens.push(Token::QueryType(String::from("md5"))),
+            QueryValue::ContentType => {
+                tokens.push(Token::QueryType(String::from("contentType")));
+            }
+            QueryValue::CompressionRatio => {
+                tokens.push(Token::QueryType(String::from("compressionRatio")));
+            }
+            QueryValue::Raw => {
+                tokens.push(Token::QueryType(String::from("raw")));
+            }
             QueryValue::Certificate {
                 space0,
                 attribute_name: field,
@@ -450,6 +468,27 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut field.tokenize());
             }
+            QueryValue::Transfer {
+                space0,
+                attribute_name: field,
+            } => {
+                tokens.push(Token::QueryType(String::from("transfer")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut field.tokenize());
+            }
+            QueryValue::TransferEncoding => {
+                tokens.push(Token::QueryType(String::from("transferEncoding")));
+            }
+            QueryValue::Link { space0, rel } => {
+                tokens.push(Token::QueryType(String::from("link")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut rel.tokenize());
+            }
+            QueryValue::HeaderCount { space0, name } => {
+                tokens.push(Token::QueryType(String::from("headerCount")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut name.tokenize());
+            }
         }
         tokens
     }
@@ -503,6 +542,16 @@ impl Tokenizable for CertificateAttributeName {
     }
 }
 
+impl Tokenizable for TransferAttributeName {
+    fn tokenize(&self) -> Vec<Token> {
+        let value = match self {
+            TransferAttributeName::Sent => "sent",
+            TransferAttributeName::Received => "received",
+        };
+        vec![Token::QueryType(value.to_string())]
+    }
+}
+
 impl Tokenizable for Predicate {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -611,6 +660,18 @@ impl Tokenizable for PredicateFuncValue {
             PredicateFuncValue::IsNumber => {
                 tokens.push(Token::PredicateType(self.name()));
             }
+            PredicateFuncValue::IsJson => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsXml => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsHtml => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::HasNoDuplicateKeys => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
         }
         tokens
     }
@@ -884,6 +945,7 @@ impl Tokenizable for OptionKind {
             OptionKind::ClientCert(filename) => filename.tokenize(),
             OptionKind::ClientKey(filename) => filename.tokenize(),
             OptionKind::Compressed(value) => value.tokenize(),
+            OptionKind::ConnectTimeout(value) => value.tokenize(),
             OptionKind::ConnectTo(value) => value.tokenize(),
             OptionKind::Delay(value) => value.tokenize(),
             OptionKind::FollowLocation(value) => value.tokenize(),
@@ -906,6 +968,7 @@ impl Tokenizable for OptionKind {
             OptionKind::Retry(value) => value.tokenize(),
             OptionKind::RetryInterval(value) => value.tokenize(),
             OptionKind::Skip(value) => value.tokenize(),
+            OptionKind::Timeout(value) => value.tokenize(),
             OptionKind::UnixSocket(value) => value.tokenize(),
             OptionKind::User(value) => value.tokenize(),
             OptionKind::Variable(value) => value.tokenize(),
@@ -1002,6 +1065,13 @@ impl Tokenizable for Filter {
                 tokens.append(&mut expr.tokenize());
                 tokens
             }
+            FilterValue::JsonPointer { space0, expr } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("jsonpointer"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut expr.tokenize());
+                tokens
+            }
+            FilterValue::LineCount => vec![Token::FilterType(String::from("lineCount"))],
             FilterValue::Nth { space0, n } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("nth"))];
                 tokens.append(&mut space0.tokenize());
@@ -160,6 +160,9 @@ fn lint_section_value(section_value: &SectionValue) -> SectionValue {
         SectionValue::Asserts(asserts) => {
             SectionValue::Asserts(asserts.iter().map(lint_assert).collect())
         }
+        SectionValue::SoftAsserts(asserts) => {
+            SectionValue::SoftAsserts(asserts.iter().map(lint_assert).collect())
+        }
         SectionValue::FormParams(params) => {
             SectionValue::FormParams(params.iter().map(lint_key_value).collect())
         }
@@ -187,6 +190,7 @@ fn section_value_index(section_value: SectionValue) -> u32 {
         // Response sections
         SectionValue::Captures(_) => 0,
         SectionValue::Asserts(_) => 1,
+        SectionValue::SoftAsserts(_) => 2,
     }
 }
 
@@ -262,6 +266,10 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             expr: expr.clone(),
             space0: one_whitespace(),
         },
+        QueryValue::Jsonpointer { expr, .. } => QueryValue::Jsonpointer {
+            expr: expr.clone(),
+            space0: one_whitespace(),
+        },
         QueryValue::Regex { value, .. } => QueryValue::Regex {
             value: lint_regex_value(value),
             space0: one_whitespace(),
@@ -272,8 +280,12 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
         },
         QueryValue::Duration => QueryValue::Duration,
         QueryValue::Bytes => QueryValue::Bytes,
+        QueryValue::BodyBytes => QueryValue::BodyBytes,
         QueryValue::Sha256 => QueryValue::Sha256,
         QueryValue::Md5 => QueryValue::Md5,
+        QueryValue::ContentType => QueryValue::ContentType,
+        QueryValue::CompressionRatio => QueryValue::CompressionRatio,
+        QueryValue::Raw => QueryValue::Raw,
         QueryValue::Certificate {
             attribute_name: field,
             ..
@@ -281,6 +293,22 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             attribute_name: field.clone(),
             space0: one_whitespace(),
         },
+        QueryValue::TransferEncoding => QueryValue::TransferEncoding,
+        QueryValue::Transfer {
+            attribute_name: field,
+            ..
+        } => QueryValue::Transfer {
+            attribute_name: field.clone(),
+            space0: one_whitespace(),
+        },
+        QueryValue::Link { rel, .. } => QueryValue::Link {
+            rel: rel.clone(),
+            space0: one_whitespace(),
+        },
+        QueryValue::HeaderCount { name, .. } => QueryValue::HeaderCount {
+            name: name.clone(),
+            space0: one_whitespace(),
+        },
     }
 }
 
@@ -400,6 +428,10 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
         PredicateFuncValue::Exist => PredicateFuncValue::Exist,
         PredicateFuncValue::IsEmpty => PredicateFuncValue::IsEmpty,
         PredicateFuncValue::IsNumber => PredicateFuncValue::IsNumber,
+        PredicateFuncValue::IsJson => PredicateFuncValue::IsJson,
+        PredicateFuncValue::IsXml => PredicateFuncValue::IsXml,
+        PredicateFuncValue::IsHtml => PredicateFuncValue::IsHtml,
+        PredicateFuncValue::HasNoDuplicateKeys => PredicateFuncValue::HasNoDuplicateKeys,
     }
 }
 
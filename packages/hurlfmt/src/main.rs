@@ -80,9 +80,11 @@ fn main() {
                 let log_linter_error =
                     cli::make_logger_linter_error(lines, opts.color, Some(input_path));
 
-                match parser::parse_hurl_file(&input) {
-                    Err(e) => {
-                        log_parser_error(&e, false);
+                match parser::parse_hurl_file_with_recovery(&input) {
+                    Err(errors) => {
+                        for e in &errors {
+                            log_parser_error(e, false);
+                        }
                         process::exit(2);
                     }
                     Ok(hurl_file) => {
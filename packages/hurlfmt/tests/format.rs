@@ -0,0 +1,104 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use std::fs;
+use std::path::PathBuf;
+
+use hurl_core::ast::{EntryBuilder, HurlFileBuilder};
+use hurl_core::parser::parse_hurl_file;
+use hurlfmt::{format, linter};
+
+/// Returns the Hurl files used as formatter samples.
+fn sample_files() -> Vec<PathBuf> {
+    let dirs = [
+        "../../integration/hurlfmt/tests_export",
+        "../../integration/hurl/tests_ok",
+    ];
+    let mut files = dirs
+        .iter()
+        .flat_map(|dir| fs::read_dir(dir).unwrap())
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.ends_with(".hurl") && !name.ends_with(".lint.hurl")
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+/// Formats a Hurl file `content` the way `hurlfmt` does.
+fn format(content: &str) -> String {
+    let hurl_file = parse_hurl_file(content).unwrap();
+    let hurl_file = linter::lint_hurl_file(&hurl_file);
+    format::format_text(hurl_file, false)
+}
+
+#[test]
+fn test_format_is_idempotent() {
+    for path in sample_files() {
+        let content = fs::read_to_string(&path).unwrap();
+        let formatted = format(&content);
+        assert_eq!(
+            format(&formatted),
+            formatted,
+            "formatting {} is not idempotent",
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn test_format_preserves_semantics() {
+    // The JSON export doesn't include whitespaces and comments: it must be the same for a file
+    // and its formatted version.
+    for path in sample_files() {
+        let content = fs::read_to_string(&path).unwrap();
+        let formatted = format(&content);
+
+        let expected = format::format_json(&parse_hurl_file(&content).unwrap());
+        let actual = format::format_json(&parse_hurl_file(&formatted).unwrap());
+        assert_eq!(actual, expected, "formatting {} changes it", path.display());
+    }
+}
+
+#[test]
+fn test_format_built_hurl_file() {
+    let mut get = EntryBuilder::new();
+    get.url("http://localhost:8000/users/{{id}}")
+        .header("Accept", "application/json")
+        .status(200)
+        .assert_header("Content-Type", "application/json")
+        .assert_jsonpath("$.name", "Bob \"the builder\"")
+        .assert_jsonpath("$.age", 42)
+        .assert_jsonpath("$.admin", true);
+    let mut delete = EntryBuilder::new();
+    delete
+        .method("DELETE")
+        .url("http://localhost:8000/users/{{id}}")
+        .header("X-Comment", "# not a comment \\o/")
+        .status(204);
+    let hurl_file = HurlFileBuilder::new()
+        .entry(&get)
+        .entry(&delete)
+        .build()
+        .unwrap();
+
+    let content = format::format_text(hurl_file.clone(), false);
+    assert_eq!(parse_hurl_file(&content).unwrap(), hurl_file);
+}